@@ -0,0 +1,68 @@
+//! Time rendering a large document to a writer without a buffer,
+//! against writing each line to it as it is rendered.
+//!
+//! Run with `cargo bench --bench render`.
+
+use std::fs::File;
+use std::io::{self, Write as _};
+use std::time::{Duration, Instant};
+
+use roff::{bold, italic, roman, Roff, NO_ARGS};
+
+/// A document with tens of thousands of lines, like the manual page
+/// of a large API.
+fn large_document() -> Roff<'static> {
+    let mut doc = Roff::with_capacity(2 + 3 * 20_000);
+    doc.control("TH", ["BIG", "3"]).control("SH", ["FUNCTIONS"]);
+    for i in 0..20_000 {
+        doc.control("TP", NO_ARGS)
+            .text([
+                bold(format!("function_{i}")),
+                roman("("),
+                italic("arg"),
+                roman(")"),
+            ])
+            .text([roman(format!(
+                "Does thing number {i}, which isn't the same as any other thing."
+            ))]);
+    }
+    doc
+}
+
+/// The fastest of several runs, to leave out noise.
+fn time(mut run: impl FnMut()) -> Duration {
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .expect("there are runs")
+}
+
+fn main() -> io::Result<()> {
+    let doc = large_document();
+    let null = if cfg!(windows) { "NUL" } else { "/dev/null" };
+    // Every write to a file is a system call.
+    let mut file = File::create(null)?;
+    let to_writer = time(|| doc.to_writer(&mut file).unwrap());
+    // The baseline: a write for each line, as without buffering.
+    let per_line = time(|| {
+        for line in doc.rendered_lines() {
+            file.write_all(format!("{line}\n").as_bytes()).unwrap();
+        }
+    });
+    let render = time(|| {
+        let _ = doc.render();
+    });
+    let lines = doc.line_count();
+    for (name, duration) in [
+        ("to_writer, to a file", to_writer),
+        ("a write per line, to a file", per_line),
+        ("render", render),
+    ] {
+        println!("{name}: {lines} lines in {duration:?}");
+    }
+    Ok(())
+}
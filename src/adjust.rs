@@ -0,0 +1,141 @@
+use crate::scope::{scoped, Restore};
+use crate::{Roff, NO_ARGS};
+
+/// How filled lines are adjusted: to which margins they are aligned.
+///
+/// By default, lines are adjusted to both margins, by widening the
+/// spaces between words.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Adjust {
+    /// Align lines to the left margin, leaving the right ragged.
+    Left,
+
+    /// Align lines to the right margin, leaving the left ragged.
+    Right,
+
+    /// Align lines to both margins.
+    Both,
+
+    /// Center lines between the margins.
+    Center,
+}
+
+impl Adjust {
+    /// The argument to the `ad` request for the mode.
+    pub fn arg(self) -> &'static str {
+        match self {
+            Self::Left => "l",
+            Self::Right => "r",
+            Self::Both => "b",
+            Self::Center => "c",
+        }
+    }
+}
+
+impl<'a> Roff<'a> {
+    /// Adjust the lines that follow, with the `ad` request.
+    pub fn adjust(&mut self, mode: Adjust) -> &mut Self {
+        self.control("ad", [mode.arg()])
+    }
+
+    /// Stop adjusting the lines that follow, with the `na` request.
+    ///
+    /// Lines are then aligned to the left margin. This is not quite the
+    /// same as [`Adjust::Left`]: a later `ad` request without an
+    /// argument goes back to the mode from before.
+    pub fn no_adjust(&mut self) -> &mut Self {
+        self.control("na", NO_ARGS)
+    }
+}
+
+const RESTORE: Restore = Restore {
+    register: "roff-ad",
+    current: r"\n[.j]",
+    request: "ad",
+    unit: "",
+};
+
+/// A block whose lines are adjusted in some mode, after which the mode
+/// from before the block is restored.
+///
+/// The mode from before is kept in a number register, which is named
+/// so that blocks can be nested.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = adjusted(Adjust::Center, |doc| {
+///     doc.text([roman("centered")]);
+/// });
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".nr roff-ad0 \\n[.j]\n\
+///      .ad c\n\
+///      centered\n\
+///      .ad \\n[roff-ad0]\n"
+/// );
+/// ```
+pub fn adjusted<'a>(mode: Adjust, content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    scoped(
+        RESTORE,
+        |doc| {
+            doc.adjust(mode);
+        },
+        content,
+    )
+}
+
+/// A block whose lines are aligned to the left margin, after which the
+/// adjustment mode from before the block is restored.
+///
+/// This is the same as [`adjusted`] with [`Adjust::Left`].
+pub fn left_aligned<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    adjusted(Adjust::Left, content)
+}
+
+/// A block whose lines aren't adjusted, after which the adjustment
+/// mode from before the block is restored.
+pub fn unadjusted<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    scoped(
+        RESTORE,
+        |doc| {
+            doc.no_adjust();
+        },
+        content,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::roman;
+
+    #[test]
+    fn requests() {
+        let mut doc = Roff::new();
+        doc.adjust(Adjust::Right).no_adjust().adjust(Adjust::Both);
+        assert_eq!(doc.to_roff(), ".ad r\n.na\n.ad b\n");
+    }
+
+    #[test]
+    fn nested_blocks_use_their_own_registers() {
+        let doc = left_aligned(|doc| {
+            doc.append(unadjusted(|doc| {
+                doc.text([roman("inner")]);
+            }));
+            doc.text([roman("outer")]);
+        });
+        assert_eq!(
+            doc.to_roff(),
+            ".nr roff-ad1 \\n[.j]\n\
+             .ad l\n\
+             .nr roff-ad0 \\n[.j]\n\
+             .na\n\
+             inner\n\
+             .ad \\n[roff-ad0]\n\
+             outer\n\
+             .ad \\n[roff-ad1]\n"
+        );
+    }
+}
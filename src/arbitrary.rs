@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Inline, Line, Roff, SpecialChar};
+
+/// How deeply inline elements are nested, at most.
+const MAX_DEPTH: u32 = 4;
+
+const SPECIAL_CHARS: [SpecialChar; 14] = [
+    SpecialChar::Bullet,
+    SpecialChar::Copyright,
+    SpecialChar::Registered,
+    SpecialChar::Trademark,
+    SpecialChar::Degree,
+    SpecialChar::Ellipsis,
+    SpecialChar::LeftDoubleQuote,
+    SpecialChar::RightDoubleQuote,
+    SpecialChar::LeftSingleQuote,
+    SpecialChar::RightSingleQuote,
+    SpecialChar::EmDash,
+    SpecialChar::EnDash,
+    SpecialChar::Section,
+    SpecialChar::NoBreakSpace,
+];
+
+/// Documents for fuzzing.
+///
+/// Text is arbitrary, since it is escaped, but control lines are
+/// used as is, so their names are valid, as by [`Roff::validate`], and
+/// their arguments have no backslashes or newlines. Rendering such a
+/// document should never produce a control line that wasn't built as
+/// one.
+impl<'a> Arbitrary<'a> for Roff<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            lines: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Line<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            let len = u.int_in_range(1..=4)?;
+            let name = (0..len)
+                .map(|_| {
+                    let c = char::from(u.int_in_range(b'!'..=b'~')?);
+                    Ok(if matches!(c, '"' | '\\') { 'x' } else { c })
+                })
+                .collect::<Result<String>>()?;
+            let args = u
+                .arbitrary_iter::<&str>()?
+                .map(|arg| Ok(plain_arg(arg?)))
+                .collect::<Result<_>>()?;
+            Ok(Self::control(Cow::Owned(name), args))
+        } else {
+            Ok(Self::text(inlines(u, 0)?))
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for Inline<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        inline(u, 0)
+    }
+}
+
+fn inline<'a>(u: &mut Unstructured<'a>, depth: u32) -> Result<Inline<'a>> {
+    // Elements with elements in them are only made above the limit.
+    let kinds = if depth < MAX_DEPTH { 13 } else { 9 };
+    Ok(match u.choose_index(kinds)? {
+        0 => Inline::Roman(Cow::Borrowed(u.arbitrary()?)),
+        1 => Inline::Italic(Cow::Borrowed(u.arbitrary()?)),
+        2 => Inline::Bold(Cow::Borrowed(u.arbitrary()?)),
+        3 => Inline::LineBreak,
+        4 => Inline::Special(*u.choose(&SPECIAL_CHARS)?),
+        5 => Inline::HyphenationPoint,
+        6 => Inline::BreakPoint,
+        7 => Inline::Tab,
+        8 => Inline::ZeroWidth,
+        9 => Inline::Colored {
+            color: plain_arg(u.arbitrary()?),
+            inlines: inlines(u, depth + 1)?,
+        },
+        10 => Inline::Resized {
+            change: u.int_in_range(-99..=99)?,
+            inlines: inlines(u, depth + 1)?,
+        },
+        11 => Inline::Superscript(inlines(u, depth + 1)?),
+        _ => Inline::Subscript(inlines(u, depth + 1)?),
+    })
+}
+
+fn inlines<'a>(u: &mut Unstructured<'a>, depth: u32) -> Result<Vec<Inline<'a>>> {
+    let len = u.arbitrary_len::<Inline<'_>>()?;
+    (0..len).map(|_| inline(u, depth)).collect()
+}
+
+/// An argument without the characters that would break a control line.
+fn plain_arg(arg: &str) -> Cow<'_, str> {
+    if arg.contains(['\\', '\n']) {
+        Cow::Owned(arg.replace(['\\', '\n'], " "))
+    } else {
+        Cow::Borrowed(arg)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rendered_control_lines_are_built() {
+        let data: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let doc = Roff::arbitrary(&mut u).unwrap();
+            assert!(doc.validate().is_ok());
+            // Line breaks are rendered as `br` requests.
+            let controls = doc
+                .lines
+                .iter()
+                .filter(|line| matches!(line, Line::Control { name, .. } if name != "br"))
+                .count();
+            let rendered = doc.to_roff();
+            let rendered_controls = rendered
+                .lines()
+                .filter(|line| line.starts_with('.') || line.starts_with('\''))
+                .filter(|&line| line != ".br" && !line.starts_with(".br "))
+                .count();
+            assert_eq!(rendered_controls, controls, "{rendered}");
+        }
+    }
+}
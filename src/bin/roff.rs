@@ -0,0 +1,101 @@
+//! Convert documents to ROFF and other formats.
+//!
+//! This reads a document in the JSON format of [`Roff::from_json`], or
+//! a manifest of a command in TOML, as read by
+//! [`Manifest::from_toml`], and writes it as a manual page using the
+//! man or mdoc macros, as Markdown, as JSON, or, typeset with groff,
+//! as HTML or text.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use clap::{Arg, ArgMatches, Command};
+use roff::groff::Device;
+use roff::manifest::Manifest;
+use roff::{Error, Roff};
+
+fn command() -> Command {
+    Command::new("roff")
+        .about("Convert documents to ROFF and other formats")
+        .arg(
+            Arg::new("from")
+                .long("from")
+                .short('f')
+                .value_parser(["json", "toml"])
+                .help("Format of the input: a document in JSON, or a manifest in TOML [default: toml for .toml files, json otherwise]"),
+        )
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .short('t')
+                .value_parser(["man", "mdoc", "markdown", "json", "html", "text"])
+                .default_value("man")
+                .help("Format of the output: html and text are typeset with groff"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .value_name("FILE")
+                .help("Write to FILE instead of standard output"),
+        )
+        .arg(
+            Arg::new("input")
+                .value_name("FILE")
+                .help("Read FILE instead of standard input, or `-` for standard input"),
+        )
+}
+
+fn main() -> ExitCode {
+    match run(&command().get_matches()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            let _ = writeln!(io::stderr(), "roff: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(matches: &ArgMatches) -> Result<(), Error> {
+    let path = matches
+        .get_one::<String>("input")
+        .filter(|path| path.as_str() != "-");
+    let input = match path {
+        Some(path) => fs::read_to_string(path)?,
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+    };
+    let from = match matches.get_one::<String>("from") {
+        Some(from) => from.as_str(),
+        None if path.is_some_and(|path| path.ends_with(".toml")) => "toml",
+        None => "json",
+    };
+
+    let manifest;
+    let doc = match from {
+        "toml" => {
+            manifest = Manifest::from_toml(&input)?;
+            manifest.to_man_page()?.into_roff()
+        }
+        _ => Roff::from_json(&input)?,
+    };
+
+    let to = matches.get_one::<String>("to").expect("has a default");
+    let output = match to.as_str() {
+        "mdoc" => doc.to_mdoc().render().into_bytes(),
+        "markdown" => doc.to_markdown().into_bytes(),
+        "json" => doc.to_json().into_bytes(),
+        "html" => doc.render_with_groff(Device::Html)?.into_output(),
+        "text" => doc.render_with_groff(Device::Utf8)?.into_output(),
+        _ => doc.render().into_bytes(),
+    };
+    match matches.get_one::<String>("output") {
+        Some(path) => fs::write(path, output)?,
+        None => io::stdout().write_all(&output)?,
+    }
+    Ok(())
+}
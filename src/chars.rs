@@ -0,0 +1,139 @@
+/// A special character (glyph) with a name known to ROFF.
+///
+/// Output devices differ in which characters they can show, so rather
+/// than putting, say, a Unicode bullet into the text and hoping for
+/// the best, a special character is rendered as the named escape
+/// sequence for the glyph. Each ROFF implementation then picks the
+/// best representation for its output device.
+///
+/// See [groff_char(7)] for the full list of glyphs.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = Roff::new()
+///     .text([SpecialChar::Copyright.into(), roman(" 2021 The Authors")])
+///     .to_roff();
+/// assert_eq!(doc, "\\(co 2021 The Authors\n");
+/// ```
+///
+/// [groff_char(7)]: https://manpages.debian.org/bullseye/groff/groff_char.7.en.html
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpecialChar {
+    /// A bullet: •
+    Bullet,
+
+    /// The copyright sign: ©
+    Copyright,
+
+    /// The registered sign: ®
+    Registered,
+
+    /// The trademark sign: ™
+    Trademark,
+
+    /// The degree sign: °
+    Degree,
+
+    /// A horizontal ellipsis: …
+    ///
+    /// There is no portable glyph for this, so it's rendered as three
+    /// periods separated by thin spaces.
+    Ellipsis,
+
+    /// A left double quotation mark: “
+    LeftDoubleQuote,
+
+    /// A right double quotation mark: ”
+    RightDoubleQuote,
+
+    /// A left single quotation mark: ‘
+    LeftSingleQuote,
+
+    /// A right single quotation mark: ’
+    RightSingleQuote,
+
+    /// An em dash: —
+    EmDash,
+
+    /// An en dash: –
+    EnDash,
+
+    /// The section sign: §
+    Section,
+
+    /// A space that lines are never broken at.
+    ///
+    /// This keeps together what reads as a unit, such as an option
+    /// and its value.
+    NoBreakSpace,
+}
+
+impl SpecialChar {
+    /// The ROFF escape sequence for the glyph.
+    pub fn escape(self) -> &'static str {
+        match self {
+            Self::Bullet => r"\(bu",
+            Self::Copyright => r"\(co",
+            Self::Registered => r"\(rg",
+            Self::Trademark => r"\(tm",
+            Self::Degree => r"\(de",
+            // The leading zero-width glyph keeps the period from
+            // being taken as a control character at the start of a
+            // line.
+            Self::Ellipsis => r"\&.\|.\|.",
+            Self::LeftDoubleQuote => r"\(lq",
+            Self::RightDoubleQuote => r"\(rq",
+            Self::LeftSingleQuote => r"\(oq",
+            Self::RightSingleQuote => r"\(cq",
+            Self::EmDash => r"\(em",
+            Self::EnDash => r"\(en",
+            Self::Section => r"\(sc",
+            Self::NoBreakSpace => r"\ ",
+        }
+    }
+
+    /// The glyph for a Unicode character, if there is one.
+    pub fn from_char(c: char) -> Option<Self> {
+        let special = match c {
+            '•' => Self::Bullet,
+            '©' => Self::Copyright,
+            '®' => Self::Registered,
+            '™' => Self::Trademark,
+            '°' => Self::Degree,
+            '…' => Self::Ellipsis,
+            '“' => Self::LeftDoubleQuote,
+            '”' => Self::RightDoubleQuote,
+            '‘' => Self::LeftSingleQuote,
+            '’' => Self::RightSingleQuote,
+            '—' => Self::EmDash,
+            '–' => Self::EnDash,
+            '§' => Self::Section,
+            '\u{a0}' => Self::NoBreakSpace,
+            _ => return None,
+        };
+        Some(special)
+    }
+
+    /// The Unicode character the glyph represents.
+    pub fn as_char(self) -> char {
+        match self {
+            Self::Bullet => '•',
+            Self::Copyright => '©',
+            Self::Registered => '®',
+            Self::Trademark => '™',
+            Self::Degree => '°',
+            Self::Ellipsis => '…',
+            Self::LeftDoubleQuote => '“',
+            Self::RightDoubleQuote => '”',
+            Self::LeftSingleQuote => '‘',
+            Self::RightSingleQuote => '’',
+            Self::EmDash => '—',
+            Self::EnDash => '–',
+            Self::Section => '§',
+            Self::NoBreakSpace => '\u{a0}',
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! Checking documents with the linters of ROFF implementations.
+//!
+//! Output that renders without complaint can still be wrong: a macro
+//! can be misspelt, or given the wrong number of arguments, or a
+//! section can be missing from a manual page. [mandoc(1)] and
+//! [groff(1)] know about such mistakes and can warn about them. This
+//! module runs them on a document and reports their warnings against
+//! the lines of the document, so that they can be checked in tests.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use roff::*;
+//! let mut doc = Roff::new();
+//! doc.control("TH", ["FOO", "1"])
+//!     .control("SH", ["NAME"])
+//!     .text([roman("foo - do a foo thing")]);
+//! let diagnostics = roff::check::lint(&doc).unwrap();
+//! assert!(diagnostics.is_empty(), "{diagnostics:?}");
+//! ```
+//!
+//! [mandoc(1)]: https://manpages.debian.org/bullseye/mandoc/mandoc.1.en.html
+//! [groff(1)]: https://manpages.debian.org/bullseye/groff/groff.1.en.html
+
+use std::fmt;
+use std::io::{self, Write as _};
+use std::process::{Command, Output, Stdio};
+
+use crate::{render_preamble, Error, LineRenderer, RenderOptions, Roff};
+
+/// A program that checks documents.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Linter {
+    /// `mandoc -T lint`, which knows the most about manual pages.
+    Mandoc,
+
+    /// `groff -man -ww -z`, which reports what GNU troff warns about.
+    Groff,
+}
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Severity {
+    /// A matter of style, such as a missing conventional section.
+    Style,
+
+    /// A mistake that is likely to change how the document is
+    /// typeset.
+    Warning,
+
+    /// A mistake that makes a part of the document unusable.
+    Error,
+}
+
+/// A problem found in a document.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    line: Option<usize>,
+    severity: Severity,
+    message: String,
+}
+
+impl Diagnostic {
+    /// Index of the line of the document the problem is on, counting
+    /// from zero.
+    ///
+    /// This is `None` if the linter didn't say, or if the problem is
+    /// with something written before the first line, such as the
+    /// apostrophe preamble.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// How serious the problem is.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// The message from the linter.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Style => "style",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        if let Some(index) = self.line {
+            write!(f, "line {}: ", index + 1)?;
+        }
+        write!(f, "{severity}: {}", self.message)
+    }
+}
+
+/// Check a document with whichever linter is installed.
+///
+/// `mandoc` is used if it is installed, and `groff` otherwise. If
+/// neither is, the error is that of running `groff`.
+pub fn lint(doc: &Roff<'_>) -> Result<Vec<Diagnostic>, Error> {
+    match lint_with(doc, Linter::Mandoc) {
+        Err(Error::Io(e)) if e.kind() == io::ErrorKind::NotFound => lint_with(doc, Linter::Groff),
+        result => result,
+    }
+}
+
+/// Check a document with a particular linter.
+pub fn lint_with(doc: &Roff<'_>, linter: Linter) -> Result<Vec<Diagnostic>, Error> {
+    let options = RenderOptions::new();
+    let input = doc.render_with(&options);
+    let output = match linter {
+        Linter::Mandoc => run(Command::new("mandoc").args(["-T", "lint"]), &input)?,
+        Linter::Groff => run(
+            Command::new("groff").args(["-t", "-man", "-ww", "-z"]),
+            &input,
+        )?,
+    };
+    // mandoc reports on standard output, groff on standard error.
+    let report = match linter {
+        Linter::Mandoc => String::from_utf8_lossy(&output.stdout).into_owned(),
+        Linter::Groff => String::from_utf8_lossy(&output.stderr).into_owned(),
+    };
+    let parse = match linter {
+        Linter::Mandoc => parse_mandoc,
+        Linter::Groff => parse_groff,
+    };
+    Ok(diagnostics(doc, &options, &report, parse))
+}
+
+/// A problem as a linter reports it: the line of output it is on,
+/// counting from one, how serious it is, and the message.
+pub(crate) type Report = (Option<usize>, Severity, String);
+
+/// Parse the report of a linter on a rendering of `doc`.
+pub(crate) fn diagnostics(
+    doc: &Roff<'_>,
+    options: &RenderOptions,
+    report: &str,
+    parse: fn(&str) -> Option<Report>,
+) -> Vec<Diagnostic> {
+    let starts = line_starts(doc, options);
+    report
+        .lines()
+        .filter_map(parse)
+        .map(|(output_line, severity, message)| Diagnostic {
+            line: output_line.and_then(|n| doc_line(&starts, n)),
+            severity,
+            message,
+        })
+        .collect()
+}
+
+/// Run a command with `input` as its standard input, and collect its
+/// output.
+pub(crate) fn run(command: &mut Command, input: &str) -> io::Result<Output> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    // Write from another thread so that a command filling its output
+    // pipes before reading all of its input can't deadlock.
+    let input = input.to_owned();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output()?;
+    match writer.join() {
+        Ok(Err(e)) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e),
+        _ => {}
+    }
+    Ok(output)
+}
+
+/// The line of output, counting from one, that each line of the
+/// document starts on.
+fn line_starts(doc: &Roff<'_>, options: &RenderOptions) -> Vec<usize> {
+    let mut rendered = String::new();
+    render_preamble(&mut rendered, options, &doc.lines).expect("writing to a String never fails");
+    let mut next = rendered.matches('\n').count() + 1;
+    let mut starts = Vec::with_capacity(doc.lines.len());
+    let mut renderer = LineRenderer::default();
+    for line in &doc.lines {
+        starts.push(next);
+        rendered.clear();
+        renderer
+            .render(&mut rendered, line, options)
+            .expect("writing to a String never fails");
+        next += rendered.matches('\n').count();
+    }
+    starts
+}
+
+/// The index of the line of the document that a line of output is
+/// part of.
+fn doc_line(starts: &[usize], output_line: usize) -> Option<usize> {
+    starts
+        .partition_point(|&start| start <= output_line)
+        .checked_sub(1)
+}
+
+/// Parse a line of `mandoc -T lint` output, such as
+/// `mandoc: <stdin>:3:5: WARNING: skipping paragraph macro: PP empty`.
+fn parse_mandoc(report: &str) -> Option<Report> {
+    let rest = report.strip_prefix("mandoc: ")?;
+    let (location, rest) = match rest.split_once(": ") {
+        Some((location, rest)) if location.starts_with("<stdin>") => (location, rest),
+        _ => ("", rest),
+    };
+    let line = location.split(':').nth(1).and_then(|n| n.parse().ok());
+    let (level, message) = rest.split_once(": ")?;
+    let severity = match level {
+        "STYLE" => Severity::Style,
+        "WARNING" => Severity::Warning,
+        "ERROR" | "UNSUPP" | "BADARG" | "SYSERR" => Severity::Error,
+        _ => return None,
+    };
+    Some((line, severity, message.to_owned()))
+}
+
+/// Parse a line of `groff -ww -z` output, such as
+/// `troff: <standard input>:3: warning: macro 'XX' not defined`.
+pub(crate) fn parse_groff(report: &str) -> Option<Report> {
+    let (_, rest) = report.split_once("<standard input>:")?;
+    let (line, rest) = rest.split_once(':')?;
+    let line = line.parse().ok();
+    let rest = rest.trim_start();
+    let (level, message) = rest.split_once(':')?;
+    // Newer versions of groff add the position on the page, as in
+    // `warning [p 1, 0.0i]`.
+    let severity = match level.split(' ').next()? {
+        "warning" => Severity::Warning,
+        "error" | "fatal" => Severity::Error,
+        _ => return None,
+    };
+    Some((line, severity, message.trim().to_owned()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_break, roman, NO_ARGS};
+
+    #[test]
+    fn parses_mandoc() {
+        assert_eq!(
+            parse_mandoc("mandoc: <stdin>:7:2: WARNING: skipping paragraph macro: PP empty"),
+            Some((
+                Some(7),
+                Severity::Warning,
+                "skipping paragraph macro: PP empty".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse_mandoc("mandoc: <stdin>: STYLE: missing section: SYNOPSIS"),
+            Some((
+                None,
+                Severity::Style,
+                "missing section: SYNOPSIS".to_owned()
+            ))
+        );
+        assert_eq!(parse_mandoc("something else"), None);
+    }
+
+    #[test]
+    fn parses_groff() {
+        assert_eq!(
+            parse_groff("troff: <standard input>:12: warning: macro 'XX' not defined"),
+            Some((
+                Some(12),
+                Severity::Warning,
+                "macro 'XX' not defined".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse_groff("troff:<standard input>:3: error [p 1, 0.0i]: bad thing"),
+            Some((Some(3), Severity::Error, "bad thing".to_owned()))
+        );
+        assert_eq!(parse_groff("groff: something else"), None);
+    }
+
+    #[test]
+    fn maps_output_lines_to_document_lines() {
+        let mut doc = Roff::new();
+        doc.control("TH", ["FOO", "1"])
+            .text([roman("one"), line_break(), roman("two")])
+            .control("PP", NO_ARGS);
+        let options = RenderOptions::new();
+        let preamble = Roff::new().render_with(&options).matches('\n').count();
+        let starts = line_starts(&doc, &options);
+        assert_eq!(starts, [preamble + 1, preamble + 2, preamble + 5]);
+        assert_eq!(doc_line(&starts, 1), None);
+        assert_eq!(doc_line(&starts, preamble + 1), Some(0));
+        assert_eq!(doc_line(&starts, preamble + 3), Some(1));
+        assert_eq!(doc_line(&starts, preamble + 5), Some(2));
+    }
+}
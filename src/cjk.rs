@@ -0,0 +1,73 @@
+/// Characters that lines mustn't be broken before: closing
+/// punctuation and small kana.
+const NO_BREAK_BEFORE: &str =
+    "、。，．：；？！）」』】〕〉》〙〗｝］・ー々ぁぃぅぇぉっゃゅょゎァィゥェォッャュョヮヵヶ";
+
+/// Characters that lines mustn't be broken after: opening punctuation.
+const NO_BREAK_AFTER: &str = "（「『【〔〈《〘〖｛［";
+
+/// Is the character shown twice as wide as others on terminals, as
+/// are those of Chinese, Japanese, and Korean?
+pub(crate) fn is_wide(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{115F}'
+            | '\u{2E80}'..='\u{303E}'
+            | '\u{3041}'..='\u{33FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{A000}'..='\u{A4CF}'
+            | '\u{AC00}'..='\u{D7A3}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FE30}'..='\u{FE4F}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE6}'
+            | '\u{20000}'..='\u{2FFFD}'
+            | '\u{30000}'..='\u{3FFFD}'
+    )
+}
+
+/// The number of columns the character takes on a terminal.
+pub(crate) fn width(c: char) -> usize {
+    if is_wide(c) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Add places where lines may be broken, `\:`, between wide
+/// characters, except where the rules for Japanese line breaking
+/// forbid it.
+pub(crate) fn with_break_points(text: &str) -> String {
+    let mut broken = String::with_capacity(text.len());
+    let mut prev = None;
+    for c in text.chars() {
+        if let Some(prev) = prev {
+            if is_wide(prev)
+                && is_wide(c)
+                && !NO_BREAK_AFTER.contains(prev)
+                && !NO_BREAK_BEFORE.contains(c)
+            {
+                broken.push_str(r"\:");
+            }
+        }
+        broken.push(c);
+        prev = Some(c);
+    }
+    broken
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn breaks_between_ideographs() {
+        assert_eq!(
+            with_break_points("「日本語」です。ok"),
+            r"「日\:本\:語」\:で\:す。ok"
+        );
+        assert_eq!(with_break_points("plain text"), "plain text");
+    }
+}
@@ -0,0 +1,327 @@
+//! Generating manual pages from [`clap`] commands.
+//!
+//! [`man_page`] turns the definition of a command line interface into
+//! a complete [`ManPage`], with a SYNOPSIS, DESCRIPTION, OPTIONS, and
+//! so on, built from its arguments, subcommands, and help text.
+//!
+//! # Example
+//!
+//! ```
+//! # use clap::{Arg, ArgAction, Command};
+//! let command = Command::new("corrupt")
+//!     .version("1.0")
+//!     .about("modify files by randomly changing bits")
+//!     .arg(
+//!         Arg::new("bits")
+//!             .short('n')
+//!             .long("bits")
+//!             .value_name("BITS")
+//!             .help("Set the number of bits to modify")
+//!             .default_value("1"),
+//!     )
+//!     .arg(Arg::new("file").required(true).action(ArgAction::Append));
+//! let page = roff::clap::man_page(&command).render();
+//! assert!(page.contains(".TH CORRUPT 1 \"\" \"corrupt 1.0\"\n"));
+//! assert!(page.contains(".OP \\-n BITS\n"));
+//! ```
+
+use ::clap::{Arg, Command};
+
+use crate::man::{ManOption, ManPage, ManSection, OptionsSection, Synopsis, TaggedList};
+use crate::{bold, italic, roman};
+
+/// Build the manual page for a command.
+///
+/// Hidden arguments and subcommands are left out. Subcommands are
+/// listed with their short help; to document them fully, build a page
+/// for each of them too.
+pub fn man_page(command: &Command) -> ManPage<'static> {
+    let mut command = command.clone();
+    command.build();
+
+    let name = command.get_name().to_owned();
+    let mut page = ManPage::new(name.to_uppercase(), ManSection::Executable);
+    if let Some(version) = command.get_version() {
+        page.source(format!("{name} {version}"));
+    }
+    let about = command.get_about().map(ToString::to_string);
+    page.name(&name, about.as_deref().unwrap_or_default());
+
+    let synopsis = synopsis(&command);
+    page.section("SYNOPSIS", |doc| {
+        doc.append(synopsis.into_roff());
+    });
+
+    let description = command
+        .get_long_about()
+        .or_else(|| command.get_about())
+        .map(ToString::to_string);
+    if let Some(description) = description {
+        page.section("DESCRIPTION", |doc| {
+            for (i, paragraph) in paragraphs(&description).into_iter().enumerate() {
+                if i > 0 {
+                    doc.paragraph();
+                }
+                doc.text([roman(paragraph)]);
+            }
+        });
+    }
+
+    let mut options = OptionsSection::new();
+    let mut has_options = false;
+    for arg in visible_args(&command).filter(|arg| !arg.is_positional()) {
+        options.option(man_option(arg));
+        has_options = true;
+    }
+    if has_options {
+        page.append(options.into_roff());
+    }
+
+    let mut arguments = TaggedList::new();
+    let mut has_arguments = false;
+    for arg in visible_args(&command).filter(|arg| arg.is_positional()) {
+        let body = help(arg).into_iter().map(|paragraph| [roman(paragraph)]);
+        arguments.item([italic(value_name(arg))], body);
+        has_arguments = true;
+    }
+    if has_arguments {
+        page.section("ARGUMENTS", |doc| {
+            doc.append(arguments.into_roff());
+        });
+    }
+
+    let mut subcommands = TaggedList::new();
+    let mut has_subcommands = false;
+    for subcommand in command.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+        let about = subcommand.get_about().map(ToString::to_string);
+        let body = about
+            .as_deref()
+            .map(paragraphs)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|paragraph| [roman(paragraph)]);
+        subcommands.item([bold(subcommand.get_name().to_owned())], body);
+        has_subcommands = true;
+    }
+    if has_subcommands {
+        page.section("SUBCOMMANDS", |doc| {
+            doc.append(subcommands.into_roff());
+        });
+    }
+
+    let after_help = command
+        .get_after_long_help()
+        .or_else(|| command.get_after_help())
+        .map(ToString::to_string);
+    if let Some(after_help) = after_help {
+        page.section("NOTES", |doc| {
+            for (i, paragraph) in paragraphs(&after_help).into_iter().enumerate() {
+                if i > 0 {
+                    doc.paragraph();
+                }
+                doc.text([roman(paragraph)]);
+            }
+        });
+    }
+
+    if let Some(author) = command.get_author() {
+        let author = author.to_owned();
+        page.section("AUTHORS", |doc| {
+            doc.text([roman(author)]);
+        });
+    }
+
+    page
+}
+
+fn visible_args(command: &Command) -> impl Iterator<Item = &Arg> {
+    command.get_arguments().filter(|arg| !arg.is_hide_set())
+}
+
+fn synopsis(command: &Command) -> Synopsis<'static> {
+    let mut synopsis = Synopsis::new(command.get_name().to_owned());
+    for arg in visible_args(command) {
+        let repeated = arg
+            .get_num_args()
+            .map(|range| range.max_values() > 1)
+            .unwrap_or(false)
+            || matches!(
+                arg.get_action(),
+                ::clap::ArgAction::Append | ::clap::ArgAction::Count
+            );
+        if arg.is_positional() {
+            if arg.is_required_set() {
+                synopsis.arg(value_name(arg));
+            } else {
+                synopsis.optional_arg(value_name(arg));
+            }
+        } else {
+            let flag = match (arg.get_short(), arg.get_long()) {
+                (Some(short), _) => format!("-{short}"),
+                (None, Some(long)) => format!("--{long}"),
+                (None, None) => continue,
+            };
+            let value = takes_value(arg).then(|| value_name(arg));
+            if arg.is_required_set() {
+                synopsis.required_option(flag, value);
+            } else {
+                synopsis.option(flag, value);
+            }
+        }
+        if repeated {
+            synopsis.repeated();
+        }
+    }
+    if command.has_subcommands() {
+        if command.is_subcommand_required_set() {
+            synopsis.arg("COMMAND");
+        } else {
+            synopsis.optional_arg("COMMAND");
+        }
+    }
+    synopsis
+}
+
+fn man_option(arg: &Arg) -> ManOption<'static> {
+    let description: Vec<_> = help(arg)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, paragraph)| {
+            let separator = (i > 0).then(|| roman(" "));
+            separator.into_iter().chain([roman(paragraph)])
+        })
+        .collect();
+    let mut option = ManOption::new().description(description);
+    if let Some(short) = arg.get_short() {
+        option = option.short(short.to_string());
+    }
+    if let Some(long) = arg.get_long() {
+        option = option.long(long.to_owned());
+    }
+    if takes_value(arg) {
+        option = option.value_name(value_name(arg));
+        let defaults: Vec<_> = arg
+            .get_default_values()
+            .iter()
+            .map(|value| value.to_string_lossy().into_owned())
+            .collect();
+        if !defaults.is_empty() {
+            option = option.default_value(defaults.join(","));
+        }
+    }
+    option
+}
+
+fn takes_value(arg: &Arg) -> bool {
+    arg.get_action().takes_values()
+}
+
+/// The name of the value of an argument, as in the help of `clap`.
+fn value_name(arg: &Arg) -> String {
+    match arg.get_value_names() {
+        Some(names) if !names.is_empty() => names
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => arg.get_id().as_str().to_uppercase(),
+    }
+}
+
+fn help(arg: &Arg) -> Vec<String> {
+    arg.get_long_help()
+        .or_else(|| arg.get_help())
+        .map(|help| paragraphs(&help.to_string()))
+        .unwrap_or_default()
+}
+
+/// Split help text into paragraphs at blank lines, joining the lines
+/// of each paragraph.
+fn paragraphs(text: &str) -> Vec<String> {
+    text.split("\n\n")
+        .map(|paragraph| {
+            paragraph
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use ::clap::{Arg, ArgAction, Command};
+
+    use super::*;
+
+    #[test]
+    fn full_command() {
+        let command = Command::new("foo")
+            .version("1.0")
+            .about("do a foo thing")
+            .long_about("Foo does things.\n\nMany things.")
+            .author("Jane Doe")
+            .disable_help_flag(true)
+            .disable_version_flag(true)
+            .disable_help_subcommand(true)
+            .arg(
+                Arg::new("verbose")
+                    .short('v')
+                    .action(ArgAction::Count)
+                    .help("Be more verbose"),
+            )
+            .arg(
+                Arg::new("level")
+                    .long("level")
+                    .value_name("N")
+                    .help("Set the level.")
+                    .default_value("3"),
+            )
+            .arg(Arg::new("secret").long("secret").hide(true))
+            .arg(Arg::new("file").help("File to foo"))
+            .subcommand(Command::new("bar").about("Do a bar thing"));
+        assert_eq!(
+            man_page(&command).into_roff().to_roff(),
+            r#".TH FOO 1 "" "foo 1.0"
+.SH NAME
+foo \- do a foo thing
+.SH SYNOPSIS
+.SY foo
+[\fB\-v\fR]...
+.OP \-\-level N
+[\fIFILE\fR]
+[\fICOMMAND\fR]
+.YS
+.SH DESCRIPTION
+Foo does things.
+.PP
+Many things.
+.SH OPTIONS
+.TP
+\fB\-v\fR
+Be more verbose
+.TP
+\fB\-\-level\fR=\fIN\fR
+Set the level. The default is \fI3\fR.
+.SH ARGUMENTS
+.TP
+\fIFILE\fR
+File to foo
+.SH SUBCOMMANDS
+.TP
+\fBbar\fR
+Do a bar thing
+.SH AUTHORS
+Jane Doe
+"#
+        );
+    }
+
+    #[test]
+    fn help_paragraphs() {
+        assert_eq!(paragraphs("one\ntwo\n\n\nthree\n"), ["one two", "three"]);
+    }
+}
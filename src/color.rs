@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use crate::{Inline, Roff};
+
+impl<'a> Roff<'a> {
+    /// Define a color by its red, green, and blue components, with the
+    /// `defcolor` request.
+    ///
+    /// The color can then be used by name in
+    /// [`colored`] elements. GNU troff knows some names
+    /// already, such as `red`, `green`, and `blue`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.define_color("warning", 0xff, 0x80, 0x00)
+    ///     .text([colored("warning", [bold("Warning:")]), roman(" careful")]);
+    /// assert_eq!(
+    ///     doc.to_roff(),
+    ///     ".defcolor warning rgb #ff8000\n\
+    ///      \\m[warning]\\fBWarning:\\fR\\m[] careful\n"
+    /// );
+    /// ```
+    pub fn define_color(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        red: u8,
+        green: u8,
+        blue: u8,
+    ) -> &mut Self {
+        let rgb = format!("#{red:02x}{green:02x}{blue:02x}");
+        self.control("defcolor", [name.into(), "rgb".into(), rgb.into()])
+    }
+}
+
+/// Return inline elements in a color.
+///
+/// See [`Inline::Colored`].
+pub fn colored<'a>(
+    color: impl Into<Cow<'a, str>>,
+    inlines: impl Into<Vec<Inline<'a>>>,
+) -> Inline<'a> {
+    Inline::Colored {
+        color: color.into(),
+        inlines: inlines.into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_break, roman};
+
+    #[test]
+    fn nested_colors_restore_outer_color() {
+        let mut doc = Roff::new();
+        doc.text([colored(
+            "red",
+            [roman("a "), colored("blue", [roman("b")]), roman(" c")],
+        )]);
+        assert_eq!(doc.to_roff(), "\\m[red]a \\m[blue]b\\m[red] c\\m[]\n");
+    }
+
+    #[test]
+    fn line_break_in_color() {
+        let mut doc = Roff::new();
+        doc.text([colored("red", [roman("a"), line_break(), roman(".b")])]);
+        assert_eq!(doc.to_roff(), "\\m[red]a\n.br\n\\&.b\\m[]\n");
+    }
+}
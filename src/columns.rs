@@ -0,0 +1,85 @@
+use crate::{Distance, Roff, NO_ARGS};
+
+/// Columns set side by side, each of them a document, with the `mk`
+/// and `rt` requests.
+///
+/// This needs no macro package. Each column is `width` wide, with
+/// `gutter` between columns, and starts at the top of the block; the
+/// text after the block starts below the longest column. Text doesn't
+/// flow from one column to the next, so nothing is split across
+/// columns, but the block should fit on the page: put it in a
+/// [`keep`](crate::keep) for that.
+///
+/// The registers `cT` and `cB` and the page offset are set by the
+/// block, and the line length is restored after it.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut left = Roff::new();
+/// left.text([roman("left")]);
+/// let mut right = Roff::new();
+/// right.text([roman("right")]);
+/// let doc = columns(Distance::inches(3.0), Distance::inches(0.5), [left, right]);
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".br\n\
+///      .nr cO \\n(.o\n\
+///      .mk cT\n\
+///      .nr cB \\n(nl\n\
+///      .ll 3i\n\
+///      left\n\
+///      .br\n\
+///      .if \\n(nl>\\n(cB .nr cB \\n(nl\n\
+///      .rt \\n(cTu\n\
+///      .po +(3i+0.5i)\n\
+///      right\n\
+///      .br\n\
+///      .if \\n(nl>\\n(cB .nr cB \\n(nl\n\
+///      .po \\n(cOu\n\
+///      .ll\n\
+///      .sp |\\n(cBu\n"
+/// );
+/// ```
+pub fn columns<'a>(
+    width: Distance,
+    gutter: Distance,
+    columns: impl IntoIterator<Item = Roff<'a>>,
+) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.control("br", NO_ARGS)
+        .control("nr", ["cO", "\\n(.o"])
+        .control("mk", ["cT"])
+        .control("nr", ["cB", "\\n(nl"])
+        .control("ll", [width]);
+    for (i, column) in columns.into_iter().enumerate() {
+        if i > 0 {
+            doc.control("rt", ["\\n(cTu"])
+                .control("po", [format!("+({width}+{gutter})")]);
+        }
+        doc.append(column)
+            .control("br", NO_ARGS)
+            .control("if", ["\\n(nl>\\n(cB", ".nr", "cB", "\\n(nl"]);
+    }
+    doc.control("po", ["\\n(cOu"])
+        .control("ll", NO_ARGS)
+        .control("sp", ["|\\n(cBu"]);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::roman;
+
+    #[test]
+    fn one_column_is_not_moved() {
+        let mut column = Roff::new();
+        column.text([roman("only")]);
+        let doc = columns(Distance::ens(30.0), Distance::ens(2.0), [column]);
+        let roff = doc.to_roff();
+        assert!(!roff.contains(".rt"));
+        assert!(!roff.contains(".po +"));
+    }
+}
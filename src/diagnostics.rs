@@ -0,0 +1,293 @@
+use std::fmt;
+
+use crate::{cjk, Encoding, Line, RenderOptions, Roff, RoffVisitor, SpecialChar};
+
+/// Words at least this many columns wide can't be broken to fit the
+/// width of a terminal, with the indentation of a manual page.
+const LONG_WORD: usize = 60;
+
+/// Content of a document that renders in a way its author may not
+/// expect.
+///
+/// Returned by [`Roff::render_with_diagnostics`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RenderDiagnostic {
+    line: usize,
+    kind: RenderDiagnosticKind,
+}
+
+/// The kind of a [`RenderDiagnostic`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RenderDiagnosticKind {
+    /// Text has a backslash, which was escaped to print as a backslash
+    /// rather than start an escape such as `\fB`.
+    Backslash,
+
+    /// Text has a character outside of ASCII, with
+    /// [`Encoding::Ascii`], that isn't a [`SpecialChar`], so it is
+    /// written as a Unicode escape or, if the dialect has none, as it
+    /// is.
+    NonAscii(char),
+
+    /// Text has a tab character, which moves to the next tab stop
+    /// rather than printing as a space.
+    Tab,
+
+    /// Text has a word, this many columns wide on a terminal, that
+    /// can't be broken to fit its width.
+    ///
+    /// Characters of Chinese, Japanese, and Korean are two columns
+    /// wide. Lines may be broken between them with
+    /// [`RenderOptions::cjk_line_breaks`].
+    LongWord(usize),
+
+    /// Text is in a script written from right to left, such as Arabic
+    /// or Hebrew.
+    ///
+    /// ROFF implementations lay out text from left to right only, in
+    /// the order it is written in, so words of these scripts come out
+    /// with their characters reversed, unless the terminal showing the
+    /// output reorders them.
+    RightToLeft,
+}
+
+impl RenderDiagnostic {
+    /// Index of the line in the document, starting at zero.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// What is surprising about the line.
+    pub fn kind(&self) -> &RenderDiagnosticKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for RenderDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line + 1)?;
+        match self.kind {
+            RenderDiagnosticKind::Backslash => {
+                write!(f, "backslash is printed, rather than starting an escape")
+            }
+            RenderDiagnosticKind::NonAscii(c) => {
+                write!(f, "{c:?} is not ASCII and may not print with ASCII output")
+            }
+            RenderDiagnosticKind::Tab => write!(f, "tab moves to the next tab stop"),
+            RenderDiagnosticKind::LongWord(len) => {
+                write!(f, "word of {len} columns can't be broken to fit")
+            }
+            RenderDiagnosticKind::RightToLeft => {
+                write!(f, "right-to-left text is laid out from left to right")
+            }
+        }
+    }
+}
+
+impl Roff<'_> {
+    /// Render as ROFF source text, with custom [`RenderOptions`], along
+    /// with diagnostics for content that renders in a surprising way.
+    ///
+    /// This is for programs generating documents from text their users
+    /// write, to show them where the output differs from what they may
+    /// expect. Only text lines are checked, as the arguments of control
+    /// lines are written as they are; each line has at most one
+    /// diagnostic of each kind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.text([roman(r"Use \fB for bold.")])
+    ///     .text([roman("Caf\u{e9}\tbar")]);
+    /// let options = RenderOptions::new().encoding(Encoding::Ascii);
+    /// let (output, diagnostics) = doc.render_with_diagnostics(&options);
+    /// assert_eq!(output, doc.render_with(&options));
+    /// let kinds: Vec<_> = diagnostics.iter().map(|d| (d.line(), d.kind().clone())).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         (0, RenderDiagnosticKind::Backslash),
+    ///         (1, RenderDiagnosticKind::NonAscii('\u{e9}')),
+    ///         (1, RenderDiagnosticKind::Tab),
+    ///     ]
+    /// );
+    /// ```
+    pub fn render_with_diagnostics(
+        &self,
+        options: &RenderOptions,
+    ) -> (String, Vec<RenderDiagnostic>) {
+        let mut diagnostics = vec![];
+        for (index, line) in self.lines.iter().enumerate() {
+            if let Line::Text(inlines) = line {
+                let mut audit = Audit {
+                    ascii: options.encoding == Encoding::Ascii,
+                    cjk_line_breaks: options.cjk_line_breaks,
+                    kinds: vec![],
+                    word: 0,
+                };
+                audit.visit_text(inlines);
+                audit.end_word();
+                diagnostics.extend(
+                    audit
+                        .kinds
+                        .into_iter()
+                        .map(|kind| RenderDiagnostic { line: index, kind }),
+                );
+            }
+        }
+        (self.render_with(options), diagnostics)
+    }
+}
+
+/// Finds the surprising content of a text line.
+struct Audit {
+    ascii: bool,
+    cjk_line_breaks: bool,
+    kinds: Vec<RenderDiagnosticKind>,
+    /// Width of the word so far, in columns.
+    word: usize,
+}
+
+impl Audit {
+    fn add(&mut self, kind: RenderDiagnosticKind) {
+        let same = |other: &RenderDiagnosticKind| {
+            std::mem::discriminant(other) == std::mem::discriminant(&kind)
+        };
+        if !self.kinds.iter().any(same) {
+            self.kinds.push(kind);
+        }
+    }
+
+    fn check(&mut self, text: &str) {
+        for c in text.chars() {
+            if c.is_whitespace() || (self.cjk_line_breaks && cjk::is_wide(c)) {
+                self.end_word();
+            }
+            if !c.is_whitespace() {
+                self.word += cjk::width(c);
+            }
+            if is_right_to_left(c) {
+                self.add(RenderDiagnosticKind::RightToLeft);
+            }
+            match c {
+                '\\' => self.add(RenderDiagnosticKind::Backslash),
+                '\t' => self.add(RenderDiagnosticKind::Tab),
+                c if self.ascii && !c.is_ascii() && SpecialChar::from_char(c).is_none() => {
+                    self.add(RenderDiagnosticKind::NonAscii(c));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn end_word(&mut self) {
+        if self.word >= LONG_WORD {
+            self.add(RenderDiagnosticKind::LongWord(self.word));
+        }
+        self.word = 0;
+    }
+}
+
+/// Is the character of a script written from right to left?
+///
+/// These are the blocks of the Hebrew, Arabic, Syriac, Thaana, and N'Ko
+/// scripts, and their presentation forms.
+fn is_right_to_left(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{07FF}'
+            | '\u{0860}'..='\u{08FF}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+    )
+}
+
+impl RoffVisitor for Audit {
+    fn visit_roman(&mut self, text: &str) {
+        self.check(text);
+    }
+
+    fn visit_italic(&mut self, text: &str) {
+        self.check(text);
+    }
+
+    fn visit_bold(&mut self, text: &str) {
+        self.check(text);
+    }
+
+    fn visit_special(&mut self, _: SpecialChar) {
+        self.word += 1;
+    }
+
+    fn visit_line_break(&mut self) {
+        self.end_word();
+    }
+
+    fn visit_hyphenation_point(&mut self) {
+        self.end_word();
+    }
+
+    fn visit_break_point(&mut self) {
+        self.end_word();
+    }
+
+    fn visit_tab(&mut self) {
+        self.end_word();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bold, break_point, roman};
+
+    #[test]
+    fn wide_characters() {
+        let mut doc = Roff::new();
+        doc.text([roman("\u{65e5}".repeat(30))]);
+        let (_, diagnostics) = doc.render_with_diagnostics(&RenderOptions::new());
+        assert_eq!(diagnostics[0].kind(), &RenderDiagnosticKind::LongWord(60));
+        let options = RenderOptions::new().cjk_line_breaks(true);
+        assert!(doc.render_with_diagnostics(&options).1.is_empty());
+    }
+
+    #[test]
+    fn long_word_across_fonts() {
+        let long = "x".repeat(40);
+        let mut doc = Roff::new();
+        doc.text([bold(long.clone()), roman(long.clone())])
+            .text([bold(long.clone()), break_point(), roman(long)])
+            .text([roman("na\u{ef}ve")])
+            .text([roman("\u{5e9}\u{5dc}\u{5d5}\u{5dd}")]);
+        let options = RenderOptions::new().encoding(Encoding::Ascii);
+        let (_, diagnostics) = doc.render_with_diagnostics(&options);
+        assert_eq!(
+            diagnostics,
+            [
+                RenderDiagnostic {
+                    line: 0,
+                    kind: RenderDiagnosticKind::LongWord(80)
+                },
+                RenderDiagnostic {
+                    line: 2,
+                    kind: RenderDiagnosticKind::NonAscii('\u{ef}')
+                },
+                RenderDiagnostic {
+                    line: 3,
+                    kind: RenderDiagnosticKind::RightToLeft
+                },
+                RenderDiagnostic {
+                    line: 3,
+                    kind: RenderDiagnosticKind::NonAscii('\u{5e9}')
+                },
+            ]
+        );
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "line 3: '\u{ef}' is not ASCII and may not print with ASCII output"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt;
+
+use crate::edit::normalize_inlines;
+use crate::{Line, LineRef, Roff};
+
+/// A difference between two documents, as found by [`diff`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Change<'r> {
+    /// A line of the new document that isn't in the old one.
+    Added {
+        /// The section of the new document the line is in.
+        section: Option<String>,
+
+        /// The index of the line in the new document.
+        index: usize,
+
+        /// The line.
+        line: LineRef<'r>,
+    },
+
+    /// A line of the old document that isn't in the new one.
+    Removed {
+        /// The section of the old document the line was in.
+        section: Option<String>,
+
+        /// The index of the line in the old document.
+        index: usize,
+
+        /// The line.
+        line: LineRef<'r>,
+    },
+
+    /// A line that was replaced by one of the same kind: a text line by
+    /// a text line, or a control line by one with the same name.
+    Modified {
+        /// The section of the new document the line is in.
+        section: Option<String>,
+
+        /// The index of the line in the old document.
+        old_index: usize,
+
+        /// The index of the line in the new document.
+        new_index: usize,
+
+        /// The line in the old document.
+        old: LineRef<'r>,
+
+        /// The line in the new document.
+        new: LineRef<'r>,
+    },
+}
+
+impl Change<'_> {
+    /// The name of the section the change is in, if it's in one, as
+    /// started by the `SH` macro.
+    pub fn section(&self) -> Option<&str> {
+        match self {
+            Self::Added { section, .. }
+            | Self::Removed { section, .. }
+            | Self::Modified { section, .. } => section.as_deref(),
+        }
+    }
+}
+
+impl fmt::Display for Change<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { index, .. } => write!(f, "line {} added", index + 1)?,
+            Self::Removed { index, .. } => write!(f, "line {} removed", index + 1)?,
+            Self::Modified {
+                old_index,
+                new_index,
+                ..
+            } => write!(
+                f,
+                "line {} modified as line {}",
+                old_index + 1,
+                new_index + 1
+            )?,
+        }
+        if let Some(section) = self.section() {
+            write!(f, " in section {section}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare two documents by their structure, line by line.
+///
+/// Lines are equal if they are control lines with the same name and
+/// arguments, or text lines with the same inline elements once
+/// [normalized](Roff::normalize), so text split differently in the
+/// same fonts isn't a change. The changes are in the order of the
+/// lines; each line of the new document that replaces one of the old
+/// document of the same kind is [`Change::Modified`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut old = Roff::new();
+/// old.control("SH", ["NAME"])
+///     .text([roman("foo - do a thing")])
+///     .control("SH", ["OPTIONS"]);
+/// let mut new = Roff::new();
+/// new.control("SH", ["NAME"])
+///     .text([roman("foo - do a "), roman("foo thing")])
+///     .control("SH", ["OPTIONS"])
+///     .control("TP", NO_ARGS);
+/// let changes = diff(&old, &new);
+/// assert_eq!(
+///     changes.iter().map(|change| change.to_string()).collect::<Vec<_>>(),
+///     [
+///         "line 2 modified as line 2 in section NAME",
+///         "line 4 added in section OPTIONS",
+///     ]
+/// );
+/// assert!(diff(&old, &old).is_empty());
+/// ```
+pub fn diff<'r>(old: &'r Roff<'_>, new: &'r Roff<'_>) -> Vec<Change<'r>> {
+    let old_lines: Vec<_> = old.lines.iter().map(normalized).collect();
+    let new_lines: Vec<_> = new.lines.iter().map(normalized).collect();
+    let old_sections = sections(&old.lines);
+    let new_sections = sections(&new.lines);
+
+    // The length of the longest common subsequence of the lines from
+    // each pair of indexes on.
+    let width = new_lines.len() + 1;
+    let mut lengths = vec![0u32; (old_lines.len() + 1) * width];
+    for i in (0..old_lines.len()).rev() {
+        for j in (0..new_lines.len()).rev() {
+            lengths[i * width + j] = if old_lines[i] == new_lines[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut changes = vec![];
+    let mut removed: Vec<usize> = vec![];
+    let mut added: Vec<usize> = vec![];
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let at_end = i == old_lines.len() && j == new_lines.len();
+        let same = i < old_lines.len() && j < new_lines.len() && old_lines[i] == new_lines[j];
+        if at_end || same {
+            // Pair the lines removed and added between the same lines.
+            let mut added = added.drain(..).peekable();
+            for old_index in removed.drain(..) {
+                let old_line = LineRef::new(&old.lines[old_index]);
+                match added
+                    .next_if(|&new_index| same_kind(old_line, LineRef::new(&new.lines[new_index])))
+                {
+                    Some(new_index) => changes.push(Change::Modified {
+                        section: new_sections[new_index].clone(),
+                        old_index,
+                        new_index,
+                        old: old_line,
+                        new: LineRef::new(&new.lines[new_index]),
+                    }),
+                    None => changes.push(Change::Removed {
+                        section: old_sections[old_index].clone(),
+                        index: old_index,
+                        line: old_line,
+                    }),
+                }
+            }
+            for index in added {
+                changes.push(Change::Added {
+                    section: new_sections[index].clone(),
+                    index,
+                    line: LineRef::new(&new.lines[index]),
+                });
+            }
+            if at_end {
+                break;
+            }
+            i += 1;
+            j += 1;
+        } else if j == new_lines.len()
+            || i < old_lines.len() && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1]
+        {
+            removed.push(i);
+            i += 1;
+        } else {
+            added.push(j);
+            j += 1;
+        }
+    }
+    changes
+}
+
+/// A line as it is compared, with its inline elements normalized.
+fn normalized<'r>(line: &'r Line<'_>) -> Line<'r> {
+    match line.reborrow() {
+        Line::Text(inlines) => Line::Text(normalize_inlines(inlines)),
+        control => control,
+    }
+}
+
+fn same_kind(old: LineRef<'_>, new: LineRef<'_>) -> bool {
+    old.name() == new.name()
+}
+
+/// The name of the section each line is in.
+fn sections(lines: &[Line<'_>]) -> Vec<Option<String>> {
+    let mut section = None;
+    lines
+        .iter()
+        .map(|line| {
+            let line = LineRef::new(line);
+            if line.is_control("SH") {
+                section = Some(line.args().join(" "));
+            }
+            section.clone()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{roman, NO_ARGS};
+
+    #[test]
+    fn replaced_by_other_kind() {
+        let mut old = Roff::new();
+        old.control("PP", NO_ARGS).text([roman("a")]);
+        let mut new = Roff::new();
+        new.control("TP", NO_ARGS).text([roman("a")]);
+        let changes = diff(&old, &new);
+        assert_eq!(
+            changes,
+            [
+                Change::Removed {
+                    section: None,
+                    index: 0,
+                    line: LineRef::new(&old.lines[0]),
+                },
+                Change::Added {
+                    section: None,
+                    index: 0,
+                    line: LineRef::new(&new.lines[0]),
+                },
+            ]
+        );
+    }
+}
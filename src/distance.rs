@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Roff, NO_ARGS};
+
+/// A unit of distance, as understood by ROFF.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Unit {
+    /// Inches: `i`.
+    Inch,
+
+    /// Centimeters: `c`.
+    Centimeter,
+
+    /// Points, 72 to the inch: `p`.
+    Point,
+
+    /// Picas, 6 to the inch: `P`.
+    Pica,
+
+    /// Ems, the width of the letter "m" in the current font: `m`.
+    Em,
+
+    /// Ens, half an em: `n`. On terminals, this is one character.
+    En,
+
+    /// Vertical spacing, the distance between output lines: `v`.
+    Line,
+
+    /// Basic units of the output device: `u`.
+    Basic,
+}
+
+impl Unit {
+    /// The unit's letter, as appended to a number.
+    pub fn suffix(self) -> char {
+        match self {
+            Self::Inch => 'i',
+            Self::Centimeter => 'c',
+            Self::Point => 'p',
+            Self::Pica => 'P',
+            Self::Em => 'm',
+            Self::En => 'n',
+            Self::Line => 'v',
+            Self::Basic => 'u',
+        }
+    }
+
+    /// The unit with a letter, if there is one.
+    pub fn from_suffix(suffix: char) -> Option<Self> {
+        let unit = match suffix {
+            'i' => Self::Inch,
+            'c' => Self::Centimeter,
+            'p' => Self::Point,
+            'P' => Self::Pica,
+            'm' => Self::Em,
+            'n' => Self::En,
+            'v' => Self::Line,
+            'u' => Self::Basic,
+            _ => return None,
+        };
+        Some(unit)
+    }
+}
+
+/// A distance, such as `1v` or `0.5i`, for requests that take one.
+///
+/// A distance is rendered as a number followed by the letter of its
+/// unit. It can also be parsed from that form, which checks that the
+/// unit is one ROFF knows. Distances convert into strings, so they can
+/// be given wherever a request takes a distance as a string, such as
+/// [`Roff::indent`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// assert_eq!(Distance::inches(0.5).to_string(), "0.5i");
+/// assert_eq!("-1v".parse::<Distance>().unwrap(), Distance::lines(-1.0));
+/// assert!("1x".parse::<Distance>().is_err());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Distance {
+    value: f64,
+    unit: Unit,
+}
+
+impl Distance {
+    /// A distance in some unit.
+    pub fn new(value: f64, unit: Unit) -> Self {
+        Self { value, unit }
+    }
+
+    /// A distance in inches.
+    pub fn inches(value: f64) -> Self {
+        Self::new(value, Unit::Inch)
+    }
+
+    /// A distance in centimeters.
+    pub fn centimeters(value: f64) -> Self {
+        Self::new(value, Unit::Centimeter)
+    }
+
+    /// A distance in points.
+    pub fn points(value: f64) -> Self {
+        Self::new(value, Unit::Point)
+    }
+
+    /// A distance in ems.
+    pub fn ems(value: f64) -> Self {
+        Self::new(value, Unit::Em)
+    }
+
+    /// A distance in ens, or characters on terminals.
+    pub fn ens(value: f64) -> Self {
+        Self::new(value, Unit::En)
+    }
+
+    /// A distance in output lines.
+    pub fn lines(value: f64) -> Self {
+        Self::new(value, Unit::Line)
+    }
+
+    /// The number of units.
+    pub fn value(self) -> f64 {
+        self.value
+    }
+
+    /// The unit.
+    pub fn unit(self) -> Unit {
+        self.unit
+    }
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit.suffix())
+    }
+}
+
+impl FromStr for Distance {
+    type Err = InvalidDistance;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidDistance(s.to_owned());
+        let suffix = s.chars().next_back().ok_or_else(invalid)?;
+        let unit = Unit::from_suffix(suffix).ok_or_else(invalid)?;
+        let number = &s[..s.len() - suffix.len_utf8()];
+        // Rust accepts forms that ROFF doesn't, such as "inf" and "1e3".
+        if !number
+            .trim_start_matches(['-', '+'])
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.')
+        {
+            return Err(invalid());
+        }
+        let value = number.parse().map_err(|_| invalid())?;
+        Ok(Self::new(value, unit))
+    }
+}
+
+impl From<Distance> for Cow<'_, str> {
+    fn from(distance: Distance) -> Self {
+        Cow::Owned(distance.to_string())
+    }
+}
+
+/// Text that isn't a [`Distance`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidDistance(String);
+
+impl fmt::Display for InvalidDistance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a number followed by a unit", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDistance {}
+
+impl<'a> Roff<'a> {
+    /// Leave vertical space, with the `sp` request.
+    ///
+    /// This also breaks the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.space(Distance::lines(1.0)).space(Distance::inches(0.5));
+    /// assert_eq!(doc.to_roff(), ".sp 1v\n.sp 0.5i\n");
+    /// ```
+    pub fn space(&mut self, distance: Distance) -> &mut Self {
+        self.control("sp", [distance])
+    }
+
+    /// Start a new page, with the `bp` request.
+    ///
+    /// This only matters for paged output, such as PostScript and PDF;
+    /// terminals have no pages.
+    pub fn page_break(&mut self) -> &mut Self {
+        self.control("bp", NO_ARGS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_distances() {
+        assert_eq!("1v".parse(), Ok(Distance::lines(1.0)));
+        assert_eq!("+2.5n".parse(), Ok(Distance::ens(2.5)));
+        assert_eq!("3P".parse(), Ok(Distance::new(3.0, Unit::Pica)));
+        for invalid in ["", "v", "1", "1x", "infv", "1e3i", "1 i"] {
+            assert!(invalid.parse::<Distance>().is_err(), "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn distances_as_arguments() {
+        let mut doc = Roff::new();
+        doc.indent(Distance::ens(4.0)).page_break();
+        assert_eq!(doc.to_roff(), ".in 4n\n.bp\n");
+    }
+}
@@ -0,0 +1,369 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::{Inline, Line, LineRef, Roff};
+
+impl<'a> Roff<'a> {
+    /// Replace every inline element of every text line.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.text([roman("foo "), italic("bar")]);
+    /// doc.map_inlines(|inline| match inline {
+    ///     Inline::Italic(text) => Inline::Roman(text),
+    ///     other => other,
+    /// });
+    /// assert_eq!(doc.to_roff(), "foo bar\n");
+    /// ```
+    pub fn map_inlines(&mut self, mut f: impl FnMut(Inline<'a>) -> Inline<'a>) -> &mut Self {
+        for line in self.lines.iter_mut() {
+            if let Line::Text(inlines) = line {
+                *inlines = std::mem::take(inlines).into_iter().map(&mut f).collect();
+            }
+        }
+        self
+    }
+
+    /// Replace every argument of every control line.
+    ///
+    /// The function is given the name of the control line and the
+    /// argument.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["See also"]);
+    /// doc.map_args(|name, arg| match name {
+    ///     "SH" => arg.to_uppercase().into(),
+    ///     _ => arg,
+    /// });
+    /// assert_eq!(doc.to_roff(), ".SH \"SEE ALSO\"\n");
+    /// ```
+    pub fn map_args(&mut self, mut f: impl FnMut(&str, Cow<'a, str>) -> Cow<'a, str>) -> &mut Self {
+        for line in self.lines.iter_mut() {
+            if let Line::Control { name, args } = line {
+                for arg in args.iter_mut() {
+                    *arg = f(name, std::mem::take(arg));
+                }
+            }
+        }
+        self
+    }
+
+    /// Keep only the lines for which the function returns `true`.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("TH", ["FOO", "1"])
+    ///     .control("br", NO_ARGS)
+    ///     .text([roman("foo")]);
+    /// doc.retain_lines(|line| !line.is_control("br"));
+    /// assert_eq!(doc.to_roff(), ".TH FOO 1\nfoo\n");
+    /// ```
+    pub fn retain_lines(&mut self, mut f: impl FnMut(LineRef<'_>) -> bool) -> &mut Self {
+        self.lines.retain(|line| f(LineRef::new(line)));
+        self
+    }
+
+    /// Insert a control line before the line at an index, or at the
+    /// end if the index is the number of lines.
+    ///
+    /// # Panics
+    ///
+    /// If the index is greater than the number of lines.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("TH", ["FOO", "1"]).text([roman("foo")]);
+    /// doc.insert_control(1, "SH", ["NAME"]);
+    /// assert_eq!(doc.to_roff(), ".TH FOO 1\n.SH NAME\nfoo\n");
+    /// ```
+    pub fn insert_control(
+        &mut self,
+        index: usize,
+        name: impl Into<Cow<'a, str>>,
+        args: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        let line = Line::control(name.into(), args.into_iter().map(Into::into).collect());
+        self.lines.insert(index, line);
+        self
+    }
+
+    /// Insert a text line before the line at an index, or at the end
+    /// if the index is the number of lines.
+    ///
+    /// # Panics
+    ///
+    /// If the index is greater than the number of lines.
+    pub fn insert_text(&mut self, index: usize, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.lines.insert(index, Line::text(inlines.into()));
+        self
+    }
+
+    /// Insert all lines of another document before the line at an
+    /// index, or at the end if the index is the number of lines.
+    ///
+    /// # Panics
+    ///
+    /// If the index is greater than the number of lines.
+    pub fn insert(&mut self, index: usize, doc: Roff<'a>) -> &mut Self {
+        self.lines.splice(index..index, doc.lines);
+        self
+    }
+
+    /// Remove the line at an index.
+    ///
+    /// # Panics
+    ///
+    /// If there is no line at the index.
+    pub fn remove_line(&mut self, index: usize) -> &mut Self {
+        self.lines.remove(index);
+        self
+    }
+
+    /// Replace a section, from its `SH` line up to the next one, by
+    /// the lines of another document, which usually starts with an
+    /// `SH` line of its own.
+    ///
+    /// This returns `false`, and leaves the document as it is, if it
+    /// has no section with the name. The name is compared with the
+    /// arguments of each `SH` line, joined by spaces.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["NAME"])
+    ///     .text([roman("foo")])
+    ///     .control("SH", ["SEE", "ALSO"])
+    ///     .text([roman("bar(1)")]);
+    /// let mut patches = Roff::new();
+    /// patches.control("SH", ["PATCHES"]).text([roman("Fixes a bug.")]);
+    /// assert!(doc.replace_section("NAME", patches));
+    /// assert!(!doc.replace_section("NAME", Roff::new()));
+    /// assert_eq!(
+    ///     doc.to_roff(),
+    ///     ".SH PATCHES\nFixes a bug.\n.SH SEE ALSO\nbar(1)\n"
+    /// );
+    /// ```
+    pub fn replace_section(&mut self, name: &str, doc: Roff<'a>) -> bool {
+        match self.section_range(name) {
+            Some(range) => {
+                self.lines.splice(range, doc.lines);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The indexes of the lines of a section, from its `SH` line up to
+    /// the next `SH` line or the end.
+    pub(crate) fn section_range(&self, name: &str) -> Option<Range<usize>> {
+        let is_section = |line: &Line<'_>| LineRef::new(line).is_control("SH");
+        let start = self.lines.iter().position(|line| {
+            let line = LineRef::new(line);
+            line.is_control("SH") && line.args().join(" ") == name
+        })?;
+        let end = self.lines[start + 1..]
+            .iter()
+            .position(is_section)
+            .map(|i| start + 1 + i)
+            .unwrap_or(self.lines.len());
+        Some(start..end)
+    }
+
+    /// Bring the document into a canonical form.
+    ///
+    /// Consecutive inline elements of a text line that are in the
+    /// same font are merged, and empty ones are removed. This doesn't
+    /// change how the document is typeset, but makes documents that
+    /// would be typeset the same way more likely to compare equal,
+    /// and the output smaller.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.text([bold("foo"), bold("bar"), roman(""), roman("baz")]);
+    /// doc.normalize();
+    /// let mut expected = Roff::new();
+    /// expected.text([bold("foobar"), roman("baz")]);
+    /// assert_eq!(doc, expected);
+    /// ```
+    pub fn normalize(&mut self) -> &mut Self {
+        for line in self.lines.iter_mut() {
+            if let Line::Text(inlines) = line {
+                *inlines = normalize_inlines(std::mem::take(inlines));
+            }
+        }
+        self
+    }
+
+    /// Remove lines that are redundant with the line before, as often
+    /// found where fragments of generated documents are joined.
+    ///
+    /// Of consecutive `br` requests, consecutive empty text lines, and
+    /// consecutive paragraph macros, `PP`, `LP`, or `P`, only the first
+    /// is kept. A `br` request just before a paragraph macro is removed
+    /// too, since the paragraph breaks the line anyway.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.text([roman("foo")])
+    ///     .control("br", NO_ARGS)
+    ///     .control("PP", NO_ARGS)
+    ///     .control("PP", NO_ARGS)
+    ///     .text([roman("bar")])
+    ///     .control("br", NO_ARGS)
+    ///     .control("br", NO_ARGS);
+    /// doc.tidy();
+    /// assert_eq!(doc.to_roff(), "foo\n.PP\nbar\n.br\n");
+    /// ```
+    pub fn tidy(&mut self) -> &mut Self {
+        let mut tidy: Vec<Line<'a>> = Vec::with_capacity(self.lines.len());
+        for line in std::mem::take(&mut self.lines) {
+            if is_paragraph(&line) {
+                if tidy.last().is_some_and(Line::is_break) {
+                    tidy.pop();
+                }
+                if tidy.last().is_some_and(is_paragraph) {
+                    continue;
+                }
+            }
+            let repeated = match tidy.last() {
+                Some(prev) => {
+                    (prev.is_break() && line.is_break()) || (is_empty(prev) && is_empty(&line))
+                }
+                None => false,
+            };
+            if !repeated {
+                tidy.push(line);
+            }
+        }
+        self.lines = tidy;
+        self
+    }
+}
+
+/// Is this a macro starting a paragraph?
+pub(crate) fn is_paragraph(line: &Line<'_>) -> bool {
+    matches!(line, Line::Control { name, args }
+        if args.is_empty() && matches!(name.as_ref(), "PP" | "LP" | "P"))
+}
+
+/// Is this a text line with no text?
+fn is_empty(line: &Line<'_>) -> bool {
+    match line {
+        Line::Text(inlines) => inlines.iter().all(|inline| {
+            matches!(inline, Inline::Roman(text) | Inline::Italic(text) | Inline::Bold(text)
+                if text.is_empty())
+        }),
+        Line::Control { .. } => false,
+    }
+}
+
+/// Merge consecutive inline elements in the same font, and remove
+/// empty ones.
+pub(crate) fn normalize_inlines(inlines: Vec<Inline<'_>>) -> Vec<Inline<'_>> {
+    let mut normalized: Vec<Inline<'_>> = Vec::with_capacity(inlines.len());
+    for inline in inlines {
+        match (normalized.last_mut(), inline) {
+            (_, Inline::Roman(text) | Inline::Italic(text) | Inline::Bold(text))
+                if text.is_empty() => {}
+            (Some(Inline::Roman(prev)), Inline::Roman(text))
+            | (Some(Inline::Italic(prev)), Inline::Italic(text))
+            | (Some(Inline::Bold(prev)), Inline::Bold(text)) => prev.to_mut().push_str(&text),
+            (_, Inline::Colored { color, inlines }) => normalized.push(Inline::Colored {
+                color,
+                inlines: normalize_inlines(inlines),
+            }),
+            (_, Inline::Resized { change, inlines }) => normalized.push(Inline::Resized {
+                change,
+                inlines: normalize_inlines(inlines),
+            }),
+            (_, Inline::Superscript(inlines)) => {
+                normalized.push(Inline::Superscript(normalize_inlines(inlines)));
+            }
+            (_, Inline::Subscript(inlines)) => {
+                normalized.push(Inline::Subscript(normalize_inlines(inlines)));
+            }
+            (_, inline) => normalized.push(inline),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bold, italic, line_break, roman, NO_ARGS};
+
+    #[test]
+    fn redact_text() {
+        let mut doc = Roff::new();
+        doc.control("SH", ["NAME"])
+            .text([roman("secret"), bold("secret")]);
+        doc.map_inlines(|inline| match inline {
+            Inline::Roman(text) if text == "secret" => roman("***"),
+            Inline::Bold(text) if text == "secret" => bold("***"),
+            other => other,
+        });
+        assert_eq!(doc.to_roff(), ".SH NAME\n***\\fB***\\fR\n");
+    }
+
+    #[test]
+    fn retain_text_lines() {
+        let mut doc = Roff::new();
+        doc.control("SH", ["NAME"])
+            .text([roman("foo")])
+            .text([roman("bar")]);
+        doc.retain_lines(|line| line.inlines() != [roman("bar")]);
+        assert_eq!(doc.to_roff(), ".SH NAME\nfoo\n");
+    }
+
+    #[test]
+    fn insert_and_remove_lines() {
+        let mut doc = Roff::new();
+        doc.control("SH", ["NAME"]).text([roman("foo")]);
+        let mut extra = Roff::new();
+        extra.control("PP", NO_ARGS).text([roman("bar")]);
+        doc.insert(2, extra)
+            .insert_text(0, [roman("first")])
+            .remove_line(1);
+        assert_eq!(doc.to_roff(), "first\nfoo\n.PP\nbar\n");
+    }
+
+    #[test]
+    fn tidy_keeps_one_empty_line() {
+        let mut doc = Roff::new();
+        doc.text([roman("foo")])
+            .text([] as [Inline<'_>; 0])
+            .text([roman("")])
+            .control("LP", NO_ARGS)
+            .control("P", NO_ARGS)
+            .text([roman("bar")]);
+        doc.tidy();
+        assert_eq!(doc.to_roff(), "foo\n\n.LP\nbar\n");
+    }
+
+    #[test]
+    fn normalize_merges_same_font() {
+        let mut doc = Roff::new();
+        doc.text([
+            roman("a"),
+            roman("b"),
+            italic("c"),
+            italic(""),
+            italic("d"),
+            line_break(),
+            roman("e"),
+            bold(""),
+        ]);
+        doc.normalize();
+        let mut expected = Roff::new();
+        expected.text([roman("ab"), italic("cd"), line_break(), roman("e")]);
+        assert_eq!(doc, expected);
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+/// An error from building or rendering a [`Roff`](crate::Roff).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A control line has a name that would produce broken output.
+    InvalidName(InvalidName),
+
+    /// Writing the output failed.
+    Io(std::io::Error),
+
+    /// Reading a document in the JSON format failed.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+
+    /// A document in the JSON format has a version that isn't known.
+    #[cfg(feature = "json")]
+    UnsupportedJsonVersion(u32),
+
+    /// Reading a manifest failed.
+    #[cfg(feature = "manifest")]
+    Manifest(toml::de::Error),
+
+    /// A section of the manual isn't valid.
+    InvalidManSection(crate::man::InvalidManSection),
+
+    /// `groff` failed, with this message.
+    #[cfg(feature = "groff")]
+    Groff(String),
+
+    /// The `SOURCE_DATE_EPOCH` environment variable isn't a valid
+    /// timestamp.
+    #[cfg(feature = "time")]
+    SourceDateEpoch(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(e) => e.fmt(f),
+            Self::Io(e) => write!(f, "failed to write output: {e}"),
+            #[cfg(feature = "json")]
+            Self::Json(e) => write!(f, "invalid JSON document: {e}"),
+            #[cfg(feature = "json")]
+            Self::UnsupportedJsonVersion(version) => {
+                write!(f, "unsupported JSON document version {version}")
+            }
+            #[cfg(feature = "manifest")]
+            Self::Manifest(e) => write!(f, "invalid manifest: {e}"),
+            Self::InvalidManSection(e) => e.fmt(f),
+            #[cfg(feature = "groff")]
+            Self::Groff(message) => write!(f, "groff failed: {message}"),
+            #[cfg(feature = "time")]
+            Self::SourceDateEpoch(value) => {
+                write!(f, "invalid SOURCE_DATE_EPOCH {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidName(e) => Some(e),
+            Self::Io(e) => Some(e),
+            #[cfg(feature = "json")]
+            Self::Json(e) => Some(e),
+            #[cfg(feature = "json")]
+            Self::UnsupportedJsonVersion(_) => None,
+            #[cfg(feature = "manifest")]
+            Self::Manifest(e) => Some(e),
+            Self::InvalidManSection(e) => Some(e),
+            #[cfg(feature = "groff")]
+            Self::Groff(_) => None,
+            #[cfg(feature = "time")]
+            Self::SourceDateEpoch(_) => None,
+        }
+    }
+}
+
+impl From<InvalidName> for Error {
+    fn from(e: InvalidName) -> Self {
+        Self::InvalidName(e)
+    }
+}
+
+impl From<crate::man::InvalidManSection> for Error {
+    fn from(e: crate::man::InvalidManSection) -> Self {
+        Self::InvalidManSection(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+#[cfg(feature = "manifest")]
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Self::Manifest(e)
+    }
+}
+
+/// A control line whose name would produce broken output.
+///
+/// Returned by [`Roff::validate`](crate::Roff::validate).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidName {
+    index: usize,
+    name: String,
+    reason: &'static str,
+}
+
+impl InvalidName {
+    pub(crate) fn new(index: usize, name: &str, reason: &'static str) -> Self {
+        Self {
+            index,
+            name: name.to_owned(),
+            reason,
+        }
+    }
+
+    /// Index of the offending line in the document, starting at zero.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The offending name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for InvalidName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: invalid control name {:?}: {}",
+            self.index + 1,
+            self.name,
+            self.reason
+        )
+    }
+}
+
+impl std::error::Error for InvalidName {}
+
+/// Check that a control name can be rendered as is.
+///
+/// Returns the reason the name is invalid. The end of a conditional
+/// block, `\}`, is allowed on a line of its own, as in `.\}`.
+pub(crate) fn check_name(name: &str) -> Result<(), &'static str> {
+    if name == r"\}" {
+        Ok(())
+    } else if name.is_empty() {
+        Err("name is empty")
+    } else if !name.is_ascii() {
+        Err("name contains non-ASCII characters")
+    } else if name.contains(|c: char| c.is_ascii_whitespace()) {
+        Err("name contains whitespace")
+    } else if name.contains(|c: char| c.is_ascii_control()) {
+        Err("name contains control characters")
+    } else if name.contains('"') {
+        Err("name contains a double quote")
+    } else if name.contains('\\') {
+        Err("name contains a backslash")
+    } else {
+        Ok(())
+    }
+}
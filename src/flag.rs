@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use crate::{bold, Inline};
+
+/// Return a command-line flag, such as `--bits`, as inline elements.
+///
+/// The flag is in bold, as is conventional, and kept from being
+/// hyphenated, so that it can't be mistaken for a flag with a hyphen
+/// in it. Its dashes are minus signs, as are all dashes in text, so
+/// that the flag can be copied from typeset output.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.text([vec![roman("Use ")], flag("--bits"), vec![roman(" to set it.")]].concat());
+/// assert_eq!(doc.to_roff(), "Use \\%\\fB\\-\\-bits\\fR to set it.\n");
+/// ```
+pub fn flag<'a>(flag: impl Into<Cow<'a, str>>) -> Vec<Inline<'a>> {
+    vec![Inline::HyphenationPoint, bold(flag)]
+}
+
+/// Return a short command-line flag, a dash and a letter such as `-n`,
+/// as inline elements.
+///
+/// See [`flag`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// assert_eq!(short_flag('n'), flag("-n"));
+/// ```
+pub fn short_flag(letter: char) -> Vec<Inline<'static>> {
+    flag(format!("-{letter}"))
+}
@@ -0,0 +1,126 @@
+//! Typesetting documents with [groff(1)].
+//!
+//! See [`Roff::render_with_groff`].
+//!
+//! [groff(1)]: https://manpages.debian.org/bullseye/groff/groff.1.en.html
+
+use std::process::Command;
+
+use crate::check::{diagnostics, parse_groff, run, Diagnostic};
+use crate::{Error, RenderOptions, Roff};
+
+/// An output device of `groff`, which decides the format of the output.
+///
+/// See [grotty(1)] for the terminal devices.
+///
+/// [grotty(1)]: https://manpages.debian.org/bullseye/groff-base/grotty.1.en.html
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Device {
+    /// Text for a terminal, in UTF-8, as shown by `man`.
+    Utf8,
+
+    /// Text for a terminal, in ASCII.
+    Ascii,
+
+    /// PostScript.
+    PostScript,
+
+    /// PDF, if `gropdf` is installed.
+    Pdf,
+
+    /// HTML, if `grohtml` is installed.
+    Html,
+}
+
+impl Device {
+    /// The name of the device, as given to `groff -T`.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf8",
+            Self::Ascii => "ascii",
+            Self::PostScript => "ps",
+            Self::Pdf => "pdf",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// A document typeset by `groff`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GroffOutput {
+    output: Vec<u8>,
+    warnings: Vec<Diagnostic>,
+}
+
+impl GroffOutput {
+    /// The typeset document, in the format of the device.
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Take the typeset document.
+    pub fn into_output(self) -> Vec<u8> {
+        self.output
+    }
+
+    /// What `groff` warned about while typesetting the document.
+    pub fn warnings(&self) -> &[Diagnostic] {
+        &self.warnings
+    }
+}
+
+impl Roff<'_> {
+    /// Typeset the document as a manual page with `groff`.
+    ///
+    /// The document is rendered as by [`Roff::render`], and typeset
+    /// with the [man(7)] macros, with all warnings enabled. Warnings
+    /// don't make this fail; they are collected in the output instead.
+    /// It fails if `groff` can't be run, or if it exits with an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use roff::*;
+    /// use roff::groff::Device;
+    ///
+    /// let mut doc = Roff::new();
+    /// doc.control("TH", ["FOO", "1"])
+    ///     .control("SH", ["NAME"])
+    ///     .text([roman("foo - do a foo thing")]);
+    /// let typeset = doc.render_with_groff(Device::Utf8).unwrap();
+    /// let text = String::from_utf8_lossy(typeset.output());
+    /// assert!(text.contains("foo - do a foo thing"));
+    /// ```
+    ///
+    /// [man(7)]: https://manpages.debian.org/bullseye/groff/groff_man.7.en.html
+    pub fn render_with_groff(&self, device: Device) -> Result<GroffOutput, Error> {
+        let options = RenderOptions::new();
+        let input = self.render_with(&options);
+        // `-k` runs the input through preconv(1), so that UTF-8 is read as
+        // such, and `-t` through tbl(1), for tables.
+        let output = run(
+            Command::new("groff").args(["-k", "-t", "-man", "-ww", "-T", device.name()]),
+            &input,
+        )?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            return Err(Error::Groff(stderr.trim_end().to_owned()));
+        }
+        Ok(GroffOutput {
+            output: output.stdout,
+            warnings: diagnostics(self, &options, &stderr, parse_groff),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn device_names() {
+        assert_eq!(Device::Utf8.name(), "utf8");
+        assert_eq!(Device::PostScript.name(), "ps");
+    }
+}
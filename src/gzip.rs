@@ -0,0 +1,79 @@
+use std::io;
+
+use flate2::{Compression, GzBuilder};
+
+use crate::{RenderOptions, Roff};
+
+impl Roff<'_> {
+    /// Render as ROFF source text, compressed with gzip.
+    ///
+    /// Manual pages are usually installed compressed, as
+    /// `name.section.gz`. The gzip header has no timestamp or file
+    /// name, so the same document always compresses to the same
+    /// bytes, which keeps builds reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let gz = Roff::new().text([roman("hello, world")]).render_gz();
+    /// assert_eq!(gz[..2], [0x1f, 0x8b]);
+    /// ```
+    pub fn render_gz(&self) -> Vec<u8> {
+        let mut gz = vec![];
+        self.to_gz_writer(&mut gz)
+            .expect("writing to a Vec always works");
+        gz
+    }
+
+    /// Write to a writer, compressed with gzip.
+    ///
+    /// See [`render_gz`](Roff::render_gz).
+    pub fn to_gz_writer(&self, w: &mut dyn io::Write) -> Result<(), io::Error> {
+        self.to_gz_writer_with(w, &RenderOptions::default())
+    }
+
+    /// Write to a writer, compressed with gzip, with custom
+    /// [`RenderOptions`].
+    pub fn to_gz_writer_with(
+        &self,
+        w: &mut dyn io::Write,
+        options: &RenderOptions,
+    ) -> Result<(), io::Error> {
+        // An explicit zero timestamp, rather than the current time,
+        // and no file name.
+        let mut encoder = GzBuilder::new().mtime(0).write(w, Compression::best());
+        self.to_writer_with(&mut encoder, options)?;
+        encoder.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read as _;
+
+    use super::*;
+    use crate::roman;
+
+    #[test]
+    fn decompresses_to_rendered() {
+        let mut doc = Roff::new();
+        doc.control("TH", ["FOO", "1"]).text([roman("don't")]);
+        let mut decompressed = String::new();
+        flate2::read::GzDecoder::new(&doc.render_gz()[..])
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, doc.render());
+    }
+
+    #[test]
+    fn is_reproducible() {
+        let mut doc = Roff::new();
+        doc.text([roman("hello")]);
+        let gz = doc.render_gz();
+        assert_eq!(gz, doc.render_gz());
+        // No timestamp.
+        assert_eq!(gz[4..8], [0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::scope::{scoped, Restore};
+use crate::{Roff, NO_ARGS};
+
+const RESTORE: Restore = Restore {
+    register: "roff-hy",
+    current: r"\n[.hy]",
+    request: "hy",
+    unit: "",
+};
+
+impl<'a> Roff<'a> {
+    /// Turn automatic hyphenation of the lines that follow on or off,
+    /// with the `hy` or `nh` request.
+    ///
+    /// To keep a single word from being hyphenated, start it with a
+    /// [`hyphenation_point`](crate::hyphenation_point) instead.
+    pub fn hyphenate(&mut self, yes: bool) -> &mut Self {
+        if yes {
+            self.control("hy", NO_ARGS)
+        } else {
+            self.control("nh", NO_ARGS)
+        }
+    }
+}
+
+/// A block whose lines aren't hyphenated, after which hyphenation is
+/// as it was before the block.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = unhyphenated(|doc| {
+///     doc.text([roman("https://example.com/a/long/path")]);
+/// });
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".nr roff-hy0 \\n[.hy]\n\
+///      .nh\n\
+///      https://example.com/a/long/path\n\
+///      .hy \\n[roff-hy0]\n"
+/// );
+/// ```
+pub fn unhyphenated<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    scoped(
+        RESTORE,
+        |doc| {
+            doc.hyphenate(false);
+        },
+        content,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bold, hyphenation_point, roman};
+
+    #[test]
+    fn requests() {
+        let mut doc = Roff::new();
+        doc.hyphenate(false).hyphenate(true);
+        assert_eq!(doc.to_roff(), ".nh\n.hy\n");
+    }
+
+    #[test]
+    fn hyphenation_points() {
+        let mut doc = Roff::new();
+        doc.text([
+            roman("."),
+            hyphenation_point(),
+            roman("hyphen"),
+            hyphenation_point(),
+            bold("ation"),
+        ]);
+        assert_eq!(doc.to_roff(), "\\&.\\%hyphen\\%\\fBation\\fR\n");
+    }
+}
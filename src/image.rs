@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+
+use crate::{roman, Distance, Roff, NO_ARGS};
+
+/// A block with an image, for typeset output, or its alternative text,
+/// on terminals.
+///
+/// The image is included with the `PSPIC` macro of GNU troff, so it is
+/// an Encapsulated PostScript file, scaled to fit `width` and `height`.
+/// Since terminals can't show images, the alternative text is used
+/// there instead; the choice is made with the `t` and `n` conditions.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = image("logo.eps", Distance::inches(2.0), Distance::inches(1.0), "[logo]");
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".if t \\{\\\n\
+///      .PSPIC logo.eps 2i 1i\n\
+///      .\\}\n\
+///      .if n \\{\\\n\
+///      [logo]\n\
+///      .\\}\n"
+/// );
+/// ```
+pub fn image<'a>(
+    path: impl Into<Cow<'a, str>>,
+    width: Distance,
+    height: Distance,
+    alt: impl Into<Cow<'a, str>>,
+) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.control("if", ["t", r"\{\"])
+        .control("PSPIC", [path.into(), width.into(), height.into()])
+        .control(r"\}", NO_ARGS)
+        .control("if", ["n", r"\{\"])
+        .text([roman(alt)])
+        .control(r"\}", NO_ARGS);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn path_and_alt_text_are_kept_apart() {
+        let doc = image(
+            "my logo.eps",
+            Distance::centimeters(3.0),
+            Distance::centimeters(1.5),
+            ".logo",
+        );
+        assert_eq!(
+            doc.to_roff(),
+            ".if t \\{\\\n\
+             .PSPIC \"my logo.eps\" 3c 1.5c\n\
+             .\\}\n\
+             .if n \\{\\\n\
+             \\&.logo\n\
+             .\\}\n"
+        );
+    }
+
+    #[test]
+    fn image_is_valid() {
+        let doc = image(
+            "logo.eps",
+            Distance::inches(2.0),
+            Distance::inches(1.0),
+            "logo",
+        );
+        assert_eq!(doc.validate(), Ok(()));
+    }
+}
@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use crate::scope::{scoped, Restore};
+use crate::{Roff, NO_ARGS};
+
+const RESTORE: Restore = Restore {
+    register: "roff-in",
+    current: r"\n[.i]",
+    request: "in",
+    unit: "u",
+};
+
+impl<'a> Roff<'a> {
+    /// Indent the lines that follow, with the `in` request.
+    ///
+    /// The indentation is a distance from the left margin, such as
+    /// `4n`, or a change to the current indentation, such as `+4n` or
+    /// `-2n`.
+    ///
+    /// In manual pages, prefer the `RS` and `RE` macros, as in
+    /// [`man::indented`](crate::man::indented), which the `man` macros
+    /// keep track of.
+    pub fn indent(&mut self, indent: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.control("in", [indent])
+    }
+
+    /// Go back to the indentation from before the last `in` request.
+    pub fn reset_indent(&mut self) -> &mut Self {
+        self.control("in", NO_ARGS)
+    }
+
+    /// Indent only the next output line, with the `ti` request.
+    ///
+    /// The indentation is as for [`Roff::indent`]. A negative change,
+    /// such as `-4n`, makes the line hang out to the left of the lines
+    /// after it.
+    pub fn temporary_indent(&mut self, indent: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.control("ti", [indent])
+    }
+}
+
+/// A block whose lines are indented, after which the indentation from
+/// before the block is restored.
+///
+/// The indentation is as for [`Roff::indent`]. This suits quotations.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = with_indent("+4n", |doc| {
+///     doc.text([roman("quoted")]);
+/// });
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".nr roff-in0 \\n[.i]\n\
+///      .in +4n\n\
+///      quoted\n\
+///      .in \\n[roff-in0]u\n"
+/// );
+/// ```
+pub fn with_indent<'a>(
+    indent: impl Into<Cow<'a, str>>,
+    content: impl FnOnce(&mut Roff<'a>),
+) -> Roff<'a> {
+    let indent = indent.into();
+    scoped(
+        RESTORE,
+        |doc| {
+            doc.indent(indent);
+        },
+        content,
+    )
+}
+
+/// A block whose lines are indented by `width`, except for the first,
+/// which hangs out to the left of the others.
+///
+/// The width is a distance, such as `4n`. After the block, the
+/// indentation from before it is restored.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = hanging_indent("4n", |doc| {
+///     doc.text([bold("Note:"), roman(" the rest lines up under here.")]);
+/// });
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".nr roff-in0 \\n[.i]\n\
+///      .in +4n\n\
+///      .ti -4n\n\
+///      \\fBNote:\\fR the rest lines up under here.\n\
+///      .in \\n[roff-in0]u\n"
+/// );
+/// ```
+pub fn hanging_indent<'a>(width: &str, content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    let (indent, outdent) = (format!("+{width}"), format!("-{width}"));
+    scoped(
+        RESTORE,
+        |doc| {
+            doc.indent(indent).temporary_indent(outdent);
+        },
+        content,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn requests() {
+        let mut doc = Roff::new();
+        doc.indent("4n").temporary_indent("-2n").reset_indent();
+        assert_eq!(doc.to_roff(), ".in 4n\n.ti -2n\n.in\n");
+    }
+
+    #[test]
+    fn nested_blocks() {
+        let doc = with_indent("+4n", |doc| {
+            doc.append(with_indent("+2n", |_| {}));
+        });
+        assert_eq!(
+            doc.to_roff(),
+            ".nr roff-in1 \\n[.i]\n\
+             .in +4n\n\
+             .nr roff-in0 \\n[.i]\n\
+             .in +2n\n\
+             .in \\n[roff-in0]u\n\
+             .in \\n[roff-in1]u\n"
+        );
+    }
+}
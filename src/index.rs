@@ -0,0 +1,54 @@
+use std::borrow::Cow;
+
+use crate::{Line, Roff};
+
+impl<'a> Roff<'a> {
+    /// Add a term to the index, with the `IX` macro.
+    ///
+    /// This is the form of index entries that pod2man writes, as
+    /// `.IX Xref "term"`. ROFF implementations ignore it, as the macro
+    /// isn't defined, but indexing tools read it from the source, as
+    /// does [`index_terms`](Roff::index_terms).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.index_entry("regular expression")
+    ///     .text([roman("Patterns are regular expressions.")]);
+    /// assert_eq!(
+    ///     doc.to_roff(),
+    ///     ".IX Xref \"regular expression\"\n\
+    ///      Patterns are regular expressions.\n"
+    /// );
+    /// ```
+    pub fn index_entry(&mut self, term: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.control("IX", [Cow::Borrowed("Xref"), term.into()])
+    }
+
+    /// The terms of the index entries, in order.
+    ///
+    /// These are the arguments of the `IX` control lines, after the
+    /// first, which is the kind of entry, such as `Xref`, `Item`, or
+    /// `Header`, joined by spaces. Entries that pod2man writes for
+    /// headings and items are included.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("IX", ["Header", "NAME"]).index_entry("foo");
+    /// assert_eq!(doc.index_terms(), ["NAME", "foo"]);
+    /// ```
+    pub fn index_terms(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Control { name, args } if name == "IX" && args.len() > 1 => {
+                    Some(args[1..].join(" "))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
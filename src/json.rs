@@ -0,0 +1,391 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Inline, Line, Roff, SpecialChar};
+
+/// The version of the JSON format written by [`Roff::to_json`].
+pub const JSON_VERSION: u32 = 1;
+
+impl Roff<'_> {
+    /// Convert to the stable JSON format.
+    ///
+    /// Unlike the representation from the `serde` implementations,
+    /// which follows the definitions of the types in this crate, the
+    /// JSON format is stable: it is versioned, and a new version will
+    /// only be introduced when it can't be extended compatibly. This
+    /// is meant for tools not written in Rust to produce documents,
+    /// and have this crate do the escaping and rendering.
+    ///
+    /// The format, version 1, is an object with the fields `version`,
+    /// which is `1`, and `lines`, which is an array of lines. Each
+    /// line is an object with the field `type`, which is one of:
+    ///
+    /// * `"control"`: a control line, with the fields `name` (a
+    ///   string) and `args` (an array of strings)
+    /// * `"text"`: a text line, with the field `inlines` (an array of
+    ///   inline elements)
+    ///
+    /// Each inline element is an object with the field `type`, which
+    /// is one of:
+    ///
+    /// * `"roman"`, `"italic"`, or `"bold"`: text in that font, with
+    ///   the field `text` (a string)
+    /// * `"line_break"`: a hard line break
+    /// * `"special"`: a special character, with the field `char`,
+    ///   which is the name of a [`SpecialChar`] variant in snake case,
+    ///   such as `"bullet"` or `"left_double_quote"`
+    /// * `"hyphenation_point"`: a place where the word may be
+    ///   hyphenated, see [`Inline::HyphenationPoint`]
+    /// * `"break_point"`: a place where the line may be broken without
+    ///   a hyphen, see [`Inline::BreakPoint`]
+    /// * `"tab"`: a tab, see [`Inline::Tab`]
+    /// * `"zero_width"`: a zero-width character, see
+    ///   [`Inline::ZeroWidth`]
+    /// * `"colored"`: inline elements in a color, with the fields
+    ///   `color` (a string) and `inlines` (an array of inline elements)
+    /// * `"resized"`: inline elements in a larger or smaller type
+    ///   size, with the fields `change` (an integer number of points)
+    ///   and `inlines` (an array of inline elements)
+    /// * `"superscript"` or `"subscript"`: inline elements raised or
+    ///   lowered, with the field `inlines` (an array of inline
+    ///   elements)
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let json = Roff::new()
+    ///     .control("SH", ["NAME"])
+    ///     .text([bold("foo")])
+    ///     .to_json();
+    /// assert_eq!(
+    ///     json,
+    ///     r#"{"version":1,"lines":[{"type":"control","name":"SH","args":["NAME"]},{"type":"text","inlines":[{"type":"bold","text":"foo"}]}]}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let doc = JsonRoff {
+            version: JSON_VERSION,
+            lines: self.lines.iter().map(JsonLine::from).collect(),
+        };
+        serde_json::to_string(&doc).expect("documents can always be serialized")
+    }
+
+    /// Convert from the stable JSON format.
+    ///
+    /// See [`to_json`](Roff::to_json) for a description of the format.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let doc = Roff::from_json(
+    ///     r#"{"version":1,"lines":[{"type":"text","inlines":[{"type":"roman","text":"don't"}]}]}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(doc.to_roff(), "don't\n");
+    /// ```
+    pub fn from_json(json: &str) -> Result<Roff<'static>, Error> {
+        let version: JsonVersion = serde_json::from_str(json)?;
+        if version.version != JSON_VERSION {
+            return Err(Error::UnsupportedJsonVersion(version.version));
+        }
+        let doc: JsonRoff<'_> = serde_json::from_str(json)?;
+        Ok(Roff {
+            lines: doc.lines.into_iter().map(Line::from).collect(),
+        }
+        .into_owned())
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonVersion {
+    version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonRoff<'a> {
+    version: u32,
+    #[serde(borrow)]
+    lines: Vec<JsonLine<'a>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonLine<'a> {
+    Control {
+        #[serde(borrow)]
+        name: Cow<'a, str>,
+        #[serde(borrow)]
+        args: Vec<Cow<'a, str>>,
+    },
+    Text {
+        #[serde(borrow)]
+        inlines: Vec<JsonInline<'a>>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonInline<'a> {
+    Roman {
+        #[serde(borrow)]
+        text: Cow<'a, str>,
+    },
+    Italic {
+        #[serde(borrow)]
+        text: Cow<'a, str>,
+    },
+    Bold {
+        #[serde(borrow)]
+        text: Cow<'a, str>,
+    },
+    LineBreak,
+    Special {
+        char: JsonSpecialChar,
+    },
+    HyphenationPoint,
+    BreakPoint,
+    Tab,
+    ZeroWidth,
+    Colored {
+        #[serde(borrow)]
+        color: Cow<'a, str>,
+        inlines: Vec<JsonInline<'a>>,
+    },
+    Resized {
+        change: i32,
+        inlines: Vec<JsonInline<'a>>,
+    },
+    Superscript {
+        inlines: Vec<JsonInline<'a>>,
+    },
+    Subscript {
+        inlines: Vec<JsonInline<'a>>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum JsonSpecialChar {
+    Bullet,
+    Copyright,
+    Registered,
+    Trademark,
+    Degree,
+    Ellipsis,
+    LeftDoubleQuote,
+    RightDoubleQuote,
+    LeftSingleQuote,
+    RightSingleQuote,
+    EmDash,
+    EnDash,
+    Section,
+    NoBreakSpace,
+}
+
+impl<'a> From<&'a Line<'_>> for JsonLine<'a> {
+    fn from(line: &'a Line<'_>) -> Self {
+        match line {
+            Line::Control { name, args } => Self::Control {
+                name: Cow::Borrowed(name),
+                args: args.iter().map(|arg| Cow::Borrowed(arg.as_ref())).collect(),
+            },
+            Line::Text(inlines) => Self::Text {
+                inlines: inlines.iter().map(JsonInline::from).collect(),
+            },
+        }
+    }
+}
+
+impl<'a> From<JsonLine<'a>> for Line<'a> {
+    fn from(line: JsonLine<'a>) -> Self {
+        match line {
+            JsonLine::Control { name, args } => Line::Control { name, args },
+            JsonLine::Text { inlines } => {
+                Line::Text(inlines.into_iter().map(Inline::from).collect())
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a Inline<'_>> for JsonInline<'a> {
+    fn from(inline: &'a Inline<'_>) -> Self {
+        match inline {
+            Inline::Roman(text) => Self::Roman {
+                text: Cow::Borrowed(text),
+            },
+            Inline::Italic(text) => Self::Italic {
+                text: Cow::Borrowed(text),
+            },
+            Inline::Bold(text) => Self::Bold {
+                text: Cow::Borrowed(text),
+            },
+            Inline::LineBreak => Self::LineBreak,
+            Inline::Special(c) => Self::Special { char: (*c).into() },
+            Inline::HyphenationPoint => Self::HyphenationPoint,
+            Inline::BreakPoint => Self::BreakPoint,
+            Inline::Tab => Self::Tab,
+            Inline::ZeroWidth => Self::ZeroWidth,
+            Inline::Colored { color, inlines } => Self::Colored {
+                color: Cow::Borrowed(color),
+                inlines: inlines.iter().map(JsonInline::from).collect(),
+            },
+            Inline::Resized { change, inlines } => Self::Resized {
+                change: *change,
+                inlines: inlines.iter().map(JsonInline::from).collect(),
+            },
+            Inline::Superscript(inlines) => Self::Superscript {
+                inlines: inlines.iter().map(JsonInline::from).collect(),
+            },
+            Inline::Subscript(inlines) => Self::Subscript {
+                inlines: inlines.iter().map(JsonInline::from).collect(),
+            },
+        }
+    }
+}
+
+impl<'a> From<JsonInline<'a>> for Inline<'a> {
+    fn from(inline: JsonInline<'a>) -> Self {
+        match inline {
+            JsonInline::Roman { text } => Inline::Roman(text),
+            JsonInline::Italic { text } => Inline::Italic(text),
+            JsonInline::Bold { text } => Inline::Bold(text),
+            JsonInline::LineBreak => Inline::LineBreak,
+            JsonInline::Special { char } => Inline::Special(char.into()),
+            JsonInline::HyphenationPoint => Inline::HyphenationPoint,
+            JsonInline::BreakPoint => Inline::BreakPoint,
+            JsonInline::Tab => Inline::Tab,
+            JsonInline::ZeroWidth => Inline::ZeroWidth,
+            JsonInline::Colored { color, inlines } => Inline::Colored {
+                color,
+                inlines: inlines.into_iter().map(Inline::from).collect(),
+            },
+            JsonInline::Resized { change, inlines } => Inline::Resized {
+                change,
+                inlines: inlines.into_iter().map(Inline::from).collect(),
+            },
+            JsonInline::Superscript { inlines } => {
+                Inline::Superscript(inlines.into_iter().map(Inline::from).collect())
+            }
+            JsonInline::Subscript { inlines } => {
+                Inline::Subscript(inlines.into_iter().map(Inline::from).collect())
+            }
+        }
+    }
+}
+
+impl From<SpecialChar> for JsonSpecialChar {
+    fn from(c: SpecialChar) -> Self {
+        match c {
+            SpecialChar::Bullet => Self::Bullet,
+            SpecialChar::Copyright => Self::Copyright,
+            SpecialChar::Registered => Self::Registered,
+            SpecialChar::Trademark => Self::Trademark,
+            SpecialChar::Degree => Self::Degree,
+            SpecialChar::Ellipsis => Self::Ellipsis,
+            SpecialChar::LeftDoubleQuote => Self::LeftDoubleQuote,
+            SpecialChar::RightDoubleQuote => Self::RightDoubleQuote,
+            SpecialChar::LeftSingleQuote => Self::LeftSingleQuote,
+            SpecialChar::RightSingleQuote => Self::RightSingleQuote,
+            SpecialChar::EmDash => Self::EmDash,
+            SpecialChar::EnDash => Self::EnDash,
+            SpecialChar::Section => Self::Section,
+            SpecialChar::NoBreakSpace => Self::NoBreakSpace,
+        }
+    }
+}
+
+impl From<JsonSpecialChar> for SpecialChar {
+    fn from(c: JsonSpecialChar) -> Self {
+        match c {
+            JsonSpecialChar::Bullet => Self::Bullet,
+            JsonSpecialChar::Copyright => Self::Copyright,
+            JsonSpecialChar::Registered => Self::Registered,
+            JsonSpecialChar::Trademark => Self::Trademark,
+            JsonSpecialChar::Degree => Self::Degree,
+            JsonSpecialChar::Ellipsis => Self::Ellipsis,
+            JsonSpecialChar::LeftDoubleQuote => Self::LeftDoubleQuote,
+            JsonSpecialChar::RightDoubleQuote => Self::RightDoubleQuote,
+            JsonSpecialChar::LeftSingleQuote => Self::LeftSingleQuote,
+            JsonSpecialChar::RightSingleQuote => Self::RightSingleQuote,
+            JsonSpecialChar::EmDash => Self::EmDash,
+            JsonSpecialChar::EnDash => Self::EnDash,
+            JsonSpecialChar::Section => Self::Section,
+            JsonSpecialChar::NoBreakSpace => Self::NoBreakSpace,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        break_point, colored, hyphenation_point, italic, line_break, resized, roman, subscript,
+        superscript, tab, zero_width,
+    };
+
+    #[test]
+    fn round_trip() {
+        let mut doc = Roff::new();
+        doc.control("TH", ["FOO", "1", ""]).text([
+            roman("a \"b\""),
+            italic("c"),
+            line_break(),
+            SpecialChar::LeftDoubleQuote.into(),
+            hyphenation_point(),
+            break_point(),
+            tab(),
+            zero_width(),
+            colored("red", [roman("warning")]),
+            resized(-2, [roman("small")]),
+            superscript([roman("2")]),
+            subscript([roman("1")]),
+        ]);
+        let json = doc.to_json();
+        assert_eq!(Roff::from_json(&json).unwrap(), doc);
+    }
+
+    #[test]
+    fn inline_kinds() {
+        let kinds = [
+            (hyphenation_point(), r#"{"type":"hyphenation_point"}"#),
+            (break_point(), r#"{"type":"break_point"}"#),
+            (tab(), r#"{"type":"tab"}"#),
+            (zero_width(), r#"{"type":"zero_width"}"#),
+            (
+                colored("red", [roman("a")]),
+                r#"{"type":"colored","color":"red","inlines":[{"type":"roman","text":"a"}]}"#,
+            ),
+            (
+                resized(-2, [roman("a")]),
+                r#"{"type":"resized","change":-2,"inlines":[{"type":"roman","text":"a"}]}"#,
+            ),
+            (
+                superscript([roman("2")]),
+                r#"{"type":"superscript","inlines":[{"type":"roman","text":"2"}]}"#,
+            ),
+            (
+                subscript([roman("1")]),
+                r#"{"type":"subscript","inlines":[{"type":"roman","text":"1"}]}"#,
+            ),
+        ];
+        for (inline, expected) in kinds {
+            let mut doc = Roff::new();
+            doc.text([inline]);
+            let json =
+                format!(r#"{{"version":1,"lines":[{{"type":"text","inlines":[{expected}]}}]}}"#);
+            assert_eq!(doc.to_json(), json);
+            assert_eq!(Roff::from_json(&json).unwrap(), doc);
+        }
+    }
+
+    #[test]
+    fn reject_unknown_version() {
+        let err = Roff::from_json(r#"{"version":2,"lines":[]}"#).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedJsonVersion(2)));
+    }
+
+    #[test]
+    fn reject_invalid_json() {
+        let err = Roff::from_json(r#"{"version":1,"lines":[{"type":"foo"}]}"#).unwrap_err();
+        assert!(matches!(err, Error::Json(_)));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::Roff;
+
+impl<'a> Roff<'a> {
+    /// Start a new page unless there is room for this many more lines
+    /// on the current one, with the `ne` request.
+    ///
+    /// This only matters for paged output, such as PostScript and PDF;
+    /// terminals have no pages.
+    pub fn need(&mut self, lines: u32) -> &mut Self {
+        self.control("ne", [format!("{lines}v")])
+    }
+}
+
+/// A block that is kept together on one page, if it is no more than
+/// `lines` lines long.
+///
+/// Before the block, a new page is started if there isn't room for
+/// that many lines, as by [`Roff::need`]. Make `lines` the length of
+/// the block, or the least of it that shouldn't be split, such as the
+/// tag and first line of a tagged paragraph.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = keep(3, |doc| {
+///     doc.control("nf", NO_ARGS)
+///         .text([roman("one\ntwo\nthree")])
+///         .control("fi", NO_ARGS);
+/// });
+/// assert_eq!(doc.to_roff(), ".ne 3v\n.nf\none\ntwo\nthree\n.fi\n");
+/// ```
+pub fn keep<'a>(lines: u32, content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.need(lines);
+    content(&mut doc);
+    doc
+}
@@ -22,7 +22,128 @@
 #![warn(clippy::print_stderr)]
 #![warn(clippy::print_stdout)]
 
-use std::io::Write;
+mod adjust;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod chars;
+#[cfg(feature = "check")]
+pub mod check;
+mod cjk;
+#[cfg(feature = "clap")]
+pub mod clap;
+mod color;
+mod columns;
+mod diagnostics;
+mod diff;
+mod distance;
+mod edit;
+mod error;
+mod flag;
+#[cfg(feature = "groff")]
+pub mod groff;
+#[cfg(feature = "gzip")]
+mod gzip;
+mod hyphenate;
+mod image;
+mod indentation;
+mod index;
+#[cfg(feature = "json")]
+mod json;
+mod keep;
+mod macros;
+pub mod man;
+#[cfg(feature = "manifest")]
+pub mod manifest;
+mod markdown;
+mod mdoc;
+pub mod me;
+mod minify;
+pub mod mm;
+pub mod mom;
+pub mod ms;
+mod page;
+mod portability;
+mod punctuation;
+mod refer;
+mod render;
+mod rendered;
+mod scope;
+mod section;
+mod sink;
+mod size;
+mod stats;
+mod syntax;
+mod tabs;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod toc;
+mod view;
+mod visit;
+mod writer;
+
+pub use adjust::adjusted;
+pub use adjust::left_aligned;
+pub use adjust::unadjusted;
+pub use adjust::Adjust;
+pub use chars::SpecialChar;
+pub use color::colored;
+pub use columns::columns;
+pub use diagnostics::RenderDiagnostic;
+pub use diagnostics::RenderDiagnosticKind;
+pub use diff::diff;
+pub use diff::Change;
+pub use distance::Distance;
+pub use distance::InvalidDistance;
+pub use distance::Unit;
+pub use error::Error;
+pub use error::InvalidName;
+pub use flag::flag;
+pub use flag::short_flag;
+pub use hyphenate::unhyphenated;
+pub use image::image;
+pub use indentation::hanging_indent;
+pub use indentation::with_indent;
+#[cfg(feature = "json")]
+pub use json::JSON_VERSION;
+pub use keep::keep;
+#[doc(hidden)]
+pub use macros::__styled;
+pub use page::PageSetup;
+pub use page::PageTitle;
+pub use portability::PortabilityIssue;
+pub use punctuation::double_quoted;
+pub use punctuation::single_quoted;
+pub use refer::refer_commands;
+pub use refer::Citation;
+pub use render::ApostropheMode;
+pub use render::Dialect;
+pub use render::Encoding;
+pub use render::OutputVersion;
+pub use render::RenderOptions;
+pub use rendered::RenderedLines;
+pub use section::SectionView;
+pub use sink::TextSink;
+pub use size::resized;
+pub use size::small_caps;
+pub use size::subscript;
+pub use size::superscript;
+pub use syntax::alt;
+pub use syntax::opt;
+pub use syntax::repeat;
+pub use syntax::req;
+pub use syntax::synopsis_line;
+pub use tabs::TabStop;
+pub use toc::table_of_contents;
+pub use toc::Heading;
+pub use view::LineRef;
+pub use visit::RoffVisitor;
+pub use writer::RoffWriter;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::io;
 use std::write;
 
 /// A ROFF document, consisting of lines.
@@ -41,62 +162,321 @@ use std::write;
 ///     .render();
 /// assert!(doc.ends_with(".TH FOO 1\n.SH NAME\nfoo \\- do a foo thing\n"));
 /// ```
+///
+/// Text and arguments are stored as [`Cow`]s, so string slices, such
+/// as those in static templates, are not copied. A document borrowing
+/// nothing has the type `Roff<'static>`, and any document can be
+/// turned into one with [`into_owned`](Roff::into_owned).
 #[derive(Debug, PartialEq, Eq, Default)]
-pub struct Roff {
-    lines: Vec<Line>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Roff<'a> {
+    lines: Vec<Line<'a>>,
 }
 
-impl Roff {
+impl<'a> Roff<'a> {
     /// Instantiate a `Roff`
     pub fn new() -> Self {
         Default::default()
     }
 
+    /// Instantiate a `Roff` with room for at least this many lines.
+    ///
+    /// Building a large document, such as the manual page of an API
+    /// with thousands of functions, then takes no reallocation until
+    /// it has that many lines.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let functions = ["open", "close"];
+    /// let mut doc = Roff::with_capacity(2 * functions.len());
+    /// for function in functions {
+    ///     doc.control("TP", NO_ARGS).text([bold(function)]);
+    /// }
+    /// assert_eq!(doc.line_count(), 4);
+    /// ```
+    pub fn with_capacity(lines: usize) -> Self {
+        Self {
+            lines: Vec::with_capacity(lines),
+        }
+    }
+
+    /// Make room for at least this many more lines.
+    pub fn reserve(&mut self, additional: usize) -> &mut Self {
+        self.lines.reserve(additional);
+        self
+    }
+
+    /// Copy any borrowed text, so the document borrows nothing.
+    pub fn into_owned(self) -> Roff<'static> {
+        Roff {
+            lines: self.lines.into_iter().map(Line::into_owned).collect(),
+        }
+    }
+
+    /// Borrow all text, rather than copying it.
+    pub(crate) fn reborrow(&self) -> Roff<'_> {
+        Roff {
+            lines: self.lines.iter().map(Line::reborrow).collect(),
+        }
+    }
+
     /// Append a control line.
     ///
     /// The line consist of the name of a built-in command or macro,
     /// and some number of arguments. Arguments that contain spaces
-    /// will be enclosed with double quotation marks.
-    pub fn control<'a>(
+    /// or double quotation marks will be enclosed with double
+    /// quotation marks, with any double quotation marks inside them
+    /// doubled.
+    ///
+    /// An empty argument is rendered as `""`. This is how to skip an
+    /// argument of a macro that takes positional arguments, such as
+    /// the date of `TH`:
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let doc = Roff::new()
+    ///     .control("TH", ["FOO", "1", "", "foo 1.0"])
+    ///     .to_roff();
+    /// assert_eq!(doc, ".TH FOO 1 \"\" \"foo 1.0\"\n");
+    /// ```
+    ///
+    /// The arguments can be anything that converts into a `Cow<str>`,
+    /// such as a `&str` or a `String`. Since the type of an empty array
+    /// can't be inferred, a control line without arguments takes
+    /// [`NO_ARGS`]:
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let version = String::from("1.0");
+    /// let doc = Roff::new()
+    ///     .control("TH", vec!["FOO".to_owned(), "1".to_owned()])
+    ///     .control("SH", [format!("VERSION {version}")])
+    ///     .control("PP", NO_ARGS)
+    ///     .to_roff();
+    /// assert_eq!(doc, ".TH FOO 1\n.SH \"VERSION 1.0\"\n.PP\n");
+    /// ```
+    pub fn control(
         &mut self,
-        name: impl Into<String>,
-        args: impl IntoIterator<Item = &'a str>,
+        name: impl Into<Cow<'a, str>>,
+        args: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
     ) -> &mut Self {
         self.lines.push(Line::control(
             name.into(),
-            args.into_iter().map(|s| s.to_owned()).collect(),
+            args.into_iter().map(Into::into).collect(),
         ));
         self
     }
 
+    /// Append a control line, if its name is valid.
+    ///
+    /// This is like [`control`](Roff::control), but returns an error
+    /// instead of appending a line that [`validate`](Roff::validate)
+    /// would reject.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// assert!(doc.try_control("SH", ["NAME"]).is_ok());
+    /// assert!(doc.try_control("S H", ["NAME"]).is_err());
+    /// ```
+    pub fn try_control(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        args: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> Result<&mut Self, Error> {
+        let name = name.into();
+        error::check_name(&name)
+            .map_err(|reason| InvalidName::new(self.lines.len(), &name, reason))?;
+        Ok(self.control(name, args))
+    }
+
     /// Append a text line.
     ///
     /// The line will be rendered in a way that ensures it can't be
     /// interpreted as a control line. The caller does not need to
     /// ensure, for example, that the line doesn't start with a
     /// period ("`.`") or an apostrophe ("`'`").
-    pub fn text(&mut self, inlines: impl Into<Vec<Inline>>) -> &mut Self {
+    pub fn text(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
         self.lines.push(Line::text(inlines.into()));
         self
     }
 
+    /// Append a text line, from anything that converts into inline
+    /// elements, such as strings, which are in the roman font.
+    ///
+    /// This is like [`Roff::text`], but saves converting each element.
+    /// The line is allocated once when the iterator knows its length,
+    /// as arrays, slices, and vectors do.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let words = vec!["foo".to_owned(), "-".to_owned()];
+    /// let mut doc = Roff::new();
+    /// doc.text_from(&words).text_from(['a', 'b']);
+    /// assert_eq!(doc.to_roff(), "foo\\-\nab\n");
+    /// ```
+    pub fn text_from(
+        &mut self,
+        inlines: impl IntoIterator<Item = impl Into<Inline<'a>>>,
+    ) -> &mut Self {
+        self.text(inlines.into_iter().map(Into::into).collect::<Vec<_>>())
+    }
+
+    /// Append all lines of another document.
+    ///
+    /// This allows assembling a document from fragments generated
+    /// independently. The same can be done with `+` and `+=`.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut header = Roff::new();
+    /// header.control("TH", ["FOO", "1"]);
+    /// let mut name = Roff::new();
+    /// name.control("SH", ["NAME"]).text([roman("foo")]);
+    /// header.append(name);
+    /// assert_eq!(header.to_roff(), ".TH FOO 1\n.SH NAME\nfoo\n");
+    /// ```
+    pub fn append(&mut self, other: Roff<'a>) -> &mut Self {
+        self.lines.extend(other.lines);
+        self
+    }
+
+    /// Check that the document can be rendered correctly.
+    ///
+    /// Control line names are used as is, so a name containing, for
+    /// example, a space or a double quote results in broken output.
+    /// This returns an error for the first control line with such a
+    /// name. The end of a conditional block, `\}`, is a valid name.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("TH", ["FOO", "1"]).control("S H", ["NAME"]);
+    /// let err = doc.validate().unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// assert_eq!(err.name(), "S H");
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidName> {
+        for (index, line) in self.lines.iter().enumerate() {
+            if let Line::Control { name, .. } = line {
+                error::check_name(name).map_err(|reason| InvalidName::new(index, name, reason))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Render as ROFF source text that can be fed to a ROFF implementation.
     pub fn render(&self) -> String {
-        let mut buf = vec![];
-        self.to_writer(&mut buf).unwrap(); // writing to a Vec always works
-        String::from_utf8(buf)
-            .expect("output is utf8 if all input is utf8 and our API guarantees that")
+        self.render_with(&RenderOptions::default())
+    }
+
+    /// Render as ROFF source text, if the document is valid.
+    ///
+    /// This is like [`render`](Roff::render), but returns an error if
+    /// [`validate`](Roff::validate) does.
+    pub fn try_render(&self) -> Result<String, Error> {
+        self.validate()?;
+        Ok(self.render())
+    }
+
+    /// Render as ROFF source text, with custom [`RenderOptions`].
+    pub fn render_with(&self, options: &RenderOptions) -> String {
+        let mut buf = String::new();
+        self.render_into_with(&mut buf, options);
+        buf
+    }
+
+    /// Render as ROFF source text, appending to a `String`.
+    ///
+    /// This avoids allocating a new `String` for each document when
+    /// rendering many of them.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut buf = String::new();
+    /// for name in ["foo", "bar"] {
+    ///     buf.clear();
+    ///     Roff::new().text([roman(name)]).render_into(&mut buf);
+    ///     assert!(buf.ends_with(&format!("\n{name}\n")));
+    /// }
+    /// ```
+    pub fn render_into(&self, buf: &mut String) {
+        self.render_into_with(buf, &RenderOptions::default());
+    }
+
+    /// Render as ROFF source text, appending to a `String`, with
+    /// custom [`RenderOptions`].
+    pub fn render_into_with(&self, buf: &mut String, options: &RenderOptions) {
+        self.render_fmt(buf, options)
+            .expect("writing to a String always works");
+    }
+
+    /// Write to a [`fmt::Write`], such as a `String` or a
+    /// [`fmt::Formatter`].
+    ///
+    /// Unlike [`to_writer`](Roff::to_writer), this needs no I/O, which
+    /// some targets, such as `wasm32-unknown-unknown`, don't have. All
+    /// rendering to a `String` goes this way.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// use std::fmt;
+    ///
+    /// struct Page(Roff<'static>);
+    ///
+    /// impl fmt::Display for Page {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         self.0.to_fmt_writer(f)
+    ///     }
+    /// }
+    ///
+    /// let mut doc = Roff::new();
+    /// doc.text([roman("foo")]);
+    /// assert_eq!(Page(doc).to_string(), Roff::new().text([roman("foo")]).render());
+    /// ```
+    pub fn to_fmt_writer(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.to_fmt_writer_with(out, &RenderOptions::default())
+    }
+
+    /// Write to a [`fmt::Write`], with custom [`RenderOptions`].
+    pub fn to_fmt_writer_with(
+        &self,
+        out: &mut dyn fmt::Write,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        self.render_fmt(out, options)
     }
 
     /// Write to a writer.
-    pub fn to_writer(&self, w: &mut dyn Write) -> Result<(), std::io::Error> {
-        w.write_all(APOSTROPHE_PREABMLE.as_bytes())?;
-        for line in self.lines.iter() {
-            line.render(w, Apostrophes::Handle)?;
-        }
+    pub fn to_writer(&self, w: &mut dyn io::Write) -> Result<(), io::Error> {
+        self.to_writer_with(w, &RenderOptions::default())
+    }
+
+    /// Write to a writer, if the document is valid.
+    ///
+    /// This is like [`to_writer`](Roff::to_writer), but first checks
+    /// the document with [`validate`](Roff::validate).
+    pub fn try_to_writer(&self, w: &mut dyn io::Write) -> Result<(), Error> {
+        self.validate()?;
+        self.to_writer(w)?;
         Ok(())
     }
 
+    /// Write to a writer, with custom [`RenderOptions`].
+    pub fn to_writer_with(
+        &self,
+        w: &mut dyn io::Write,
+        options: &RenderOptions,
+    ) -> Result<(), io::Error> {
+        let mut out = IoAdapter::new(w);
+        match self.render_fmt(&mut out, options) {
+            Ok(()) => out.finish(),
+            Err(_) => Err(out.into_error()),
+        }
+    }
+
     /// Render without handling apostrophes specially.
     ///
     /// You probably want [`render`](Roff::render) or
@@ -108,18 +488,112 @@ impl Roff {
     /// other methods handle apostrophes specially to prevent it, but
     /// for completeness, and for testing, this method is provided to
     /// avoid it.
+    ///
+    /// This is the same as rendering with [`ApostropheMode::Plain`].
     pub fn to_roff(&self) -> String {
-        let mut buf = vec![];
+        self.render_with(&RenderOptions::new().apostrophes(ApostropheMode::Plain))
+    }
+
+    /// Render everything, including the preamble.
+    fn render_fmt(&self, out: &mut dyn fmt::Write, options: &RenderOptions) -> fmt::Result {
+        render_preamble(out, options, &self.lines)?;
+        let mut renderer = LineRenderer::default();
         for line in self.lines.iter() {
-            // Writing to a Vec always works, so we discard any error.
-            line.render(&mut buf, Apostrophes::DontHandle).unwrap();
+            renderer.render(out, line, options)?;
+        }
+        Ok(())
+    }
+}
+
+/// Render the lines of a document one at a time, keeping what the
+/// rendering of a line depends on from the lines before it.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct LineRenderer {
+    after_break: bool,
+}
+
+impl LineRenderer {
+    /// Render the next line of the document, with its entry in the PDF
+    /// outline, if any.
+    ///
+    /// Nothing is written for a line break that
+    /// [minifying](RenderOptions::minify) leaves out.
+    pub(crate) fn render(
+        &mut self,
+        out: &mut dyn fmt::Write,
+        line: &Line<'_>,
+        options: &RenderOptions,
+    ) -> fmt::Result {
+        if options.minify && self.after_break && line.is_break() {
+            return Ok(());
+        }
+        line.render(out, options)?;
+        if options.pdf_outline {
+            render_outline_entry(out, line, options)?;
         }
-        String::from_utf8(buf)
-            .expect("output is utf8 if all input is utf8 and our API guarantees that")
+        self.after_break = line.ends_with_break();
+        Ok(())
     }
 }
 
-impl<I: Into<Inline>> From<I> for Roff {
+/// Render what comes before the first line of a document.
+///
+/// If the lines have a table, the output starts with the comment `'\" t`,
+/// which tells `man` to run the page through tbl(1), except for
+/// [`OutputVersion::V1`].
+fn render_preamble(
+    out: &mut dyn fmt::Write,
+    options: &RenderOptions,
+    lines: &[Line<'_>],
+) -> fmt::Result {
+    let has_table = lines
+        .iter()
+        .any(|line| matches!(line, Line::Control { name, .. } if name == "TS"));
+    if has_table && options.version > OutputVersion::V1 {
+        writeln!(out, r#"'\" t"#)?;
+    }
+    if options.coding_comment {
+        writeln!(out, r#".\" -*- coding: {} -*-"#, options.encoding.name())?;
+    }
+    if options.apostrophes == ApostropheMode::Preamble && options.dialect == Dialect::Groff {
+        out.write_str(APOSTROPHE_PREABMLE)?;
+    }
+    if options.smart_punctuation {
+        out.write_str(punctuation::SMART_PUNCTUATION_PREAMBLE)?;
+    }
+    Ok(())
+}
+
+/// Render the entry in the PDF outline for a line that is a heading.
+fn render_outline_entry(
+    out: &mut dyn fmt::Write,
+    line: &Line<'_>,
+    options: &RenderOptions,
+) -> fmt::Result {
+    let Line::Control { name, args } = line else {
+        return Ok(());
+    };
+    if options.dialect != Dialect::Groff {
+        return Ok(());
+    }
+    let level = match name.as_ref() {
+        "SH" => 1,
+        "SS" => 2,
+        _ => return Ok(()),
+    };
+    if args.is_empty() {
+        return Ok(());
+    }
+    // The heading is always quoted, so that it is one argument, and
+    // the condition never is, since quotes would change its meaning.
+    let mut heading = quote_arg(&args.join(" "), true, options.version);
+    if options.encoding == Encoding::Ascii {
+        heading = escape_non_ascii(&heading, options.dialect);
+    }
+    writeln!(out, r".if \n(.g .if d pdfhref .pdfhref O {level} {heading}")
+}
+
+impl<'a, I: Into<Inline<'a>>> From<I> for Roff<'a> {
     fn from(other: I) -> Self {
         let mut r = Roff::new();
         r.text([other.into()]);
@@ -127,7 +601,41 @@ impl<I: Into<Inline>> From<I> for Roff {
     }
 }
 
-impl<R: Into<Roff>> FromIterator<R> for Roff {
+impl<'a> std::ops::Add for Roff<'a> {
+    type Output = Roff<'a>;
+
+    fn add(mut self, other: Roff<'a>) -> Self::Output {
+        self.append(other);
+        self
+    }
+}
+
+impl<'a> std::ops::AddAssign for Roff<'a> {
+    fn add_assign(&mut self, other: Roff<'a>) {
+        self.append(other);
+    }
+}
+
+/// Build a document from fragments, such as the output of an iterator
+/// pipeline.
+///
+/// ```
+/// # use roff::*;
+/// let options = [("-n", "Set the number of bits."), ("-v", "Be verbose.")];
+/// let doc: Roff = options
+///     .iter()
+///     .map(|(flag, help)| {
+///         let mut entry = Roff::new();
+///         entry.control("TP", NO_ARGS).text([bold(*flag)]).text([roman(*help)]);
+///         entry
+///     })
+///     .collect();
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".TP\n\\fB\\-n\\fR\nSet the number of bits.\n.TP\n\\fB\\-v\\fR\nBe verbose.\n"
+/// );
+/// ```
+impl<'a, R: Into<Roff<'a>>> FromIterator<R> for Roff<'a> {
     fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
         let mut r = Roff::new();
         for i in iter {
@@ -137,7 +645,16 @@ impl<R: Into<Roff>> FromIterator<R> for Roff {
     }
 }
 
-impl<R: Into<Roff>> Extend<R> for Roff {
+/// Append fragments to a document.
+///
+/// ```
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.control("SH", ["FILES"]);
+/// doc.extend(["/etc/foo", "/etc/bar"].map(italic));
+/// assert_eq!(doc.to_roff(), ".SH FILES\n\\fI/etc/foo\\fR\n\\fI/etc/bar\\fR\n");
+/// ```
+impl<'a, R: Into<Roff<'a>>> Extend<R> for Roff<'a> {
     fn extend<T: IntoIterator<Item = R>>(&mut self, iter: T) {
         for i in iter {
             self.lines.extend(i.into().lines);
@@ -156,141 +673,375 @@ impl<R: Into<Roff>> Extend<R> for Roff {
 /// received from the API user. The `Line::render` function handles
 /// escaping etc.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Inline {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Inline<'a> {
     /// Text in the "roman" font, which is the normal font if nothing
     /// else is specified.
-    Roman(String),
+    Roman(Cow<'a, str>),
 
     /// Text in the italic (slanted) font.
-    Italic(String),
+    Italic(Cow<'a, str>),
 
     /// Text in a bold face font.
-    Bold(String),
+    Bold(Cow<'a, str>),
 
     /// A hard line break. This is an inline element so it's easy to
     /// insert a line break in a paragraph.
     LineBreak,
+
+    /// A special character, rendered as the named glyph.
+    Special(SpecialChar),
+
+    /// A place where the word it is in may be hyphenated, rendered as
+    /// `\%`.
+    ///
+    /// A word with one of these is only hyphenated at such places. At
+    /// the start of a word, it keeps the word from being hyphenated at
+    /// all, which suits option names and URLs.
+    HyphenationPoint,
+
+    /// A place where a line may be broken without a hyphen, rendered
+    /// as `\:`.
+    ///
+    /// This gives long words that can't be hyphenated, such as URLs,
+    /// paths, and identifiers, places to break on narrow terminals.
+    BreakPoint,
+
+    /// A tab, which moves to the next tab stop, rendered as a tab
+    /// character.
+    ///
+    /// See [`Roff::tab_stops`] for setting where the tab stops are.
+    Tab,
+
+    /// A zero-width character that prints nothing, rendered as `\&`.
+    ///
+    /// This protects what is next to it: after an abbreviation such as
+    /// "e.g.", it keeps the period from ending a sentence, and before
+    /// a period at the start of a line, from starting a control line.
+    /// Text is already protected where it needs to be when rendered,
+    /// but not when it is joined with other text later.
+    ZeroWidth,
+
+    /// Inline elements in a color, rendered with the `\m` escape.
+    ///
+    /// Devices that can't show colors, such as terminals without them
+    /// turned on, and ROFF implementations that don't know about
+    /// colors, show the elements as usual.
+    Colored {
+        /// Name of the color, such as `red`, or one defined with
+        /// [`Roff::define_color`].
+        color: Cow<'a, str>,
+
+        /// The elements in the color.
+        inlines: Vec<Inline<'a>>,
+    },
+
+    /// Inline elements in a larger or smaller type size, rendered with
+    /// the `\s` escape.
+    ///
+    /// Terminals have only one size, so this only matters for
+    /// typeset output, such as PostScript and PDF.
+    Resized {
+        /// Change of the size, in points, such as `2` or `-1`.
+        change: i32,
+
+        /// The elements in the size.
+        inlines: Vec<Inline<'a>>,
+    },
+
+    /// Inline elements raised half a line and in a smaller size, as
+    /// in "cm²", with the `\u` and `\d` escapes.
+    Superscript(Vec<Inline<'a>>),
+
+    /// Inline elements lowered half a line and in a smaller size, as
+    /// in "x₁", with the `\d` and `\u` escapes.
+    Subscript(Vec<Inline<'a>>),
 }
 
 /// Turn a string slice into inline text in the roman font.
 ///
 /// This is equivalent to the [roman] function, but may be more
 /// convenient to use.
-impl<S: Into<String>> From<S> for Inline {
-    fn from(s: S) -> Self {
+impl<'a> From<&'a str> for Inline<'a> {
+    fn from(s: &'a str) -> Self {
         roman(s)
     }
 }
 
+/// Turn a string into inline text in the roman font.
+impl From<String> for Inline<'_> {
+    fn from(s: String) -> Self {
+        roman(s)
+    }
+}
+
+/// Turn a borrowed string into inline text in the roman font.
+impl<'a> From<&'a String> for Inline<'a> {
+    fn from(s: &'a String) -> Self {
+        roman(s.as_str())
+    }
+}
+
+/// Turn a string into inline text in the roman font.
+impl<'a> From<Cow<'a, str>> for Inline<'a> {
+    fn from(s: Cow<'a, str>) -> Self {
+        roman(s)
+    }
+}
+
+/// Turn a character into inline text in the roman font.
+impl From<char> for Inline<'_> {
+    fn from(c: char) -> Self {
+        roman(c.to_string())
+    }
+}
+
+impl From<SpecialChar> for Inline<'_> {
+    fn from(c: SpecialChar) -> Self {
+        special(c)
+    }
+}
+
+impl Inline<'_> {
+    /// Copy any borrowed text, so the inline borrows nothing.
+    pub fn into_owned(self) -> Inline<'static> {
+        match self {
+            Self::Roman(text) => Inline::Roman(Cow::Owned(text.into_owned())),
+            Self::Italic(text) => Inline::Italic(Cow::Owned(text.into_owned())),
+            Self::Bold(text) => Inline::Bold(Cow::Owned(text.into_owned())),
+            Self::LineBreak => Inline::LineBreak,
+            Self::Special(c) => Inline::Special(c),
+            Self::HyphenationPoint => Inline::HyphenationPoint,
+            Self::BreakPoint => Inline::BreakPoint,
+            Self::Tab => Inline::Tab,
+            Self::ZeroWidth => Inline::ZeroWidth,
+            Self::Colored { color, inlines } => Inline::Colored {
+                color: Cow::Owned(color.into_owned()),
+                inlines: inlines.into_iter().map(Inline::into_owned).collect(),
+            },
+            Self::Resized { change, inlines } => Inline::Resized {
+                change,
+                inlines: inlines.into_iter().map(Inline::into_owned).collect(),
+            },
+            Self::Superscript(inlines) => {
+                Inline::Superscript(inlines.into_iter().map(Inline::into_owned).collect())
+            }
+            Self::Subscript(inlines) => {
+                Inline::Subscript(inlines.into_iter().map(Inline::into_owned).collect())
+            }
+        }
+    }
+
+    /// Borrow all text, rather than copying it.
+    pub(crate) fn reborrow(&self) -> Inline<'_> {
+        match self {
+            Self::Roman(text) => Inline::Roman(Cow::Borrowed(text)),
+            Self::Italic(text) => Inline::Italic(Cow::Borrowed(text)),
+            Self::Bold(text) => Inline::Bold(Cow::Borrowed(text)),
+            Self::LineBreak => Inline::LineBreak,
+            Self::Special(c) => Inline::Special(*c),
+            Self::HyphenationPoint => Inline::HyphenationPoint,
+            Self::BreakPoint => Inline::BreakPoint,
+            Self::Tab => Inline::Tab,
+            Self::ZeroWidth => Inline::ZeroWidth,
+            Self::Colored { color, inlines } => Inline::Colored {
+                color: Cow::Borrowed(color),
+                inlines: inlines.iter().map(Inline::reborrow).collect(),
+            },
+            Self::Resized { change, inlines } => Inline::Resized {
+                change: *change,
+                inlines: inlines.iter().map(Inline::reborrow).collect(),
+            },
+            Self::Superscript(inlines) => {
+                Inline::Superscript(inlines.iter().map(Inline::reborrow).collect())
+            }
+            Self::Subscript(inlines) => {
+                Inline::Subscript(inlines.iter().map(Inline::reborrow).collect())
+            }
+        }
+    }
+}
+
+/// The arguments of a control line without any, for
+/// [`Roff::control`].
+///
+/// ```
+/// # use roff::*;
+/// let doc = Roff::new().control("PP", NO_ARGS).to_roff();
+/// assert_eq!(doc, ".PP\n");
+/// ```
+pub const NO_ARGS: [&str; 0] = [];
+
 /// Return some inline text in the "roman" font.
 ///
 /// The roman font is the normal font, if no other font is chosen.
-pub fn roman(input: impl Into<String>) -> Inline {
+pub fn roman<'a>(input: impl Into<Cow<'a, str>>) -> Inline<'a> {
     Inline::Roman(input.into())
 }
 
 /// Return some inline text in the bold font.
-pub fn bold(input: impl Into<String>) -> Inline {
+pub fn bold<'a>(input: impl Into<Cow<'a, str>>) -> Inline<'a> {
     Inline::Bold(input.into())
 }
 
 /// Return some inline text in the italic font.
-pub fn italic(input: impl Into<String>) -> Inline {
+pub fn italic<'a>(input: impl Into<Cow<'a, str>>) -> Inline<'a> {
     Inline::Italic(input.into())
 }
 
 /// Return an inline element for a hard line break.
-pub fn line_break() -> Inline {
+pub fn line_break() -> Inline<'static> {
     Inline::LineBreak
 }
 
+/// Return an inline element for a special character.
+pub fn special(c: SpecialChar) -> Inline<'static> {
+    Inline::Special(c)
+}
+
+/// Return an inline element for a place where a word may be
+/// hyphenated.
+///
+/// See [`Inline::HyphenationPoint`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = Roff::new()
+///     .text([roman("use "), hyphenation_point(), bold("--no-hyphens")])
+///     .to_roff();
+/// assert_eq!(doc, "use \\%\\fB\\-\\-no\\-hyphens\\fR\n");
+/// ```
+pub fn hyphenation_point() -> Inline<'static> {
+    Inline::HyphenationPoint
+}
+
+/// Return an inline element for a place where a line may be broken
+/// without a hyphen.
+///
+/// See [`Inline::BreakPoint`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = Roff::new()
+///     .text([roman("/usr/share/"), break_point(), roman("man")])
+///     .to_roff();
+/// assert_eq!(doc, "/usr/share/\\:man\n");
+/// ```
+pub fn break_point() -> Inline<'static> {
+    Inline::BreakPoint
+}
+
+/// Return an inline element for a tab.
+///
+/// See [`Inline::Tab`].
+pub fn tab() -> Inline<'static> {
+    Inline::Tab
+}
+
+/// Return an inline element for a zero-width character that prints
+/// nothing.
+///
+/// See [`Inline::ZeroWidth`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = Roff::new()
+///     .text([roman("e.g."), zero_width(), roman(" this")])
+///     .to_roff();
+/// assert_eq!(doc, "e.g.\\& this\n");
+/// ```
+pub fn zero_width() -> Inline<'static> {
+    Inline::ZeroWidth
+}
+
 /// A line in a ROFF document.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub(crate) enum Line {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Line<'a> {
     /// A control line.
     Control {
         /// Name of control request or macro being invoked.
-        name: String,
+        name: Cow<'a, str>,
 
         /// Arguments on control line.
-        args: Vec<String>,
+        args: Vec<Cow<'a, str>>,
     },
 
     /// A text line.
-    Text(Vec<Inline>),
+    Text(Vec<Inline<'a>>),
 }
 
-impl Line {
+impl<'a> Line<'a> {
     /// Append a control line.
-    pub(crate) fn control(name: String, args: Vec<String>) -> Self {
+    pub(crate) fn control(name: Cow<'a, str>, args: Vec<Cow<'a, str>>) -> Self {
         Self::Control { name, args }
     }
 
     /// Append a text line, consisting of inline elements.
-    pub(crate) fn text(parts: Vec<Inline>) -> Self {
+    pub(crate) fn text(parts: Vec<Inline<'a>>) -> Self {
         Self::Text(parts)
     }
 
+    /// Borrow all text, rather than copying it.
+    pub(crate) fn reborrow(&self) -> Line<'_> {
+        match self {
+            Self::Control { name, args } => Line::Control {
+                name: Cow::Borrowed(name),
+                args: args.iter().map(|arg| Cow::Borrowed(arg.as_ref())).collect(),
+            },
+            Self::Text(inlines) => Line::Text(inlines.iter().map(Inline::reborrow).collect()),
+        }
+    }
+
+    /// Copy any borrowed text.
+    fn into_owned(self) -> Line<'static> {
+        match self {
+            Self::Control { name, args } => Line::Control {
+                name: Cow::Owned(name.into_owned()),
+                args: args
+                    .into_iter()
+                    .map(|arg| Cow::Owned(arg.into_owned()))
+                    .collect(),
+            },
+            Self::Text(inlines) => {
+                Line::Text(inlines.into_iter().map(Inline::into_owned).collect())
+            }
+        }
+    }
+
     /// Generate a ROFF line.
     ///
     /// All the ROFF code generation and special handling happens here.
-    fn render(
-        &self,
-        out: &mut dyn Write,
-        handle_apostrophes: Apostrophes,
-    ) -> Result<(), std::io::Error> {
+    fn render(&self, out: &mut dyn fmt::Write, options: &RenderOptions) -> fmt::Result {
         match self {
             Self::Control { name, args } => {
                 write!(out, ".{name}")?;
+                // These requests take the rest of their line, so
+                // quotes would become part of it.
+                let always_quote = options.always_quote && !matches!(&**name, "ds" | "tl");
                 for arg in args {
-                    write!(out, " {}", &escape_spaces(arg))?;
+                    let mut arg = quote_arg(arg, always_quote, options.version);
+                    if options.encoding == Encoding::Ascii {
+                        arg = escape_non_ascii(&arg, options.dialect);
+                    }
+                    write!(out, " {arg}")?;
                 }
             }
             Self::Text(inlines) => {
-                let mut at_line_start = true;
-                for inline in inlines.iter() {
-                    // We need to handle line breaking specially: it
-                    // introduces a control line to the ROFF, and the
-                    // leading period of that mustn't be escaped.
-                    match inline {
-                        Inline::LineBreak => {
-                            if at_line_start {
-                                writeln!(out, ".br")?;
-                            } else {
-                                writeln!(out, "\n.br")?;
-                            }
-                        }
-                        Inline::Roman(text) | Inline::Italic(text) | Inline::Bold(text) => {
-                            let mut text = escape_inline(text);
-                            if handle_apostrophes == Apostrophes::Handle {
-                                text = escape_apostrophes(&text);
-                            };
-                            let text = escape_leading_cc(&text);
-                            if let Inline::Bold(_) = inline {
-                                write!(out, r"\fB{text}\fR")?;
-                            } else if let Inline::Italic(_) = inline {
-                                write!(out, r"\fI{text}\fR")?;
-                            } else {
-                                if at_line_start && starts_with_cc(&text) {
-                                    // Line would start with a period, so we
-                                    // insert a non-printable, zero-width glyph to
-                                    // prevent it from being interpreted as such.
-                                    // We only do that when it's needed, though,
-                                    // to avoid making the output ugly.
-                                    //
-                                    // Note that this isn't handled by
-                                    // escape_leading_cc, as it
-                                    // doesn't know when an inline
-                                    // element is at the start of a
-                                    // line.
-                                    write!(out, r"\&").unwrap();
-                                }
-                                write!(out, "{text}")?;
-                            }
-                        }
-                    }
-                    at_line_start = false;
-                }
+                let minified;
+                let inlines = if options.minify {
+                    minified = minify::minify_inlines(inlines);
+                    &minified
+                } else {
+                    inlines
+                };
+                render_inlines(out, inlines, options, &mut true, false, &mut vec![])?;
             }
         };
         writeln!(out)?;
@@ -298,18 +1049,182 @@ impl Line {
     }
 }
 
+/// Generate the inline elements of a text line.
+///
+/// `more_follows` is whether more inline elements follow these on the
+/// line, and `colors` are those of the colored elements these are in.
+fn render_inlines<'r>(
+    out: &mut dyn fmt::Write,
+    inlines: &'r [Inline<'_>],
+    options: &RenderOptions,
+    at_line_start: &mut bool,
+    more_follows: bool,
+    colors: &mut Vec<&'r str>,
+) -> fmt::Result {
+    for (i, inline) in inlines.iter().enumerate() {
+        // We need to handle line breaking specially: it
+        // introduces a control line to the ROFF, and the
+        // leading period of that mustn't be escaped.
+        match inline {
+            Inline::LineBreak => {
+                if *at_line_start {
+                    writeln!(out, ".br")?;
+                } else {
+                    writeln!(out, "\n.br")?;
+                }
+                *at_line_start = true;
+            }
+            Inline::Special(c) => {
+                write!(out, "{}", c.escape())?;
+                *at_line_start = false;
+            }
+            Inline::HyphenationPoint => {
+                write!(out, r"\%")?;
+                *at_line_start = false;
+            }
+            Inline::BreakPoint => {
+                write!(out, r"\:")?;
+                *at_line_start = false;
+            }
+            Inline::Tab => {
+                write!(out, "\t")?;
+                *at_line_start = false;
+            }
+            Inline::ZeroWidth => {
+                write!(out, r"\&")?;
+                *at_line_start = false;
+            }
+            Inline::Colored {
+                color,
+                inlines: colored,
+            } => {
+                let has_colors = options.dialect.has_groff_escapes();
+                if has_colors {
+                    write!(out, r"\m[{color}]")?;
+                    *at_line_start = false;
+                }
+                colors.push(color);
+                let more_follows = more_follows || i + 1 < inlines.len();
+                render_inlines(out, colored, options, at_line_start, more_follows, colors)?;
+                colors.pop();
+                if has_colors {
+                    // `\m[]` only goes back one color, so a color inside
+                    // another goes back to the outer color by name.
+                    let outer = colors.last().copied().unwrap_or_default();
+                    write!(out, r"\m[{outer}]")?;
+                    *at_line_start = false;
+                }
+            }
+            Inline::Resized {
+                change,
+                inlines: resized,
+            } => {
+                // Changing back by the same amount, rather than with
+                // `\s0`, lets sizes nest.
+                write!(out, "{}", size_escape(*change, options.dialect))?;
+                *at_line_start = false;
+                let more_follows = more_follows || i + 1 < inlines.len();
+                render_inlines(out, resized, options, at_line_start, more_follows, colors)?;
+                write!(out, "{}", size_escape(-change, options.dialect))?;
+                *at_line_start = false;
+            }
+            Inline::Superscript(shifted) | Inline::Subscript(shifted) => {
+                let (there, back) = match inline {
+                    Inline::Superscript(_) => (r"\u", r"\d"),
+                    _ => (r"\d", r"\u"),
+                };
+                write!(out, r"{there}\s-2")?;
+                *at_line_start = false;
+                let more_follows = more_follows || i + 1 < inlines.len();
+                render_inlines(out, shifted, options, at_line_start, more_follows, colors)?;
+                write!(out, r"\s+2{back}")?;
+                *at_line_start = false;
+            }
+            Inline::Roman(text) | Inline::Italic(text) | Inline::Bold(text) => {
+                let escape = if options.smart_punctuation {
+                    punctuation::smarten
+                } else {
+                    escape_inline
+                };
+                let mut text = if options.sentence_per_line {
+                    let more_follows = more_follows || i + 1 < inlines.len();
+                    escape(&break_sentences(text, more_follows))
+                } else {
+                    escape(text)
+                };
+                if options.cjk_line_breaks {
+                    text = cjk::with_break_points(&text);
+                }
+                text = escape_apostrophes(&text, options.apostrophes, options.dialect);
+                if options.encoding == Encoding::Ascii {
+                    text = escape_non_ascii(&text, options.dialect);
+                }
+                let text = escape_leading_cc(&text);
+                if let Inline::Bold(_) = inline {
+                    write!(out, r"\fB{text}\fR")?;
+                } else if let Inline::Italic(_) = inline {
+                    write!(out, r"\fI{text}\fR")?;
+                } else {
+                    if *at_line_start && starts_with_cc(&text) {
+                        // Line would start with a period, so we
+                        // insert a non-printable, zero-width glyph to
+                        // prevent it from being interpreted as such.
+                        // We only do that when it's needed, though,
+                        // to avoid making the output ugly.
+                        //
+                        // Note that this isn't handled by
+                        // escape_leading_cc, as it
+                        // doesn't know when an inline
+                        // element is at the start of a
+                        // line.
+                        write!(out, r"\&")?;
+                    }
+                    write!(out, "{text}")?;
+                }
+                *at_line_start = text.ends_with('\n');
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The escape that changes the type size by some points.
+fn size_escape(change: i32, dialect: Dialect) -> String {
+    match change.unsigned_abs() {
+        0 => String::new(),
+        // The short form only takes a single digit.
+        1..=9 => format!(r"\s{change:+}"),
+        _ if dialect.has_groff_escapes() => format!(r"\s[{change:+}]"),
+        // The traditional form takes two digits at most.
+        size => {
+            let sign = if change < 0 { '-' } else { '+' };
+            format!(r"\s{sign}({:02}", size.min(99))
+        }
+    }
+}
+
 /// Does line start with a control character?
 fn starts_with_cc(line: &str) -> bool {
     line.starts_with('.') || line.starts_with('\'')
 }
 
-/// This quotes strings with spaces. This doesn't handle strings with
-/// quotes in any way: there doesn't seem to a way to escape them.
-fn escape_spaces(w: &str) -> String {
-    if w.contains(' ') {
-        format!("\"{w}\"")
-    } else {
-        w.to_owned()
+/// Quote a control line argument, if needed.
+///
+/// Arguments containing spaces (or tabs) are enclosed in double
+/// quotes, as are arguments containing double quotes, and empty
+/// arguments, which would otherwise vanish. Inside a quoted argument,
+/// a double quote is represented by two double quotes.
+///
+/// [`OutputVersion::V1`] only quotes arguments containing spaces, and
+/// leaves double quotes as they are.
+fn quote_arg(arg: &str, always: bool, version: OutputVersion) -> String {
+    match version {
+        OutputVersion::V1 if always || arg.contains(' ') => format!("\"{arg}\""),
+        OutputVersion::V1 => arg.to_owned(),
+        OutputVersion::V2 if always || arg.is_empty() || arg.contains([' ', '\t', '"']) => {
+            format!("\"{}\"", arg.replace('"', "\"\""))
+        }
+        OutputVersion::V2 => arg.to_owned(),
     }
 }
 
@@ -328,15 +1243,122 @@ fn escape_inline(text: &str) -> String {
     text.replace('\\', r"\\").replace('-', r"\-")
 }
 
+/// Start each sentence after the first on a new line.
+///
+/// A sentence ends with a period, question mark, or exclamation mark,
+/// optionally followed by closing parentheses, brackets, or quotes,
+/// and then spaces. The spaces are replaced by a newline, unless they
+/// end the text and nothing follows it on the same line.
+fn break_sentences(text: &str, more_follows: bool) -> String {
+    let mut broken = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut after_end = false;
+    while let Some(c) = chars.next() {
+        if after_end && c == ' ' {
+            let mut spaces = 1;
+            while chars.next_if_eq(&' ').is_some() {
+                spaces += 1;
+            }
+            match chars.peek() {
+                Some('\n') => broken.extend(std::iter::repeat(' ').take(spaces)),
+                None if !more_follows => broken.extend(std::iter::repeat(' ').take(spaces)),
+                _ => broken.push('\n'),
+            }
+            after_end = false;
+            continue;
+        }
+        after_end = matches!(c, '.' | '?' | '!')
+            || (after_end && matches!(c, ')' | ']' | '"' | '\'' | '’' | '”'));
+        broken.push(c);
+    }
+    broken
+}
+
+/// Replace characters outside of ASCII with glyph names or, if the
+/// dialect has them, Unicode escapes.
+fn escape_non_ascii(text: &str, dialect: Dialect) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            escaped.push(c);
+        } else if let Some(special) = SpecialChar::from_char(c) {
+            escaped.push_str(special.escape());
+        } else if !dialect.has_groff_escapes() {
+            escaped.push(c);
+        } else {
+            escaped.push_str(&format!(r"\[u{:04X}]", u32::from(c)));
+        }
+    }
+    escaped
+}
+
 /// Handle apostrophes.
-fn escape_apostrophes(text: &str) -> String {
-    text.replace('\'', APOSTROPHE)
+fn escape_apostrophes(text: &str, mode: ApostropheMode, dialect: Dialect) -> String {
+    match (mode, dialect) {
+        (ApostropheMode::Plain, _) => text.to_owned(),
+        (ApostropheMode::Preamble, Dialect::Groff) => text.replace('\'', APOSTROPHE),
+        // Without GNU troff, the preamble would only define the string
+        // as an apostrophe, except in mandoc, which has the glyph.
+        (ApostropheMode::Preamble, Dialect::Mandoc) => text.replace('\'', r"\(aq"),
+        (ApostropheMode::Unicode, dialect) if dialect.has_groff_escapes() => {
+            text.replace('\'', r"\[u0027]")
+        }
+        _ => text.to_owned(),
+    }
+}
+
+/// How much rendered text [`IoAdapter`] collects before writing it.
+const WRITE_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Write ROFF source text to an [`io::Write`], keeping the first
+/// error, as [`fmt::Write`] can't report it.
+///
+/// Rendering makes many small writes, several for each inline element,
+/// which are slow on writers without a buffer, such as files and
+/// pipes, so they are collected and written in chunks. [`finish`]
+/// writes what is left.
+///
+/// [`finish`]: IoAdapter::finish
+struct IoAdapter<'w> {
+    inner: &'w mut dyn io::Write,
+    buf: String,
+    error: Option<io::Error>,
+}
+
+impl<'w> IoAdapter<'w> {
+    fn new(inner: &'w mut dyn io::Write) -> Self {
+        Self {
+            inner,
+            buf: String::with_capacity(WRITE_BUFFER_SIZE),
+            error: None,
+        }
+    }
+
+    /// Write what has been collected.
+    fn finish(self) -> Result<(), io::Error> {
+        self.inner.write_all(self.buf.as_bytes())
+    }
+
+    /// The error that made writing fail.
+    fn into_error(self) -> io::Error {
+        self.error
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::Other, "formatting failed"))
+    }
 }
 
-#[derive(Eq, PartialEq)]
-enum Apostrophes {
-    Handle,
-    DontHandle,
+impl fmt::Write for IoAdapter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buf.push_str(s);
+        if self.buf.len() < WRITE_BUFFER_SIZE {
+            return Ok(());
+        }
+        let written = self.inner.write_all(self.buf.as_bytes());
+        self.buf.clear();
+        written.map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
 }
 
 /// Use the apostrophe string variable.
@@ -359,10 +1381,10 @@ const APOSTROPHE: &str = r"\*(Aq";
 /// The special handling of apostrophes is avoided in the
 /// [`to_roff`](Roff::to_roff) method, but it's used in the
 /// [`render`](Roff::render) and [`to_writer`](Roff::to_writer)
-/// methods.
+/// methods. Other methods follow [`RenderOptions::apostrophes`].
 ///
 /// See: <https://bugs.debian.org/cgi-bin/bugreport.cgi?bug=507673#65>
-const APOSTROPHE_PREABMLE: &str = r#".ie \n(.g .ds Aq \(aq
+pub(crate) const APOSTROPHE_PREABMLE: &str = r#".ie \n(.g .ds Aq \(aq
 .el .ds Aq '
 "#;
 
@@ -390,6 +1412,72 @@ mod test {
         assert_eq!("foo\n\\&.bar\n\\&'yo", escape_leading_cc("foo\n.bar\n'yo"));
     }
 
+    #[test]
+    fn escape_non_ascii_chars() {
+        assert_eq!(
+            r"caf\[u00E9] \(em \[u1F980]",
+            escape_non_ascii("café — 🦀", Dialect::Groff)
+        );
+    }
+
+    #[test]
+    fn quote_plain_arg() {
+        assert_eq!("foo", quote_arg("foo", false, OutputVersion::V2));
+        assert_eq!(r#""foo""#, quote_arg("foo", true, OutputVersion::V2));
+    }
+
+    #[test]
+    fn quote_empty_arg() {
+        assert_eq!(r#""""#, quote_arg("", false, OutputVersion::V2));
+    }
+
+    #[test]
+    fn quote_arg_with_spaces() {
+        assert_eq!(
+            r#""foo bar""#,
+            quote_arg("foo bar", false, OutputVersion::V2)
+        );
+        assert_eq!(
+            "\"foo\tbar\"",
+            quote_arg("foo\tbar", false, OutputVersion::V2)
+        );
+    }
+
+    #[test]
+    fn quote_arg_with_quotes() {
+        assert_eq!(
+            r#""My ""quoted"" tool""#,
+            quote_arg(r#"My "quoted" tool"#, false, OutputVersion::V2)
+        );
+        assert_eq!(
+            r#""""quoted""""#,
+            quote_arg(r#""quoted""#, false, OutputVersion::V2)
+        );
+    }
+
+    #[test]
+    fn quote_arg_v1() {
+        let v1 = OutputVersion::V1;
+        assert_eq!("foo", quote_arg("foo", false, v1));
+        assert_eq!(r#""foo""#, quote_arg("foo", true, v1));
+        assert_eq!(r#""foo bar""#, quote_arg("foo bar", false, v1));
+        assert_eq!("", quote_arg("", false, v1));
+        assert_eq!("foo\tbar", quote_arg("foo\tbar", false, v1));
+        assert_eq!(r#"a"b"#, quote_arg(r#"a"b"#, false, v1));
+    }
+
+    #[test]
+    fn break_plain_sentences() {
+        assert_eq!(
+            "One.\nTwo?\nThree!  ",
+            break_sentences("One. Two?  Three!  ", false)
+        );
+        assert_eq!("One.\nTwo.\n", break_sentences("One. Two. ", true));
+        assert_eq!("(See foo.)\nBar", break_sentences("(See foo.) Bar", false));
+        assert_eq!("1.5 times", break_sentences("1.5 times", false));
+        assert_eq!("End.  \nNext", break_sentences("End.  \nNext", false));
+    }
+
     #[test]
     fn escape_plain() {
         assert_eq!("abc", escape_inline("abc"));
@@ -444,6 +1532,273 @@ mod test {
         assert_eq!(text, "roman\n.br\nmore\n");
     }
 
+    #[test]
+    fn render_special() {
+        let text = Roff::new()
+            .text([
+                special(SpecialChar::Ellipsis),
+                roman(" and "),
+                SpecialChar::Bullet.into(),
+            ])
+            .to_roff();
+        assert_eq!(text, "\\&.\\|.\\|. and \\(bu\n");
+    }
+
+    #[test]
+    fn render_sentence_per_line() {
+        let options = RenderOptions::new().sentence_per_line(true);
+        let text = Roff::new()
+            .text([
+                roman("Set the number of bits to modify. "),
+                roman("Default is one bit. .5 is not allowed."),
+            ])
+            .render_with(&options);
+        assert!(text.ends_with(
+            "\nSet the number of bits to modify.\nDefault is one bit.\n\\&.5 is not allowed.\n"
+        ));
+    }
+
+    #[test]
+    fn render_text_after_line_break() {
+        let text = Roff::new()
+            .text([roman("foo"), line_break(), roman(".bar")])
+            .to_roff();
+        assert_eq!(text, "foo\n.br\n\\&.bar\n");
+    }
+
+    #[test]
+    fn render_apostrophe_modes() {
+        let mut doc = Roff::new();
+        doc.text([roman("don't")]);
+        let render = |mode| doc.render_with(&RenderOptions::new().apostrophes(mode));
+        assert_eq!(
+            render(ApostropheMode::Preamble),
+            format!("{APOSTROPHE_PREABMLE}don\\*(Aqt\n")
+        );
+        assert_eq!(render(ApostropheMode::Plain), "don't\n");
+        assert_eq!(render(ApostropheMode::Unicode), "don\\[u0027]t\n");
+    }
+
+    #[test]
+    fn render_ascii() {
+        let options = RenderOptions::new().encoding(Encoding::Ascii);
+        let text = Roff::new()
+            .control("TH", ["CAFÉ", "1"])
+            .text([roman("©")])
+            .render_with(&options);
+        assert!(text.ends_with(".TH CAF\\[u00C9] 1\n\\(co\n"));
+    }
+
+    #[test]
+    fn render_coding_comment() {
+        let options = RenderOptions::new()
+            .encoding(Encoding::Utf8)
+            .coding_comment(true);
+        let text = Roff::new().text([roman("café")]).render_with(&options);
+        assert!(text.starts_with(".\\\" -*- coding: utf-8 -*-\n.ie"));
+        assert!(text.ends_with("\ncafé\n"));
+    }
+
+    #[test]
+    fn render_always_quote() {
+        let options = RenderOptions::new().always_quote(true);
+        let text = Roff::new()
+            .control("TH", ["FOO", "1"])
+            .render_with(&options);
+        assert!(text.ends_with(".TH \"FOO\" \"1\"\n"));
+    }
+
+    #[test]
+    fn render_dialects() {
+        let mut doc = Roff::new();
+        doc.control("SH", ["NAMÉ"]).text([
+            roman("don't "),
+            colored("red", [resized(12, [roman("café")])]),
+        ]);
+        let render = |dialect| {
+            let options = RenderOptions::new()
+                .encoding(Encoding::Ascii)
+                .pdf_outline(true)
+                .dialect(dialect);
+            doc.render_with(&options)
+        };
+        assert_eq!(
+            render(Dialect::Mandoc),
+            ".SH NAM\\[u00C9]\n\
+             don\\(aqt \\m[red]\\s[+12]caf\\[u00E9]\\s[-12]\\m[]\n"
+        );
+        for dialect in [Dialect::Heirloom, Dialect::Plan9] {
+            assert_eq!(render(dialect), ".SH NAMÉ\ndon't \\s+(12café\\s-(12\n");
+        }
+    }
+
+    #[test]
+    fn render_pdf_outline() {
+        let options = RenderOptions::new()
+            .apostrophes(ApostropheMode::Plain)
+            .always_quote(true)
+            .pdf_outline(true);
+        let text = Roff::new()
+            .control("SH", ["OPTIONS"])
+            .control("SS", ["Output", "options"])
+            .control("SH", NO_ARGS)
+            .render_with(&options);
+        assert_eq!(
+            text,
+            ".SH \"OPTIONS\"\n\
+             .if \\n(.g .if d pdfhref .pdfhref O 1 \"OPTIONS\"\n\
+             .SS \"Output\" \"options\"\n\
+             .if \\n(.g .if d pdfhref .pdfhref O 2 \"Output options\"\n\
+             .SH\n"
+        );
+    }
+
+    #[test]
+    fn render_control_owned_args() {
+        let args = vec![String::from("foo"), String::from("bar")];
+        let text = Roff::new().control("foo", args).to_roff();
+        assert_eq!(text, ".foo foo bar\n");
+    }
+
+    #[test]
+    fn validate_names() {
+        assert_eq!(Roff::new().control("TH", ["FOO"]).validate(), Ok(()));
+        for name in ["", "T H", "TH\n", "T\"H", "\\fB", "TÉ"] {
+            let err = Roff::new()
+                .text([roman("foo")])
+                .control(name, NO_ARGS)
+                .validate()
+                .unwrap_err();
+            assert_eq!(err.index(), 1);
+            assert_eq!(err.name(), name);
+        }
+    }
+
+    #[test]
+    fn try_render_invalid() {
+        let err = Roff::new()
+            .control("S H", ["NAME"])
+            .try_render()
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidName(_)));
+        assert_eq!(
+            err.to_string(),
+            r#"line 1: invalid control name "S H": name contains whitespace"#
+        );
+    }
+
+    #[test]
+    fn try_control_rejects_invalid() {
+        let mut doc = Roff::new();
+        doc.try_control("SH", ["NAME"]).unwrap();
+        let err = doc.try_control("S\"H", ["NAME"]).unwrap_err();
+        assert!(matches!(err, Error::InvalidName(e) if e.index() == 1));
+        assert_eq!(doc.to_roff(), ".SH NAME\n");
+    }
+
+    #[test]
+    fn render_matches_to_writer() {
+        let mut doc = Roff::new();
+        doc.control("TH", ["FOO", "1"])
+            .text([roman("don't"), line_break(), bold("stop")]);
+        let mut buf = vec![];
+        doc.to_writer(&mut buf).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), doc.render());
+        let mut buf = String::from("existing\n");
+        doc.render_into(&mut buf);
+        assert_eq!(buf, format!("existing\n{}", doc.render()));
+    }
+
+    #[test]
+    fn to_writer_writes_in_chunks() {
+        struct CountingWriter {
+            writes: usize,
+            written: Vec<u8>,
+        }
+
+        impl io::Write for CountingWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.writes += 1;
+                self.written.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut doc = Roff::new();
+        for _ in 0..1000 {
+            doc.control("TP", NO_ARGS)
+                .text([bold("--foo"), roman(" "), italic("bar")]);
+        }
+        let mut w = CountingWriter {
+            writes: 0,
+            written: vec![],
+        };
+        doc.to_writer(&mut w).unwrap();
+        assert_eq!(String::from_utf8(w.written).unwrap(), doc.render());
+        assert!(w.writes < 10, "{} writes", w.writes);
+    }
+
+    #[test]
+    fn borrowed_text() {
+        let name = String::from("foo");
+        let mut doc = Roff::new();
+        doc.control("TH", [name.as_str(), "1"]).text([bold(&name)]);
+        let owned: Roff<'static> = doc.into_owned();
+        drop(name);
+        assert_eq!(owned.to_roff(), ".TH foo 1\n\\fBfoo\\fR\n");
+    }
+
+    #[test]
+    fn collect_fragments() {
+        let mut line = Roff::new();
+        line.text([roman("bar"), italic("baz")]);
+        let doc: Roff<'_> = [Roff::from(bold("foo")), line]
+            .into_iter()
+            .chain([Roff::from("quux")])
+            .collect();
+        assert_eq!(doc.to_roff(), "\\fBfoo\\fR\nbar\\fIbaz\\fR\nquux\n");
+    }
+
+    #[test]
+    fn add_fragments() {
+        let mut doc = Roff::from("foo") + Roff::from(bold("bar"));
+        doc += Roff::from("baz");
+        assert_eq!(doc.to_roff(), "foo\n\\fBbar\\fR\nbaz\n");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let mut doc = Roff::new();
+        doc.control("TH", ["FOO", "1"]).text([
+            roman("a"),
+            italic("b"),
+            line_break(),
+            SpecialChar::Bullet.into(),
+        ]);
+        let json = serde_json::to_string(&doc).unwrap();
+        assert_eq!(
+            json,
+            r#"{"lines":[{"Control":{"name":"TH","args":["FOO","1"]}},{"Text":[{"Roman":"a"},{"Italic":"b"},"LineBreak",{"Special":"Bullet"}]}]}"#
+        );
+        let back: Roff<'_> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, doc);
+    }
+
+    #[test]
+    fn inline_conversions() {
+        let owned = String::from("b");
+        assert_eq!(Inline::from("a"), roman("a"));
+        assert_eq!(Inline::from(owned.clone()), roman("b"));
+        assert_eq!(Inline::from(&owned), roman("b"));
+        assert_eq!(Inline::from(Cow::Borrowed("c")), roman("c"));
+        assert_eq!(Inline::from('d'), roman("d"));
+    }
+
     #[test]
     fn render_control() {
         let text = Roff::new().control("foo", ["bar", "foo and bar"]).to_roff();
@@ -0,0 +1,273 @@
+use std::fmt;
+
+use crate::{bold, italic, roman, Inline};
+
+/// Write a document as a sequence of lines.
+///
+/// Each line ends with a semicolon. A line starting with a period is a
+/// control line: the name of the request or macro, then its
+/// arguments. An argument is a word, a literal, or an expression in
+/// braces, and is converted to text with [`ToString`].
+///
+/// Any other line is text: a sequence of string literals, which are in
+/// the roman font, calls to [`roman`](crate::roman),
+/// [`bold`](crate::bold), or [`italic`](crate::italic), or expressions
+/// in braces that convert into an [`Inline`](crate::Inline). Text is
+/// escaped when the document is rendered, as usual, so it is written
+/// as it should appear.
+///
+/// The macro handles one token at a time, so very long documents may
+/// need a higher `#![recursion_limit]`.
+///
+/// # Example
+///
+/// ```
+/// # use roff::roff;
+/// let section = 1;
+/// let doc = roff! {
+///     .TH FOO {section};
+///     .SH NAME;
+///     "foo - do a foo thing";
+///     .SH "SEE ALSO";
+///     bold("bar") "(1)";
+/// };
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".TH FOO 1\n\
+///      .SH NAME\n\
+///      foo \\- do a foo thing\n\
+///      .SH \"SEE ALSO\"\n\
+///      \\fBbar\\fR(1)\n"
+/// );
+/// ```
+#[macro_export]
+macro_rules! roff {
+    ($($body:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut doc = $crate::Roff::new();
+        $crate::__roff_lines!(doc; $($body)*);
+        doc
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __roff_lines {
+    ($doc:ident;) => {};
+    ($doc:ident; . $name:ident $($rest:tt)*) => {
+        $crate::__roff_control!($doc; $name; []; $($rest)*)
+    };
+    ($doc:ident; $($rest:tt)+) => {
+        $crate::__roff_text!($doc; []; $($rest)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __roff_control {
+    ($doc:ident; $name:ident; [$($args:expr),*]; ; $($rest:tt)*) => {
+        let args: ::std::vec::Vec<::std::string::String> = ::std::vec![$($args),*];
+        $doc.control(::std::stringify!($name), args);
+        $crate::__roff_lines!($doc; $($rest)*);
+    };
+    ($doc:ident; $name:ident; [$($args:expr),*]; $arg:ident $($rest:tt)*) => {
+        $crate::__roff_control!(
+            $doc; $name;
+            [$($args,)* ::std::string::String::from(::std::stringify!($arg))];
+            $($rest)*
+        )
+    };
+    ($doc:ident; $name:ident; [$($args:expr),*]; $arg:literal $($rest:tt)*) => {
+        $crate::__roff_control!(
+            $doc; $name;
+            [$($args,)* ::std::string::ToString::to_string(&$arg)];
+            $($rest)*
+        )
+    };
+    ($doc:ident; $name:ident; [$($args:expr),*]; { $arg:expr } $($rest:tt)*) => {
+        $crate::__roff_control!(
+            $doc; $name;
+            [$($args,)* ::std::string::ToString::to_string(&$arg)];
+            $($rest)*
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __roff_text {
+    ($doc:ident; [$($inlines:expr),*]; ; $($rest:tt)*) => {
+        $doc.text(::std::vec![$($inlines),*]);
+        $crate::__roff_lines!($doc; $($rest)*);
+    };
+    ($doc:ident; [$($inlines:expr),*]; $text:literal $($rest:tt)*) => {
+        $crate::__roff_text!($doc; [$($inlines,)* $crate::roman($text)]; $($rest)*)
+    };
+    ($doc:ident; [$($inlines:expr),*]; roman($text:expr) $($rest:tt)*) => {
+        $crate::__roff_text!($doc; [$($inlines,)* $crate::roman($text)]; $($rest)*)
+    };
+    ($doc:ident; [$($inlines:expr),*]; bold($text:expr) $($rest:tt)*) => {
+        $crate::__roff_text!($doc; [$($inlines,)* $crate::bold($text)]; $($rest)*)
+    };
+    ($doc:ident; [$($inlines:expr),*]; italic($text:expr) $($rest:tt)*) => {
+        $crate::__roff_text!($doc; [$($inlines,)* $crate::italic($text)]; $($rest)*)
+    };
+    ($doc:ident; [$($inlines:expr),*]; { $inline:expr } $($rest:tt)*) => {
+        $crate::__roff_text!(
+            $doc;
+            [$($inlines,)* ::std::convert::Into::<$crate::Inline<'_>>::into($inline)];
+            $($rest)*
+        )
+    };
+}
+
+/// Write text mixing fonts, as a `Vec<Inline>`.
+///
+/// Text in braces is in the font named before a colon: `roman`,
+/// `bold`, or `italic`. Everything else is in the roman font. As with
+/// [`format!`], `{}` is replaced by the next argument, which may be
+/// anything that implements [`Display`](std::fmt::Display), including
+/// inside a font, and `{{` and `}}` stand for literal braces.
+///
+/// # Panics
+///
+/// If a brace isn't matched, a font isn't known, or the number of
+/// arguments doesn't match the number of `{}`.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let value = "BITS";
+/// let text = styled!("use {bold:--bits} to set {italic:{}}", value);
+/// assert_eq!(
+///     text,
+///     [roman("use "), bold("--bits"), roman(" to set "), italic("BITS")]
+/// );
+/// ```
+#[macro_export]
+macro_rules! styled {
+    ($format:expr $(, $arg:expr)* $(,)?) => {
+        $crate::__styled($format, &[$(&$arg as &dyn ::std::fmt::Display),*])
+    };
+}
+
+#[doc(hidden)]
+pub fn __styled(format: &str, args: &[&dyn fmt::Display]) -> Vec<Inline<'static>> {
+    let mut args = args.iter();
+    let mut next_arg = || {
+        args.next()
+            .unwrap_or_else(|| panic!("missing argument in {format:?}"))
+            .to_string()
+    };
+
+    let mut inlines = vec![];
+    let mut text = String::new();
+    let mut font: Option<fn(String) -> Inline<'static>> = None;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '{' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push_str(&next_arg());
+            }
+            '{' if font.is_none() => {
+                let mut name = String::new();
+                for c in chars.by_ref() {
+                    if c == ':' {
+                        break;
+                    }
+                    name.push(c);
+                }
+                font = Some(match name.as_str() {
+                    "roman" => roman,
+                    "bold" => bold,
+                    "italic" => italic,
+                    _ => panic!("unknown font {name:?} in {format:?}"),
+                });
+                if !text.is_empty() {
+                    inlines.push(roman(std::mem::take(&mut text)));
+                }
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '}' => {
+                let styled = font
+                    .take()
+                    .unwrap_or_else(|| panic!("unmatched '}}' in {format:?}"));
+                if !text.is_empty() {
+                    inlines.push(styled(std::mem::take(&mut text)));
+                }
+            }
+            '{' => panic!("nested font in {format:?}"),
+            _ => text.push(c),
+        }
+    }
+    if font.is_some() {
+        panic!("unmatched '{{' in {format:?}");
+    }
+    if !text.is_empty() {
+        inlines.push(roman(text));
+    }
+    if args.next().is_some() {
+        panic!("too many arguments for {format:?}");
+    }
+    inlines
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{bold, Roff, NO_ARGS};
+
+    #[test]
+    fn matches_builder() {
+        let name = "foo";
+        let doc = roff! {
+            .TH "FOO" 1 "2021-12-23";
+            .SH NAME;
+            {bold(name)} " - a " italic("thing");
+            .br;
+            {format!("{name}!")};
+        };
+        let mut expected = Roff::new();
+        expected
+            .control("TH", ["FOO", "1", "2021-12-23"])
+            .control("SH", ["NAME"])
+            .text([bold("foo"), crate::roman(" - a "), crate::italic("thing")])
+            .control("br", NO_ARGS)
+            .text([crate::roman("foo!")]);
+        assert_eq!(doc, expected);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(roff! {}, Roff::new());
+    }
+
+    #[test]
+    fn styled_braces_and_arguments() {
+        assert_eq!(
+            styled!("{{{}}} {bold:a{}b}", 1, 2),
+            [crate::roman("{1} "), bold("a2b")]
+        );
+        assert_eq!(styled!(""), []);
+    }
+
+    #[test]
+    #[should_panic = "unknown font"]
+    fn styled_unknown_font() {
+        styled!("{blue:text}");
+    }
+
+    #[test]
+    #[should_panic = "too many arguments"]
+    fn styled_extra_argument() {
+        styled!("text", 1);
+    }
+}
@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use super::ManPage;
+use crate::Roff;
+
+impl<'a> ManPage<'a> {
+    /// Add another name the page can be found under.
+    ///
+    /// This is for commands installed under several names, such as
+    /// `vi` and `view`. Each alias gets a stub page that includes this
+    /// one with the `so` request, in the same section.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::man::{ManPage, ManSection};
+    /// let mut page = ManPage::new("VI", ManSection::Executable);
+    /// page.alias("view");
+    /// assert_eq!(
+    ///     page.alias_stubs(),
+    ///     [("view.1".to_owned(), ".so man1/vi.1\n".to_owned())]
+    /// );
+    /// ```
+    pub fn alias(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.aliases.push(name.into());
+        self
+    }
+
+    /// The other names of the page.
+    pub fn aliases(&self) -> &[Cow<'a, str>] {
+        &self.aliases
+    }
+
+    /// The stub page for each alias, as pairs of a file name and ROFF
+    /// source text.
+    ///
+    /// The stubs refer to the page by its path relative to the
+    /// manual directory, as `man` expects, so they must be installed
+    /// in the same directory as the page.
+    pub fn alias_stubs(&self) -> Vec<(String, String)> {
+        self.alias_stub_docs()
+            .into_iter()
+            .map(|(file_name, stub)| (file_name, stub.to_roff()))
+            .collect()
+    }
+
+    /// The stub page for each alias, as documents.
+    pub(crate) fn alias_stub_docs(&self) -> Vec<(String, Roff<'static>)> {
+        let target = format!("{}/{}", self.section_dir(), self.file_name());
+        self.aliases
+            .iter()
+            .map(|alias| {
+                // A stub is a single line: no apostrophe preamble.
+                let mut stub = Roff::new();
+                stub.control("so", [target.clone()]);
+                (format!("{alias}.{}", self.section), stub)
+            })
+            .collect()
+    }
+}
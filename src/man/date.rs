@@ -0,0 +1,85 @@
+use std::env;
+
+use time::OffsetDateTime;
+
+use super::ManPage;
+use crate::Error;
+
+/// The date for the `TH` line of a manual page, as `YYYY-MM-DD`.
+///
+/// For [reproducible builds], this is the date of the
+/// `SOURCE_DATE_EPOCH` environment variable, if it is set, so that the
+/// page doesn't change from one build to the next. Otherwise it is
+/// today's date, in UTC. It is an error for `SOURCE_DATE_EPOCH` not to
+/// be a number of seconds since the Unix epoch.
+///
+/// [reproducible builds]: https://reproducible-builds.org/docs/source-date-epoch/
+pub fn th_date() -> Result<String, Error> {
+    match env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => {
+            let timestamp = epoch
+                .trim()
+                .parse()
+                .map_err(|_| Error::SourceDateEpoch(epoch.clone()))?;
+            th_date_from_timestamp(timestamp).ok_or(Error::SourceDateEpoch(epoch))
+        }
+        Err(_) => Ok(format_date(OffsetDateTime::now_utc())),
+    }
+}
+
+/// The date for the `TH` line of a manual page, as `YYYY-MM-DD`, from
+/// a number of seconds since the Unix epoch.
+///
+/// This is `None` if the date is out of range.
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::th_date_from_timestamp;
+/// assert_eq!(th_date_from_timestamp(1640217600).unwrap(), "2021-12-23");
+/// ```
+pub fn th_date_from_timestamp(timestamp: i64) -> Option<String> {
+    let date = OffsetDateTime::from_unix_timestamp(timestamp).ok()?;
+    Some(format_date(date))
+}
+
+fn format_date(date: OffsetDateTime) -> String {
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    )
+}
+
+impl ManPage<'_> {
+    /// Set the date to [`th_date`], unless it has already been set.
+    ///
+    /// A date set with [`ManPage::date`] overrides `SOURCE_DATE_EPOCH`
+    /// and today's date.
+    pub fn default_date(&mut self) -> Result<&mut Self, Error> {
+        if self.date.is_none() {
+            self.date = Some(th_date()?.into());
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(th_date_from_timestamp(0).unwrap(), "1970-01-01");
+        assert_eq!(th_date_from_timestamp(951782400).unwrap(), "2000-02-29");
+        assert_eq!(th_date_from_timestamp(i64::MAX), None);
+    }
+
+    #[test]
+    fn explicit_date_wins() {
+        let mut page = ManPage::new("FOO", crate::man::ManSection::Executable);
+        page.date("2021-12-23").default_date().unwrap();
+        assert_eq!(page.date.as_deref(), Some("2021-12-23"));
+    }
+}
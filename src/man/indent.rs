@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use crate::edit::is_paragraph;
+use crate::{Roff, NO_ARGS};
+
+/// Indent content, with the `RS` and `RE` macros.
+///
+/// The content is appended by the given function, and is indented
+/// relative to where it starts, by the default indentation of the
+/// ROFF implementation. After an item of a list, it is indented
+/// relative to the body of the item, which makes for nested lists.
+/// `RE` resets the indentation of paragraphs, so the `PP` that ends a
+/// [bulleted](super::bullet_list) or [numbered](super::numbered_list)
+/// list is left out at the end of the content.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::{bullet_list, indented, TaggedList};
+/// let mut list = TaggedList::new();
+/// list.item([bold("fruit")], [[roman("Sweet things:")]]);
+/// let mut doc = list.into_roff();
+/// doc.append(indented(|doc| {
+///     doc.append(bullet_list([[roman("apple")], [roman("pear")]]));
+/// }));
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".TP\n\
+///      \\fBfruit\\fR\n\
+///      Sweet things:\n\
+///      .RS\n\
+///      .IP \\(bu 2\n\
+///      apple\n\
+///      .IP \\(bu 2\n\
+///      pear\n\
+///      .RE\n"
+/// );
+/// ```
+pub fn indented<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    indent(None, content)
+}
+
+/// Indent content by a given width, such as `4` or `8n`.
+///
+/// See [`indented`].
+pub fn indented_by<'a>(
+    width: impl Into<Cow<'a, str>>,
+    content: impl FnOnce(&mut Roff<'a>),
+) -> Roff<'a> {
+    indent(Some(width.into()), content)
+}
+
+fn indent<'a>(width: Option<Cow<'a, str>>, content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.control("RS", width);
+    content(&mut doc);
+    // `RE` resets the indentation of paragraphs, so a paragraph macro
+    // ending the content, as after a list, would only add space.
+    if doc.lines.last().is_some_and(is_paragraph) {
+        doc.lines.pop();
+    }
+    doc.control("RE", NO_ARGS);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::roman;
+
+    #[test]
+    fn indented_by_width() {
+        let doc = indented_by("4", |doc| {
+            doc.text([roman("text")]);
+        });
+        assert_eq!(doc.to_roff(), ".RS 4\ntext\n.RE\n");
+    }
+}
@@ -0,0 +1,167 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{Locale, ManPage};
+#[cfg(feature = "gzip")]
+use crate::{ApostropheMode, RenderOptions};
+
+impl ManPage<'_> {
+    /// The name of the file the page is conventionally installed as,
+    /// such as `foo.1`.
+    ///
+    /// This is the name given to [`ManPage::name`], or else the title
+    /// in lower case, followed by the section.
+    pub fn file_name(&self) -> String {
+        format!("{}.{}", self.page_name(), self.section)
+    }
+
+    /// The name of the page, without the section.
+    pub(crate) fn page_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone().into_owned(),
+            None => self.title.to_lowercase(),
+        }
+    }
+
+    /// The directory, relative to a manual directory such as
+    /// `/usr/share/man`, that the page is conventionally installed
+    /// in, such as `man1`.
+    ///
+    /// Pages in subsections, such as `3p`, go in the directory for the
+    /// section.
+    pub fn section_dir(&self) -> String {
+        let number = self.section.trim_end_matches(|c: char| !c.is_ascii_digit());
+        format!("man{number}")
+    }
+
+    /// Write the rendered page to where it conventionally belongs
+    /// under `prefix`, creating directories as needed.
+    ///
+    /// The page is written to `<prefix>/man/man<section>/<file name>`,
+    /// so `prefix` is typically something like `/usr/local/share`.
+    /// Pages in another [locale](ManPage::locale) than English are
+    /// written to `<prefix>/man/<locale>/man<section>/<file name>`.
+    /// The path written to is returned. A [stub](ManPage::alias_stubs)
+    /// is written next to it for each alias of the page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use roff::man::{ManPage, ManSection};
+    /// let mut page = ManPage::new("FOO", ManSection::Executable);
+    /// page.name("foo", "do a foo thing");
+    /// let path = page.install("/usr/local/share").unwrap();
+    /// assert_eq!(path, std::path::Path::new("/usr/local/share/man/man1/foo.1"));
+    /// ```
+    pub fn install(&self, prefix: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let dir = self.install_dir(prefix.as_ref())?;
+        for (file_name, stub) in self.alias_stubs() {
+            fs::write(dir.join(file_name), stub)?;
+        }
+        let path = dir.join(self.file_name());
+        fs::write(&path, self.render())?;
+        Ok(path)
+    }
+
+    /// Write the rendered page, compressed with gzip, to where it
+    /// conventionally belongs under `prefix`.
+    ///
+    /// This is like [`install`](ManPage::install), except that `.gz`
+    /// is added to the file name. See also
+    /// [`Roff::render_gz`](crate::Roff::render_gz).
+    #[cfg(feature = "gzip")]
+    pub fn install_gz(&self, prefix: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let dir = self.install_dir(prefix.as_ref())?;
+        // As with `alias_stubs`, the stubs have no preamble.
+        let plain = RenderOptions::new().apostrophes(ApostropheMode::Plain);
+        for (file_name, stub) in self.alias_stub_docs() {
+            let mut gz = vec![];
+            stub.to_gz_writer_with(&mut gz, &plain)?;
+            fs::write(dir.join(format!("{file_name}.gz")), gz)?;
+        }
+        let path = dir.join(format!("{}.gz", self.file_name()));
+        fs::write(&path, self.reborrow().into_roff().render_gz())?;
+        Ok(path)
+    }
+
+    /// Create the directory to install the page in.
+    fn install_dir(&self, prefix: &Path) -> io::Result<PathBuf> {
+        let mut dir = prefix.join("man");
+        if self.locale != Locale::English {
+            dir.push(self.locale.code());
+        }
+        dir.push(self.section_dir());
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::ManSection;
+
+    #[test]
+    fn file_names() {
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        assert_eq!(page.file_name(), "foo.1");
+        assert_eq!(page.section_dir(), "man1");
+        page.name("Foo", "do a foo thing");
+        assert_eq!(page.file_name(), "Foo.1");
+
+        let mut page = ManPage::new("FOO", ManSection::LibraryCall);
+        page.suffix("p").unwrap();
+        assert_eq!(page.file_name(), "foo.3p");
+        assert_eq!(page.section_dir(), "man3");
+    }
+
+    #[test]
+    fn installs() {
+        let prefix = std::env::temp_dir().join(format!("roff-install-{}", std::process::id()));
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.name("foo", "do a foo thing");
+        page.alias("bar");
+        let path = page.install(&prefix).unwrap();
+        assert_eq!(path, prefix.join("man/man1/foo.1"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), page.render());
+        assert_eq!(
+            fs::read_to_string(prefix.join("man/man1/bar.1")).unwrap(),
+            ".so man1/foo.1\n"
+        );
+        fs::remove_dir_all(&prefix).unwrap();
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn installs_gz() {
+        use std::io::Read as _;
+
+        let gunzip = |path: PathBuf| {
+            let mut text = String::new();
+            flate2::read::GzDecoder::new(fs::File::open(path).unwrap())
+                .read_to_string(&mut text)
+                .unwrap();
+            text
+        };
+        let prefix = std::env::temp_dir().join(format!("roff-install-gz-{}", std::process::id()));
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.name("foo", "do a foo thing");
+        page.alias("bar");
+        let path = page.install_gz(&prefix).unwrap();
+        assert_eq!(path, prefix.join("man/man1/foo.1.gz"));
+        assert_eq!(gunzip(path), page.render());
+        assert_eq!(gunzip(prefix.join("man/man1/bar.1.gz")), ".so man1/foo.1\n");
+        fs::remove_dir_all(&prefix).unwrap();
+    }
+
+    #[test]
+    fn installs_in_locale_dir() {
+        let prefix = std::env::temp_dir().join(format!("roff-locale-{}", std::process::id()));
+        let mut page = ManPage::new("FOO", ManSection::FileFormat);
+        page.locale(Locale::BrazilianPortuguese);
+        let path = page.install(&prefix).unwrap();
+        assert_eq!(path, prefix.join("man/pt_BR/man5/foo.5"));
+        fs::remove_dir_all(&prefix).unwrap();
+    }
+}
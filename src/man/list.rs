@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+use crate::{Inline, Roff, SpecialChar, NO_ARGS};
+
+/// A list of tagged paragraphs, such as the options of a command.
+///
+/// Each item has a tag, set on a line of its own, and a body of
+/// paragraphs, indented below it. This is what the `TP` macro is
+/// for, and getting its sequence of macros right by hand is fiddly.
+///
+/// Text directly after the list is indented as the last body was;
+/// start a new paragraph after it, as [`Section`](super::Section)
+/// does.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::TaggedList;
+/// let mut list = TaggedList::new();
+/// list.item([bold("-v")], [[roman("Be verbose.")]])
+///     .item(
+///         [bold("-q")],
+///         [[roman("Be quiet.")], [roman("Really quiet.")]],
+///     );
+/// assert_eq!(
+///     list.into_roff().to_roff(),
+///     ".TP\n\
+///      \\fB\\-v\\fR\n\
+///      Be verbose.\n\
+///      .TP\n\
+///      \\fB\\-q\\fR\n\
+///      Be quiet.\n\
+///      .IP\n\
+///      Really quiet.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct TaggedList<'a> {
+    indent: Option<Cow<'a, str>>,
+    items: Vec<(Vec<Inline<'a>>, Vec<Vec<Inline<'a>>>)>,
+}
+
+impl<'a> TaggedList<'a> {
+    /// Start an empty list.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set how far bodies are indented from tags, such as `4` or `8n`.
+    ///
+    /// By default, the indentation of the ROFF implementation is used.
+    pub fn indent(&mut self, width: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.indent = Some(width.into());
+        self
+    }
+
+    /// Append an item, with a tag and paragraphs for its body.
+    pub fn item<P: Into<Vec<Inline<'a>>>>(
+        &mut self,
+        tag: impl Into<Vec<Inline<'a>>>,
+        body: impl IntoIterator<Item = P>,
+    ) -> &mut Self {
+        let body = body.into_iter().map(Into::into).collect();
+        self.items.push((tag.into(), body));
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let mut doc = Roff::new();
+        for (tag, body) in self.items {
+            doc.control("TP", self.indent.clone());
+            doc.text(tag);
+            for (i, paragraph) in body.into_iter().enumerate() {
+                if i > 0 {
+                    // Without a tag, `IP` keeps the indentation of the
+                    // `TP` before it.
+                    doc.control("IP", NO_ARGS);
+                }
+                doc.text(paragraph);
+            }
+        }
+        doc
+    }
+}
+
+impl<'a> From<TaggedList<'a>> for Roff<'a> {
+    fn from(list: TaggedList<'a>) -> Self {
+        list.into_roff()
+    }
+}
+
+/// A bulleted list, with a paragraph for each item.
+///
+/// Each item is an indented paragraph with a bullet as its tag. The
+/// list ends with `PP`, so that text after it isn't indented as the
+/// last item was. In an [indented](super::indented) block, the `RE`
+/// at its end does that instead.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::bullet_list;
+/// let list = bullet_list([[roman("one")], [roman("two")]]);
+/// assert_eq!(list.to_roff(), ".IP \\(bu 2\none\n.IP \\(bu 2\ntwo\n.PP\n");
+/// ```
+pub fn bullet_list<'a, I: Into<Vec<Inline<'a>>>>(items: impl IntoIterator<Item = I>) -> Roff<'a> {
+    let mut doc = Roff::new();
+    for item in items {
+        doc.control("IP", [SpecialChar::Bullet.escape(), "2"]);
+        doc.text(item);
+    }
+    doc.control("PP", NO_ARGS);
+    doc
+}
+
+/// A numbered list, with a paragraph for each item.
+///
+/// Items are numbered from 1. See [`bullet_list`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::numbered_list;
+/// let list = numbered_list([[roman("one")], [roman("two")]]);
+/// assert_eq!(list.to_roff(), ".IP 1. 4\none\n.IP 2. 4\ntwo\n.PP\n");
+/// ```
+pub fn numbered_list<'a, I: Into<Vec<Inline<'a>>>>(items: impl IntoIterator<Item = I>) -> Roff<'a> {
+    let mut doc = Roff::new();
+    for (i, item) in items.into_iter().enumerate() {
+        // Wide enough for two digits and the period.
+        doc.control("IP", [format!("{}.", i + 1), "4".to_owned()]);
+        doc.text(item);
+    }
+    doc.control("PP", NO_ARGS);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bold, roman};
+
+    #[test]
+    fn indent() {
+        let mut list = TaggedList::new();
+        list.indent("4")
+            .item([bold("a")], [[roman("one")]])
+            .item([bold("b")], [] as [[Inline<'_>; 0]; 0]);
+        assert_eq!(
+            list.into_roff().to_roff(),
+            ".TP 4\n\\fBa\\fR\none\n.TP 4\n\\fBb\\fR\n"
+        );
+    }
+
+    #[test]
+    fn paragraph_after_list_is_reset() {
+        let mut doc = numbered_list([[roman("item")]]);
+        doc.text([roman("after")]);
+        assert_eq!(doc.to_roff(), ".IP 1. 4\nitem\n.PP\nafter\n");
+
+        let mut section = crate::man::Section::new("NOTES");
+        section
+            .append(bullet_list([[roman("item")]]))
+            .paragraph([roman("after")]);
+        assert_eq!(
+            section.into_roff().to_roff(),
+            ".SH NOTES\n.IP \\(bu 2\nitem\n.PP\nafter\n"
+        );
+    }
+}
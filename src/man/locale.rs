@@ -0,0 +1,228 @@
+/// A language for the standard section headings of a manual page.
+///
+/// Manual pages in other languages than English have their headings
+/// translated, as `mandb` and `apropos` look for the translated NAME
+/// section to index them. See [`ManPage::locale`](super::ManPage::locale).
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::Locale;
+/// assert_eq!(Locale::German.heading("SEE ALSO"), Some("SIEHE AUCH"));
+/// assert_eq!(Locale::German.heading("HACKING"), None);
+/// assert_eq!(Locale::German.code(), "de");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[non_exhaustive]
+pub enum Locale {
+    /// English, which the headings are conventionally in.
+    #[default]
+    English,
+
+    /// German: `de`.
+    German,
+
+    /// Spanish: `es`.
+    Spanish,
+
+    /// French: `fr`.
+    French,
+
+    /// Italian: `it`.
+    Italian,
+
+    /// Brazilian Portuguese: `pt_BR`.
+    BrazilianPortuguese,
+}
+
+/// The standard headings, in English, in the order of the
+/// translations.
+const HEADINGS: [&str; 20] = [
+    "NAME",
+    "SYNOPSIS",
+    "DESCRIPTION",
+    "OPTIONS",
+    "EXIT STATUS",
+    "RETURN VALUE",
+    "ERRORS",
+    "ENVIRONMENT",
+    "FILES",
+    "VERSIONS",
+    "STANDARDS",
+    "HISTORY",
+    "NOTES",
+    "BUGS",
+    "EXAMPLES",
+    "AUTHORS",
+    "REPORTING BUGS",
+    "COPYRIGHT",
+    "SEE ALSO",
+    "CONTENTS",
+];
+
+const GERMAN: [&str; 20] = [
+    "BEZEICHNUNG",
+    "ÜBERSICHT",
+    "BESCHREIBUNG",
+    "OPTIONEN",
+    "EXIT-STATUS",
+    "RÜCKGABEWERT",
+    "FEHLER",
+    "UMGEBUNGSVARIABLEN",
+    "DATEIEN",
+    "VERSIONEN",
+    "STANDARDS",
+    "GESCHICHTE",
+    "ANMERKUNGEN",
+    "FEHLER",
+    "BEISPIELE",
+    "AUTOREN",
+    "FEHLER MELDEN",
+    "COPYRIGHT",
+    "SIEHE AUCH",
+    "INHALT",
+];
+
+const SPANISH: [&str; 20] = [
+    "NOMBRE",
+    "SINOPSIS",
+    "DESCRIPCIÓN",
+    "OPCIONES",
+    "ESTADO DE SALIDA",
+    "VALOR DEVUELTO",
+    "ERRORES",
+    "ENTORNO",
+    "ARCHIVOS",
+    "VERSIONES",
+    "ESTÁNDARES",
+    "HISTORIAL",
+    "NOTAS",
+    "ERRORES",
+    "EJEMPLOS",
+    "AUTORES",
+    "INFORMAR DE ERRORES",
+    "COPYRIGHT",
+    "VÉASE TAMBIÉN",
+    "CONTENIDO",
+];
+
+const FRENCH: [&str; 20] = [
+    "NOM",
+    "SYNOPSIS",
+    "DESCRIPTION",
+    "OPTIONS",
+    "CODE DE RETOUR",
+    "VALEUR RENVOYÉE",
+    "ERREURS",
+    "ENVIRONNEMENT",
+    "FICHIERS",
+    "VERSIONS",
+    "STANDARDS",
+    "HISTORIQUE",
+    "NOTES",
+    "BOGUES",
+    "EXEMPLES",
+    "AUTEURS",
+    "SIGNALER DES BOGUES",
+    "COPYRIGHT",
+    "VOIR AUSSI",
+    "SOMMAIRE",
+];
+
+const ITALIAN: [&str; 20] = [
+    "NOME",
+    "SINTASSI",
+    "DESCRIZIONE",
+    "OPZIONI",
+    "STATO DI USCITA",
+    "VALORE RESTITUITO",
+    "ERRORI",
+    "AMBIENTE",
+    "FILE",
+    "VERSIONI",
+    "STANDARD",
+    "STORIA",
+    "NOTE",
+    "BUG",
+    "ESEMPI",
+    "AUTORI",
+    "SEGNALAZIONE BUG",
+    "COPYRIGHT",
+    "VEDERE ANCHE",
+    "INDICE",
+];
+
+const BRAZILIAN_PORTUGUESE: [&str; 20] = [
+    "NOME",
+    "SINOPSE",
+    "DESCRIÇÃO",
+    "OPÇÕES",
+    "STATUS DE SAÍDA",
+    "VALOR DE RETORNO",
+    "ERROS",
+    "AMBIENTE",
+    "ARQUIVOS",
+    "VERSÕES",
+    "PADRÕES",
+    "HISTÓRICO",
+    "NOTAS",
+    "BUGS",
+    "EXEMPLOS",
+    "AUTORES",
+    "RELATANDO PROBLEMAS",
+    "COPYRIGHT",
+    "VEJA TAMBÉM",
+    "CONTEÚDO",
+];
+
+impl Locale {
+    /// The code of the language, as used in the directories manual
+    /// pages are installed in, such as `de` in
+    /// `/usr/share/man/de/man1`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+            Self::Spanish => "es",
+            Self::French => "fr",
+            Self::Italian => "it",
+            Self::BrazilianPortuguese => "pt_BR",
+        }
+    }
+
+    /// The translation of a standard heading, given in English, such
+    /// as `SEE ALSO`, if it is one.
+    pub fn heading(self, heading: &str) -> Option<&'static str> {
+        let i = HEADINGS.iter().position(|h| *h == heading)?;
+        let translations = match self {
+            Self::English => &HEADINGS,
+            Self::German => &GERMAN,
+            Self::Spanish => &SPANISH,
+            Self::French => &FRENCH,
+            Self::Italian => &ITALIAN,
+            Self::BrazilianPortuguese => &BRAZILIAN_PORTUGUESE,
+        };
+        Some(translations[i])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn translations_are_upper_case() {
+        for locale in [
+            Locale::German,
+            Locale::Spanish,
+            Locale::French,
+            Locale::Italian,
+            Locale::BrazilianPortuguese,
+        ] {
+            for heading in HEADINGS {
+                let translated = locale.heading(heading).unwrap();
+                assert_eq!(translated, translated.to_uppercase());
+            }
+        }
+    }
+}
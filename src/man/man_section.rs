@@ -0,0 +1,204 @@
+use std::fmt;
+
+/// A section of the manual.
+///
+/// A page can also be in a subsection of one of these, named with a
+/// suffix, such as `3p` for Perl modules or `1ssl` for the commands of
+/// OpenSSL. See [`ManPage::suffix`](super::ManPage::suffix).
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::ManSection;
+/// assert_eq!(ManSection::Executable.to_string(), "1");
+/// assert_eq!(ManSection::from_number(5), Some(ManSection::FileFormat));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SerializedSection", into = "u8")
+)]
+pub enum ManSection {
+    /// Executable programs and shell commands: 1.
+    Executable = 1,
+
+    /// System calls, provided by the kernel: 2.
+    SystemCall = 2,
+
+    /// Library calls, functions of program libraries: 3.
+    LibraryCall = 3,
+
+    /// Special files, usually found in `/dev`: 4.
+    SpecialFile = 4,
+
+    /// File formats and conventions, such as `/etc/passwd`: 5.
+    FileFormat = 5,
+
+    /// Games: 6.
+    Game = 6,
+
+    /// Miscellaneous, such as macro packages and conventions: 7.
+    Miscellaneous = 7,
+
+    /// System administration commands, usually only for root: 8.
+    SystemAdministration = 8,
+
+    /// Kernel routines, which are not standard: 9.
+    Kernel = 9,
+}
+
+impl ManSection {
+    /// The number of the section.
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+
+    /// The section with a number, if there is one.
+    pub fn from_number(number: u8) -> Option<Self> {
+        let section = match number {
+            1 => Self::Executable,
+            2 => Self::SystemCall,
+            3 => Self::LibraryCall,
+            4 => Self::SpecialFile,
+            5 => Self::FileFormat,
+            6 => Self::Game,
+            7 => Self::Miscellaneous,
+            8 => Self::SystemAdministration,
+            9 => Self::Kernel,
+            _ => return None,
+        };
+        Some(section)
+    }
+}
+
+impl fmt::Display for ManSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+impl TryFrom<u8> for ManSection {
+    type Error = InvalidManSection;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        Self::from_number(number).ok_or(InvalidManSection(Invalid::Number(number.to_string())))
+    }
+}
+
+impl std::str::FromStr for ManSection {
+    type Err = InvalidManSection;
+
+    /// Parse the number of a section, such as `1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .ok()
+            .and_then(Self::from_number)
+            .ok_or_else(|| InvalidManSection(Invalid::Number(s.to_owned())))
+    }
+}
+
+impl From<ManSection> for u8 {
+    fn from(section: ManSection) -> Self {
+        section.number()
+    }
+}
+
+/// A section as it is written in a serialized document: a number, or
+/// a string of one, such as `"1"`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum SerializedSection {
+    Number(u8),
+    Text(String),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerializedSection> for ManSection {
+    type Error = InvalidManSection;
+
+    fn try_from(section: SerializedSection) -> Result<Self, Self::Error> {
+        match section {
+            SerializedSection::Number(number) => number.try_into(),
+            SerializedSection::Text(text) => text.parse(),
+        }
+    }
+}
+
+/// A section of the manual, or subsection suffix, that isn't valid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidManSection(Invalid);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Invalid {
+    /// Not the number of a section.
+    Number(String),
+
+    /// A suffix that isn't a lowercase ASCII word, with the reason.
+    Suffix(String, &'static str),
+}
+
+impl InvalidManSection {
+    pub(crate) fn suffix(suffix: &str, reason: &'static str) -> Self {
+        Self(Invalid::Suffix(suffix.to_owned(), reason))
+    }
+}
+
+impl fmt::Display for InvalidManSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Invalid::Number(number) => write!(f, "{number:?} is not a section of the manual"),
+            Invalid::Suffix(suffix, reason) => {
+                write!(f, "invalid section suffix {suffix:?}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidManSection {}
+
+/// Check that a subsection suffix is a lowercase ASCII word, such as
+/// `p` or `ssl`, that starts with a letter, so that it doesn't run
+/// into the section number.
+pub(crate) fn check_suffix(suffix: &str) -> Result<(), &'static str> {
+    if suffix.is_empty() {
+        Err("suffix is empty")
+    } else if !suffix.starts_with(|c: char| c.is_ascii_lowercase()) {
+        Err("suffix must start with a lowercase ASCII letter")
+    } else if !suffix
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        Err("suffix may only contain lowercase ASCII letters and digits")
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numbers_round_trip() {
+        for number in 1..=9 {
+            let section = ManSection::from_number(number).unwrap();
+            assert_eq!(section.number(), number);
+        }
+        let zero = Err(InvalidManSection(Invalid::Number("0".to_owned())));
+        assert_eq!(ManSection::try_from(0), zero);
+        assert_eq!("0".parse::<ManSection>(), zero);
+        assert_eq!("8".parse(), Ok(ManSection::SystemAdministration));
+    }
+
+    #[test]
+    fn suffixes() {
+        assert!(check_suffix("ssl").is_ok());
+        assert!(check_suffix("").is_err());
+        assert!(check_suffix("P").is_err());
+        assert!(check_suffix("p x").is_err());
+        assert!(check_suffix("1").is_err());
+        assert!(check_suffix("x11").is_ok());
+    }
+}
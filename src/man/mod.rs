@@ -0,0 +1,448 @@
+//! Building manual pages.
+//!
+//! A manual page is a [`Roff`] document using the `man` macros, as
+//! described in [groff_man(7)]. [`ManPage`] takes care of the
+//! conventional structure of a page, while the content of each
+//! section is built as a [`Roff`].
+//!
+//! [groff_man(7)]: https://manpages.debian.org/bullseye/groff/groff_man.7.en.html
+
+use std::borrow::Cow;
+
+use crate::{roman, LineRef, RenderOptions, Roff};
+
+mod alias;
+#[cfg(feature = "time")]
+mod date;
+mod indent;
+mod install;
+mod list;
+mod locale;
+pub(crate) mod man_section;
+mod name_line;
+mod options;
+mod paragraph;
+mod section;
+mod sections;
+mod see_also;
+mod set;
+mod synopsis;
+mod translate;
+mod verbatim;
+
+#[cfg(feature = "time")]
+pub use date::th_date;
+#[cfg(feature = "time")]
+pub use date::th_date_from_timestamp;
+pub use indent::indented;
+pub use indent::indented_by;
+pub use list::bullet_list;
+pub use list::numbered_list;
+pub use list::TaggedList;
+pub use locale::Locale;
+pub use man_section::InvalidManSection;
+pub use man_section::ManSection;
+pub use name_line::NameLine;
+pub use name_line::NameLineError;
+pub use options::ManOption;
+pub use options::OptionsSection;
+pub use paragraph::Paragraph;
+pub use paragraph::ParagraphMacro;
+pub use section::Section;
+pub use sections::authors;
+pub use sections::copyright;
+pub use sections::environment;
+pub use sections::exit_status;
+pub use sections::exit_status_table;
+pub use sections::files;
+pub use sections::reporting_bugs;
+pub use sections::Author;
+pub use see_also::see_also;
+pub use see_also::see_also_mdoc;
+pub use see_also::ManRef;
+pub use set::ManSet;
+pub use synopsis::Synopsis;
+pub use verbatim::example;
+pub use verbatim::example_portable;
+pub use verbatim::verbatim;
+
+/// The conventional order of the sections of a manual page, as
+/// described in [man-pages(7)], for [`Roff::reorder_sections`].
+///
+/// [man-pages(7)]: https://man7.org/linux/man-pages/man7/man-pages.7.html
+pub const SECTION_ORDER: &[&str] = &[
+    "NAME",
+    "LIBRARY",
+    "SYNOPSIS",
+    "CONFIGURATION",
+    "DESCRIPTION",
+    "OPTIONS",
+    "EXIT STATUS",
+    "RETURN VALUE",
+    "ERRORS",
+    "ENVIRONMENT",
+    "FILES",
+    "ATTRIBUTES",
+    "VERSIONS",
+    "STANDARDS",
+    "HISTORY",
+    "NOTES",
+    "CAVEATS",
+    "BUGS",
+    "EXAMPLES",
+    "AUTHORS",
+    "REPORTING BUGS",
+    "COPYRIGHT",
+    "SEE ALSO",
+];
+
+/// A manual page.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::{ManPage, ManSection};
+/// let mut page = ManPage::new("FOO", ManSection::Executable);
+/// page.date("2021-12-23")
+///     .name("foo", "do a foo thing")
+///     .section("DESCRIPTION", |doc| {
+///         doc.text([bold("foo"), roman(" does a foo thing.")]);
+///     });
+/// assert_eq!(
+///     page.into_roff().to_roff(),
+///     ".TH FOO 1 2021-12-23\n\
+///      .SH NAME\n\
+///      foo \\- do a foo thing\n\
+///      .SH DESCRIPTION\n\
+///      \\fBfoo\\fR does a foo thing.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct ManPage<'a> {
+    title: Cow<'a, str>,
+    section: Cow<'a, str>,
+    name: Option<Cow<'a, str>>,
+    aliases: Vec<Cow<'a, str>>,
+    date: Option<Cow<'a, str>>,
+    source: Option<Cow<'a, str>>,
+    manual: Option<Cow<'a, str>>,
+    contents: bool,
+    locale: Locale,
+    body: Roff<'a>,
+}
+
+impl<'a> ManPage<'a> {
+    /// Start a manual page with a title and a section.
+    ///
+    /// The title is conventionally the name of the command (or
+    /// function, file, etc.) in upper case.
+    pub fn new(title: impl Into<Cow<'a, str>>, section: ManSection) -> Self {
+        Self {
+            title: title.into(),
+            section: Cow::Owned(section.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Put the page in a subsection of its section, such as `p` for
+    /// section `3p`.
+    ///
+    /// Returns an error if the suffix doesn't start with a lowercase
+    /// ASCII letter, which would run into the section number, or has
+    /// anything but lowercase ASCII letters and digits in it.
+    ///
+    /// ```
+    /// # use roff::man::{ManPage, ManSection};
+    /// let mut page = ManPage::new("FOO", ManSection::LibraryCall);
+    /// assert!(page.suffix("x y").is_err());
+    /// page.suffix("p")?.date("2021-12-23");
+    /// assert!(page.into_roff().to_roff().starts_with(".TH FOO 3p "));
+    /// # Ok::<(), roff::man::InvalidManSection>(())
+    /// ```
+    pub fn suffix(&mut self, suffix: &str) -> Result<&mut Self, InvalidManSection> {
+        man_section::check_suffix(suffix)
+            .map_err(|reason| InvalidManSection::suffix(suffix, reason))?;
+        let number = self.section.trim_end_matches(|c: char| !c.is_ascii_digit());
+        self.section = Cow::Owned(format!("{number}{suffix}"));
+        Ok(self)
+    }
+
+    /// Set the date the page was last changed.
+    pub fn date(&mut self, date: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    /// Set the source of the page, such as the name and version of
+    /// the package it belongs to.
+    pub fn source(&mut self, source: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Set the title of the manual the page belongs to.
+    ///
+    /// This is usually left for the ROFF implementation to choose
+    /// from the section.
+    pub fn manual(&mut self, manual: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.manual = Some(manual.into());
+        self
+    }
+
+    /// Append the NAME section.
+    ///
+    /// This is in the format that `whatis` and `apropos` expect: the
+    /// name, a dash, and a short description.
+    ///
+    /// The name is also used for the [file name](ManPage::file_name)
+    /// of the page.
+    pub fn name(&mut self, name: &str, description: &str) -> &mut Self {
+        self.name = Some(Cow::Owned(name.to_owned()));
+        self.body.control("SH", ["NAME"]);
+        self.body.text([roman(format!("{name} - {description}"))]);
+        self
+    }
+
+    /// Append a section.
+    ///
+    /// The heading is conventionally in upper case. The content of the
+    /// section is appended by the given function.
+    pub fn section(
+        &mut self,
+        heading: impl Into<Cow<'a, str>>,
+        content: impl FnOnce(&mut Roff<'a>),
+    ) -> &mut Self {
+        self.body.control("SH", [heading]);
+        content(&mut self.body);
+        self
+    }
+
+    /// Append the lines of a document.
+    pub fn append(&mut self, doc: Roff<'a>) -> &mut Self {
+        self.body.append(doc);
+        self
+    }
+
+    /// Add a CONTENTS section after the NAME section, listing the
+    /// other sections and their subsections.
+    ///
+    /// Few pages are long enough to need one, but for those that are,
+    /// such as the pages of shells, it saves searching.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// # use roff::man::{ManPage, ManSection};
+    /// let mut page = ManPage::new("FOO", ManSection::Executable);
+    /// page.contents(true)
+    ///     .name("foo", "do a foo thing")
+    ///     .section("OPTIONS", |doc| {
+    ///         doc.control("SS", ["Output"]);
+    ///     });
+    /// assert_eq!(
+    ///     page.into_roff().to_roff(),
+    ///     ".TH FOO 1\n\
+    ///      .SH NAME\n\
+    ///      foo \\- do a foo thing\n\
+    ///      .SH CONTENTS\n\
+    ///      OPTIONS\n\
+    ///      .br\n\
+    ///      .ti +4n\n\
+    ///      Output\n\
+    ///      .br\n\
+    ///      .SH OPTIONS\n\
+    ///      .SS Output\n"
+    /// );
+    /// ```
+    pub fn contents(&mut self, yes: bool) -> &mut Self {
+        self.contents = yes;
+        self
+    }
+
+    /// Set the language of the page, for the standard section
+    /// headings, such as NAME and SEE ALSO, to be translated into.
+    ///
+    /// The headings are given in English, as usual, and translated
+    /// when the page is converted into a document. Other headings are
+    /// left as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// # use roff::man::{Locale, ManPage, ManSection};
+    /// let mut page = ManPage::new("FOO", ManSection::Executable);
+    /// page.locale(Locale::French)
+    ///     .name("foo", "faire une chose")
+    ///     .section("SEE ALSO", |doc| {
+    ///         doc.text([roman("bar(1)")]);
+    ///     });
+    /// assert_eq!(
+    ///     page.into_roff().to_roff(),
+    ///     ".TH FOO 1\n\
+    ///      .SH NOM\n\
+    ///      foo \\- faire une chose\n\
+    ///      .SH \"VOIR AUSSI\"\n\
+    ///      bar(1)\n"
+    /// );
+    /// ```
+    pub fn locale(&mut self, locale: Locale) -> &mut Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let Self {
+            title,
+            section,
+            name: _,
+            aliases: _,
+            date,
+            source,
+            manual,
+            contents,
+            locale,
+            mut body,
+        } = self;
+        // Trailing arguments that aren't set are left out, but any
+        // before the last one set must be passed, even if empty.
+        let optional = [date, source, manual];
+        let set = optional
+            .iter()
+            .rposition(Option::is_some)
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let mut args = vec![title, section];
+        args.extend(
+            optional
+                .into_iter()
+                .take(set)
+                .map(Option::unwrap_or_default),
+        );
+
+        if contents {
+            body = with_contents(body);
+        }
+        if locale != Locale::English {
+            translate_headings(&mut body, locale);
+        }
+
+        let mut doc = Roff::new();
+        doc.control("TH", args);
+        doc.append(body);
+        doc
+    }
+
+    /// Render as ROFF source text.
+    pub fn render(&self) -> String {
+        self.render_with(&RenderOptions::default())
+    }
+
+    /// Render as ROFF source text, with custom [`RenderOptions`].
+    pub fn render_with(&self, options: &RenderOptions) -> String {
+        self.reborrow().into_roff().render_with(options)
+    }
+
+    /// Borrow all text, rather than copying it.
+    fn reborrow(&self) -> ManPage<'_> {
+        ManPage {
+            title: Cow::Borrowed(&self.title),
+            section: Cow::Borrowed(&self.section),
+            name: borrow(&self.name),
+            aliases: self
+                .aliases
+                .iter()
+                .map(|alias| Cow::Borrowed(&**alias))
+                .collect(),
+            date: borrow(&self.date),
+            source: borrow(&self.source),
+            manual: borrow(&self.manual),
+            contents: self.contents,
+            locale: self.locale,
+            body: self.body.reborrow(),
+        }
+    }
+}
+
+/// Insert the CONTENTS section before the first section after NAME.
+fn with_contents(mut body: Roff<'_>) -> Roff<'_> {
+    let headings: Vec<_> = body
+        .headings()
+        .into_iter()
+        .filter(|heading| heading.text() != "NAME")
+        .collect();
+    let is_section = |line: &crate::Line<'_>| LineRef::new(line).is_control("SH");
+    let first = body.lines.iter().position(is_section);
+    let after_name = match first {
+        Some(i) if LineRef::new(&body.lines[i]).args().join(" ") == "NAME" => body.lines[i + 1..]
+            .iter()
+            .position(is_section)
+            .map(|j| i + 1 + j),
+        first => first,
+    }
+    .unwrap_or(body.lines.len());
+    let rest = body.lines.split_off(after_name);
+    body.control("SH", ["CONTENTS"])
+        .append(crate::table_of_contents(&headings));
+    body.lines.extend(rest);
+    body
+}
+
+/// Translate the standard section headings.
+fn translate_headings(body: &mut Roff<'_>, locale: Locale) {
+    for line in &mut body.lines {
+        if let crate::Line::Control { name, args } = line {
+            if name == "SH" {
+                if let Some(heading) = locale.heading(&args.join(" ")) {
+                    *args = vec![Cow::Borrowed(heading)];
+                }
+            }
+        }
+    }
+}
+
+fn borrow<'r>(text: &'r Option<Cow<'_, str>>) -> Option<Cow<'r, str>> {
+    text.as_deref().map(Cow::Borrowed)
+}
+
+impl<'a> From<ManPage<'a>> for Roff<'a> {
+    fn from(page: ManPage<'a>) -> Self {
+        page.into_roff()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn th_skips_unset_arguments() {
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        assert_eq!(page.render_with(&plain()), ".TH FOO 1\n");
+        page.manual("Foo Manual");
+        assert_eq!(
+            page.render_with(&plain()),
+            ".TH FOO 1 \"\" \"\" \"Foo Manual\"\n"
+        );
+        page.date("2021");
+        assert_eq!(
+            page.render_with(&plain()),
+            ".TH FOO 1 2021 \"\" \"Foo Manual\"\n"
+        );
+    }
+
+    #[test]
+    fn render_matches_into_roff() {
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.source("foo 1.0").name("foo", "don't");
+        let rendered = page.render();
+        assert_eq!(rendered, page.into_roff().render());
+    }
+
+    fn plain() -> RenderOptions {
+        RenderOptions::new().apostrophes(crate::ApostropheMode::Plain)
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+
+use super::ManPage;
+use crate::{Inline, LineRef, Roff};
+
+/// The NAME section of a manual page, as `whatis` and `apropos` see
+/// it.
+///
+/// Tools such as `mandb` and `makewhatis` index manual pages by the
+/// NAME section, which must be a single paragraph of the form `name -
+/// description`, where several names may be given, separated by
+/// commas. A page whose NAME section isn't of that form can't be found
+/// with `apropos`, without any warning.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::NameLine;
+/// let mut doc = Roff::new();
+/// doc.control("SH", ["NAME"])
+///     .text([bold("vi"), roman(", "), bold("view"), roman(" - edit text")]);
+/// let name_line = NameLine::from_roff(&doc).unwrap();
+/// assert_eq!(name_line.names(), ["vi", "view"]);
+/// assert_eq!(name_line.description(), "edit text");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NameLine {
+    names: Vec<String>,
+    description: String,
+}
+
+impl NameLine {
+    /// Extract the NAME section from a document.
+    ///
+    /// Fonts and line breaks are ignored, as they are by `mandb`.
+    pub fn from_roff(doc: &Roff<'_>) -> Result<Self, NameLineError> {
+        let mut lines = doc
+            .lines
+            .iter()
+            .map(LineRef::new)
+            .skip_while(|line| !is_name_heading(line));
+        if lines.next().is_none() {
+            return Err(NameLineError::MissingSection);
+        }
+
+        let mut text = String::new();
+        for line in lines {
+            match line {
+                LineRef::Control { name: "SH", .. } => break,
+                LineRef::Control { name, .. } => {
+                    if !text.is_empty() && matches!(name, "PP" | "P" | "LP" | "br" | "sp") {
+                        return Err(NameLineError::NotOneParagraph);
+                    }
+                }
+                LineRef::Text(inlines) => {
+                    if !text.is_empty() {
+                        text.push(' ');
+                    }
+                    push_plain_text(&mut text, inlines);
+                }
+            }
+        }
+        Self::parse(&text)
+    }
+
+    /// Parse the text of a NAME section, such as `foo, bar - do a
+    /// thing`.
+    pub fn parse(text: &str) -> Result<Self, NameLineError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(NameLineError::Empty);
+        }
+        let (names, description) = text
+            .split_once(" - ")
+            .or_else(|| text.split_once(r" \- "))
+            .ok_or(NameLineError::MissingDash)?;
+        let names: Vec<String> = names
+            .split(',')
+            .map(|name| name.trim().to_owned())
+            .collect();
+        if names.iter().any(String::is_empty) {
+            return Err(NameLineError::MissingName);
+        }
+        // The text is trimmed, so there is always a description after
+        // the dash.
+        Ok(Self {
+            names,
+            description: description.trim().to_owned(),
+        })
+    }
+
+    /// The names the page documents.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The short description of what is documented.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl fmt::Display for NameLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} - {}", self.names.join(", "), self.description)
+    }
+}
+
+impl ManPage<'_> {
+    /// Extract the NAME section of the page.
+    ///
+    /// See [`NameLine::from_roff`].
+    pub fn name_line(&self) -> Result<NameLine, NameLineError> {
+        NameLine::from_roff(&self.body)
+    }
+}
+
+/// Why the NAME section of a manual page isn't usable by `whatis`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum NameLineError {
+    /// There is no NAME section.
+    MissingSection,
+
+    /// The NAME section is empty.
+    Empty,
+
+    /// The NAME section is broken into several paragraphs or lines.
+    NotOneParagraph,
+
+    /// The names aren't followed by a dash with a space on each side,
+    /// and a description.
+    MissingDash,
+
+    /// A name is empty.
+    MissingName,
+}
+
+impl fmt::Display for NameLineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::MissingSection => "no NAME section",
+            Self::Empty => "NAME section is empty",
+            Self::NotOneParagraph => "NAME section is not a single paragraph",
+            Self::MissingDash => "NAME section has no ` - ` between names and description",
+            Self::MissingName => "NAME section has an empty name",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for NameLineError {}
+
+fn is_name_heading(line: &LineRef<'_>) -> bool {
+    line.is_control("SH") && line.args().join(" ") == "NAME"
+}
+
+fn push_plain_text(text: &mut String, inlines: &[Inline<'_>]) {
+    for inline in inlines {
+        match inline {
+            Inline::Roman(s) | Inline::Italic(s) | Inline::Bold(s) => text.push_str(s),
+            Inline::LineBreak | Inline::Tab => text.push(' '),
+            Inline::Special(c) => text.push(c.as_char()),
+            Inline::HyphenationPoint | Inline::BreakPoint | Inline::ZeroWidth => {}
+            Inline::Colored { inlines, .. }
+            | Inline::Resized { inlines, .. }
+            | Inline::Superscript(inlines)
+            | Inline::Subscript(inlines) => {
+                push_plain_text(text, inlines);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::ManSection;
+    use crate::{roman, NO_ARGS};
+
+    #[test]
+    fn from_man_page() {
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.name("foo", "do a foo thing");
+        let name_line = page.name_line().unwrap();
+        assert_eq!(name_line.names(), ["foo"]);
+        assert_eq!(name_line.description(), "do a foo thing");
+        assert_eq!(name_line.to_string(), "foo - do a foo thing");
+    }
+
+    #[test]
+    fn joins_lines_until_next_section() {
+        let mut doc = Roff::new();
+        doc.control("SH", ["NAME"])
+            .text([roman("foo -")])
+            .text([roman("do a foo thing")])
+            .control("SH", ["SYNOPSIS"])
+            .text([roman("foo - not this")]);
+        let name_line = NameLine::from_roff(&doc).unwrap();
+        assert_eq!(name_line.description(), "do a foo thing");
+    }
+
+    #[test]
+    fn errors() {
+        let mut doc = Roff::new();
+        assert_eq!(
+            NameLine::from_roff(&doc),
+            Err(NameLineError::MissingSection)
+        );
+        doc.control("SH", ["NAME"]);
+        assert_eq!(NameLine::from_roff(&doc), Err(NameLineError::Empty));
+        doc.text([roman("foo")])
+            .control("PP", NO_ARGS)
+            .text([roman("- bar")]);
+        assert_eq!(
+            NameLine::from_roff(&doc),
+            Err(NameLineError::NotOneParagraph)
+        );
+        assert_eq!(NameLine::parse("foo-bar"), Err(NameLineError::MissingDash));
+        assert_eq!(
+            NameLine::parse("foo, - bar"),
+            Err(NameLineError::MissingName)
+        );
+        assert_eq!(NameLine::parse("foo -  "), Err(NameLineError::MissingDash));
+        assert_eq!(
+            NameLine::parse("foo -  \t"),
+            Err(NameLineError::MissingDash)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::borrow::Cow;
+
+use super::TaggedList;
+use crate::{bold, italic, roman, Inline, Roff};
+
+/// A command line option, for an [`OptionsSection`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::ManOption;
+/// let option = ManOption::new()
+///     .short("n")
+///     .long("bits")
+///     .value_name("BITS")
+///     .description([roman("Set the number of bits to modify.")])
+///     .default_value("1");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ManOption<'a> {
+    short: Option<Cow<'a, str>>,
+    long: Option<Cow<'a, str>>,
+    value_name: Option<Cow<'a, str>>,
+    description: Vec<Inline<'a>>,
+    default: Option<Cow<'a, str>>,
+}
+
+impl<'a> ManOption<'a> {
+    /// Start describing an option.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the short name of the option, without the dash.
+    pub fn short(mut self, short: impl Into<Cow<'a, str>>) -> Self {
+        self.short = Some(short.into());
+        self
+    }
+
+    /// Set the long name of the option, without the dashes.
+    pub fn long(mut self, long: impl Into<Cow<'a, str>>) -> Self {
+        self.long = Some(long.into());
+        self
+    }
+
+    /// Set the name of the value the option takes.
+    pub fn value_name(mut self, value_name: impl Into<Cow<'a, str>>) -> Self {
+        self.value_name = Some(value_name.into());
+        self
+    }
+
+    /// Set what the option does.
+    pub fn description(mut self, description: impl Into<Vec<Inline<'a>>>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Set the value used if the option isn't given.
+    pub fn default_value(mut self, default: impl Into<Cow<'a, str>>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// The tag of the option: its names in bold, and the name of its
+    /// value in italics.
+    fn tag(&self) -> Vec<Inline<'a>> {
+        let mut tag = vec![];
+        if let Some(short) = &self.short {
+            tag.push(bold(format!("-{short}")));
+        }
+        if let Some(long) = &self.long {
+            if !tag.is_empty() {
+                tag.push(roman(", "));
+            }
+            tag.push(bold(format!("--{long}")));
+        }
+        if let Some(value_name) = &self.value_name {
+            tag.push(roman(if self.long.is_some() { "=" } else { " " }));
+            tag.push(italic(value_name.clone()));
+        }
+        tag
+    }
+}
+
+/// The OPTIONS section of a manual page.
+///
+/// Each option is a tagged paragraph, with the names of the option
+/// in bold and the name of its value in italics, as is conventional.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::{ManOption, OptionsSection};
+/// let mut options = OptionsSection::new();
+/// options.option(
+///     ManOption::new()
+///         .short("n")
+///         .long("bits")
+///         .value_name("BITS")
+///         .description([roman("Set the number of bits to modify.")])
+///         .default_value("1"),
+/// );
+/// assert_eq!(
+///     options.into_roff().to_roff(),
+///     ".SH OPTIONS\n\
+///      .TP\n\
+///      \\fB\\-n\\fR, \\fB\\-\\-bits\\fR=\\fIBITS\\fR\n\
+///      Set the number of bits to modify. The default is \\fI1\\fR.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct OptionsSection<'a> {
+    heading: Cow<'a, str>,
+    options: Vec<ManOption<'a>>,
+}
+
+impl Default for OptionsSection<'_> {
+    fn default() -> Self {
+        Self {
+            heading: Cow::Borrowed("OPTIONS"),
+            options: vec![],
+        }
+    }
+}
+
+impl<'a> OptionsSection<'a> {
+    /// Start an empty OPTIONS section.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Use a heading other than OPTIONS, such as GLOBAL OPTIONS.
+    pub fn heading(&mut self, heading: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.heading = heading.into();
+        self
+    }
+
+    /// Append an option.
+    pub fn option(&mut self, option: ManOption<'a>) -> &mut Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let mut list = TaggedList::new();
+        for option in self.options {
+            let tag = option.tag();
+            let mut body = option.description;
+            if let Some(default) = option.default {
+                if !body.is_empty() {
+                    body.push(roman(" "));
+                }
+                body.push(roman("The default is "));
+                body.push(italic(default));
+                body.push(roman("."));
+            }
+            list.item(tag, (!body.is_empty()).then_some(body));
+        }
+        let mut doc = Roff::new();
+        doc.control("SH", [self.heading]);
+        doc.append(list.into_roff());
+        doc
+    }
+}
+
+impl<'a> From<OptionsSection<'a>> for Roff<'a> {
+    fn from(options: OptionsSection<'a>) -> Self {
+        options.into_roff()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tags() {
+        let tag = |option: ManOption<'static>| {
+            let mut doc = Roff::new();
+            doc.text(option.tag());
+            doc.to_roff()
+        };
+        assert_eq!(tag(ManOption::new().short("v")), "\\fB\\-v\\fR\n");
+        assert_eq!(
+            tag(ManOption::new().short("o").value_name("FILE")),
+            "\\fB\\-o\\fR \\fIFILE\\fR\n"
+        );
+        assert_eq!(
+            tag(ManOption::new().long("output").value_name("FILE")),
+            "\\fB\\-\\-output\\fR=\\fIFILE\\fR\n"
+        );
+    }
+
+    #[test]
+    fn heading() {
+        let mut options = OptionsSection::new();
+        options
+            .heading("GLOBAL OPTIONS")
+            .option(ManOption::new().long("help"));
+        assert_eq!(
+            options.into_roff().to_roff(),
+            ".SH \"GLOBAL OPTIONS\"\n.TP\n\\fB\\-\\-help\\fR\n"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{roman, Inline, Roff, NO_ARGS};
+
+/// A paragraph, built up from words and phrases that are separated by
+/// spaces.
+///
+/// Each word pushed is separated from the one before by a space,
+/// unless either side already has one, or the word is punctuation
+/// that follows the word before directly, such as a comma or a closing
+/// parenthesis. Use [`attach`](Paragraph::attach) to push a word with
+/// no space before it in any case.
+///
+/// As a document, the paragraph starts with the `PP` macro. It can also
+/// be given to [`Section::paragraph`](super::Section::paragraph),
+/// which only starts a new paragraph if there is something before it.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::Paragraph;
+/// let mut paragraph = Paragraph::new();
+/// paragraph
+///     .word(bold("corrupt"))
+///     .word("modifies")
+///     .word(italic("file"))
+///     .word(", see")
+///     .word(bold("dd"))
+///     .attach("(1).");
+/// assert_eq!(
+///     paragraph.into_roff().to_roff(),
+///     ".PP\n\\fBcorrupt\\fR modifies \\fIfile\\fR, see \\fBdd\\fR(1).\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Paragraph<'a> {
+    inlines: Vec<Inline<'a>>,
+}
+
+impl<'a> Paragraph<'a> {
+    /// Start an empty paragraph.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Append a word or phrase, with a space before it if one is
+    /// needed.
+    pub fn word(&mut self, word: impl Into<Inline<'a>>) -> &mut Self {
+        let word = word.into();
+        if let (Some(before), Some(after)) =
+            (self.inlines.last().and_then(last_char), first_char(&word))
+        {
+            if needs_space(before, after) {
+                self.inlines.push(roman(" "));
+            }
+        }
+        self.inlines.push(word);
+        self
+    }
+
+    /// Append a word or phrase, with no space before it.
+    pub fn attach(&mut self, word: impl Into<Inline<'a>>) -> &mut Self {
+        self.inlines.push(word.into());
+        self
+    }
+
+    /// The paragraph as inline elements, without the `PP` macro.
+    pub fn into_inlines(self) -> Vec<Inline<'a>> {
+        self.inlines
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let mut doc = Roff::new();
+        doc.paragraph().text(self.inlines);
+        doc
+    }
+}
+
+impl<'a> From<Paragraph<'a>> for Vec<Inline<'a>> {
+    fn from(paragraph: Paragraph<'a>) -> Self {
+        paragraph.into_inlines()
+    }
+}
+
+impl<'a> From<Paragraph<'a>> for Roff<'a> {
+    fn from(paragraph: Paragraph<'a>) -> Self {
+        paragraph.into_roff()
+    }
+}
+
+/// One of the macros that starts a paragraph in a manual page.
+///
+/// They all do the same, so [`Roff::paragraph`] uses `PP`, which is
+/// the most common. The others are for matching existing pages.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum ParagraphMacro {
+    /// The `PP` macro.
+    #[default]
+    Pp,
+
+    /// The `P` macro.
+    P,
+
+    /// The `LP` macro.
+    Lp,
+}
+
+impl ParagraphMacro {
+    /// The name of the macro.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Pp => "PP",
+            Self::P => "P",
+            Self::Lp => "LP",
+        }
+    }
+}
+
+impl<'a> Roff<'a> {
+    /// Start a new paragraph, in a manual page.
+    ///
+    /// This also ends a list: the indentation of tagged and indented
+    /// paragraphs is reset, so text after it starts at the left margin
+    /// again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.text([roman("one")]).paragraph().text([roman("two")]);
+    /// assert_eq!(doc.to_roff(), "one\n.PP\ntwo\n");
+    /// ```
+    pub fn paragraph(&mut self) -> &mut Self {
+        self.paragraph_with(ParagraphMacro::default())
+    }
+
+    /// Start a new paragraph with a particular macro.
+    pub fn paragraph_with(&mut self, kind: ParagraphMacro) -> &mut Self {
+        self.control(kind.name(), NO_ARGS)
+    }
+}
+
+fn first_char(inline: &Inline<'_>) -> Option<char> {
+    match inline {
+        Inline::Roman(s) | Inline::Italic(s) | Inline::Bold(s) => s.chars().next(),
+        Inline::Colored { inlines, .. }
+        | Inline::Resized { inlines, .. }
+        | Inline::Superscript(inlines)
+        | Inline::Subscript(inlines) => inlines.first().and_then(first_char),
+        Inline::LineBreak | Inline::HyphenationPoint | Inline::BreakPoint | Inline::ZeroWidth => {
+            None
+        }
+        Inline::Special(c) => Some(c.as_char()),
+        Inline::Tab => Some('\t'),
+    }
+}
+
+fn last_char(inline: &Inline<'_>) -> Option<char> {
+    match inline {
+        Inline::Roman(s) | Inline::Italic(s) | Inline::Bold(s) => s.chars().next_back(),
+        Inline::Colored { inlines, .. }
+        | Inline::Resized { inlines, .. }
+        | Inline::Superscript(inlines)
+        | Inline::Subscript(inlines) => inlines.last().and_then(last_char),
+        Inline::LineBreak | Inline::HyphenationPoint | Inline::BreakPoint | Inline::ZeroWidth => {
+            None
+        }
+        Inline::Special(c) => Some(c.as_char()),
+        Inline::Tab => Some('\t'),
+    }
+}
+
+/// Whether a space is needed between two words, given the characters
+/// either side of where it would go.
+fn needs_space(before: char, after: char) -> bool {
+    !(before.is_whitespace()
+        || after.is_whitespace()
+        || matches!(before, '(' | '[' | '{' | '“' | '‘')
+        || matches!(
+            after,
+            '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '”' | '’' | '…'
+        ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::Section;
+    use crate::{bold, italic, SpecialChar};
+
+    #[test]
+    fn spaces_between_words() {
+        let mut paragraph = Paragraph::new();
+        paragraph
+            .word("[")
+            .word(bold("-n"))
+            .word(SpecialChar::NoBreakSpace)
+            .word(italic("BITS"))
+            .word("]")
+            .word(" trailing ")
+            .word("end");
+        assert_eq!(
+            paragraph.into_inlines(),
+            [
+                roman("["),
+                bold("-n"),
+                SpecialChar::NoBreakSpace.into(),
+                italic("BITS"),
+                roman("]"),
+                roman(" trailing "),
+                roman("end"),
+            ]
+        );
+    }
+
+    #[test]
+    fn paragraph_macros() {
+        let mut doc = Roff::new();
+        doc.paragraph_with(ParagraphMacro::P)
+            .paragraph_with(ParagraphMacro::Lp)
+            .paragraph();
+        assert_eq!(doc.to_roff(), ".P\n.LP\n.PP\n");
+    }
+
+    #[test]
+    fn section_paragraph() {
+        let mut paragraph = Paragraph::new();
+        paragraph.word("one").word("two");
+        let mut section = Section::new("DESCRIPTION");
+        section.paragraph(paragraph);
+        assert_eq!(section.into_roff().to_roff(), ".SH DESCRIPTION\none two\n");
+    }
+}
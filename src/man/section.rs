@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use super::ManPage;
+use crate::edit::is_paragraph;
+use crate::{Inline, Roff};
+
+/// A section of a manual page, built up from paragraphs, other
+/// blocks, and subsections.
+///
+/// Paragraphs are separated with the `PP` macro, and subsections
+/// start with the `SS` macro. The `man` macros have only these two
+/// levels of heading, so a subsection of a subsection is just another
+/// subsection.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::Section;
+/// let mut section = Section::new("DESCRIPTION");
+/// section
+///     .paragraph([bold("foo"), roman(" does a foo thing.")])
+///     .paragraph([roman("It does it well.")])
+///     .subsection("Configuration", |sub| {
+///         sub.paragraph([roman("There is none.")]);
+///     });
+/// assert_eq!(
+///     section.into_roff().to_roff(),
+///     ".SH DESCRIPTION\n\
+///      \\fBfoo\\fR does a foo thing.\n\
+///      .PP\n\
+///      It does it well.\n\
+///      .SS Configuration\n\
+///      There is none.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    doc: Roff<'a>,
+    after_content: bool,
+}
+
+impl<'a> Section<'a> {
+    /// Start a section with a heading.
+    ///
+    /// The heading is conventionally in upper case.
+    pub fn new(heading: impl Into<Cow<'a, str>>) -> Self {
+        let mut doc = Roff::new();
+        doc.control("SH", [heading]);
+        Self {
+            doc,
+            after_content: false,
+        }
+    }
+
+    /// Append a paragraph.
+    pub fn paragraph(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        if self.after_content {
+            self.doc.paragraph();
+        }
+        self.doc.text(inlines);
+        self.after_content = true;
+        self
+    }
+
+    /// Append a block, such as a list.
+    pub fn append(&mut self, block: impl Into<Roff<'a>>) -> &mut Self {
+        let block = block.into();
+        // A block that ends with a paragraph macro, such as a list,
+        // already starts the next paragraph.
+        self.after_content = !block.lines.last().is_some_and(is_paragraph);
+        self.doc.append(block);
+        self
+    }
+
+    /// Append a subsection.
+    ///
+    /// The content of the subsection is appended by the given
+    /// function.
+    pub fn subsection(
+        &mut self,
+        heading: impl Into<Cow<'a, str>>,
+        content: impl FnOnce(&mut Self),
+    ) -> &mut Self {
+        self.doc.control("SS", [heading]);
+        self.after_content = false;
+        content(self);
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        self.doc
+    }
+}
+
+impl<'a> From<Section<'a>> for Roff<'a> {
+    fn from(section: Section<'a>) -> Self {
+        section.into_roff()
+    }
+}
+
+impl<'a> ManPage<'a> {
+    /// Append a subsection of the last section.
+    ///
+    /// The content of the subsection is appended by the given
+    /// function.
+    pub fn subsection(
+        &mut self,
+        heading: impl Into<Cow<'a, str>>,
+        content: impl FnOnce(&mut Roff<'a>),
+    ) -> &mut Self {
+        self.body.control("SS", [heading]);
+        content(&mut self.body);
+        self
+    }
+
+    /// Append a section built with [`Section`].
+    pub fn push_section(&mut self, section: Section<'a>) -> &mut Self {
+        self.body.append(section.into_roff());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::ManSection;
+    use crate::{roman, NO_ARGS};
+
+    #[test]
+    fn paragraphs_after_blocks_are_separated() {
+        let mut block = Roff::new();
+        block.control("RS", NO_ARGS).control("RE", NO_ARGS);
+        let mut section = Section::new("NOTES");
+        section.append(block).paragraph([roman("after")]);
+        assert_eq!(
+            section.into_roff().to_roff(),
+            ".SH NOTES\n.RS\n.RE\n.PP\nafter\n"
+        );
+    }
+
+    #[test]
+    fn page_subsections() {
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.section("DESCRIPTION", |_| {})
+            .subsection("Details", |doc| {
+                doc.text([roman("details")]);
+            });
+        let mut section = Section::new("NOTES");
+        section.paragraph([roman("notes")]);
+        page.push_section(section);
+        assert_eq!(
+            page.into_roff().to_roff(),
+            ".TH FOO 1\n.SH DESCRIPTION\n.SS Details\ndetails\n.SH NOTES\nnotes\n"
+        );
+    }
+}
@@ -0,0 +1,472 @@
+use std::borrow::Cow;
+
+use super::TaggedList;
+use crate::{bold, italic, roman, tab, Inline, Roff, SpecialChar, NO_ARGS};
+
+/// The EXIT STATUS section of a manual page.
+///
+/// Each exit status is a tagged paragraph, with the status in bold.
+/// See [`exit_status_table`] for a table instead.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::exit_status;
+/// let doc = exit_status([
+///     ("0", [roman("Success.")]),
+///     ("1", [roman("A file could not be read.")]),
+/// ]);
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".SH \"EXIT STATUS\"\n\
+///      .TP\n\
+///      \\fB0\\fR\n\
+///      Success.\n\
+///      .TP\n\
+///      \\fB1\\fR\n\
+///      A file could not be read.\n"
+/// );
+/// ```
+pub fn exit_status<'a, C, M>(statuses: impl IntoIterator<Item = (C, M)>) -> Roff<'a>
+where
+    C: Into<Cow<'a, str>>,
+    M: Into<Vec<Inline<'a>>>,
+{
+    tagged_section(
+        "EXIT STATUS",
+        statuses.into_iter().map(|(c, m)| (bold(c), m)),
+    )
+}
+
+/// The EXIT STATUS section of a manual page, as a table.
+///
+/// The table has the statuses in bold in the first column, and what
+/// they mean in the second, filled to the width of the page. Tables
+/// need the tbl(1) preprocessor, so the rendered document starts with
+/// the comment `'\" t`, which tells `man` to run it. mandoc and
+/// `groff -t` read tables as well.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::exit_status_table;
+/// let doc = exit_status_table([
+///     ("0", [roman("Success.")]),
+///     ("1", [roman("A file could not be read.")]),
+/// ]);
+/// assert_eq!(
+///     doc.to_roff(),
+///     "'\\\" t\n\
+///      .SH \"EXIT STATUS\"\n\
+///      .TS\n\
+///      lB lx.\n\
+///      0\tT{\n\
+///      Success.\n\
+///      T}\n\
+///      1\tT{\n\
+///      A file could not be read.\n\
+///      T}\n\
+///      .TE\n"
+/// );
+/// ```
+pub fn exit_status_table<'a, C, M>(statuses: impl IntoIterator<Item = (C, M)>) -> Roff<'a>
+where
+    C: Into<Cow<'a, str>>,
+    M: Into<Vec<Inline<'a>>>,
+{
+    let mut doc = Roff::new();
+    // Each meaning is a text block, `T{` to `T}`, so that it is
+    // filled like a paragraph.
+    doc.control("SH", ["EXIT STATUS"])
+        .control("TS", NO_ARGS)
+        .text([roman("lB lx.")]);
+    for (code, meaning) in statuses {
+        doc.text([roman(code), tab(), roman("T{")])
+            .text(meaning)
+            .text([roman("T}")]);
+    }
+    doc.control("TE", NO_ARGS);
+    doc
+}
+
+/// The ENVIRONMENT section of a manual page.
+///
+/// Each variable is a tagged paragraph, with the name of the variable
+/// in bold.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::environment;
+/// let doc = environment([("FOO_LOG", [roman("Where to write the log.")])]);
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".SH ENVIRONMENT\n.TP\n\\fBFOO_LOG\\fR\nWhere to write the log.\n"
+/// );
+/// ```
+pub fn environment<'a, V, D>(variables: impl IntoIterator<Item = (V, D)>) -> Roff<'a>
+where
+    V: Into<Cow<'a, str>>,
+    D: Into<Vec<Inline<'a>>>,
+{
+    tagged_section(
+        "ENVIRONMENT",
+        variables.into_iter().map(|(v, d)| (bold(v), d)),
+    )
+}
+
+/// The FILES section of a manual page.
+///
+/// Each file is a tagged paragraph, with the path in italics, as is
+/// conventional for file names.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::files;
+/// let doc = files([("/etc/foo.conf", [roman("The configuration.")])]);
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".SH FILES\n.TP\n\\fI/etc/foo.conf\\fR\nThe configuration.\n"
+/// );
+/// ```
+pub fn files<'a, P, D>(files: impl IntoIterator<Item = (P, D)>) -> Roff<'a>
+where
+    P: Into<Cow<'a, str>>,
+    D: Into<Vec<Inline<'a>>>,
+{
+    tagged_section("FILES", files.into_iter().map(|(p, d)| (italic(p), d)))
+}
+
+/// A section with a tagged paragraph for each item.
+fn tagged_section<'a, D: Into<Vec<Inline<'a>>>>(
+    heading: &'static str,
+    items: impl Iterator<Item = (Inline<'a>, D)>,
+) -> Roff<'a> {
+    let mut list = TaggedList::new();
+    for (tag, body) in items {
+        list.item([tag], [body]);
+    }
+    let mut doc = Roff::new();
+    doc.control("SH", [heading]).append(list.into_roff());
+    doc
+}
+
+/// An author of a program, for [`authors`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Author<'a> {
+    name: Cow<'a, str>,
+    email: Option<Cow<'a, str>>,
+}
+
+impl<'a> Author<'a> {
+    /// Name an author.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            email: None,
+        }
+    }
+
+    /// Set the email address of the author.
+    pub fn email(mut self, email: impl Into<Cow<'a, str>>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+}
+
+/// The AUTHORS section of a manual page.
+///
+/// The authors are listed in a sentence. Email addresses are marked
+/// up with the `MT` and `ME` macros, which show them as links where
+/// that is possible.
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::{authors, Author};
+/// let doc = authors([
+///     Author::new("Jane Doe").email("jane@example.com"),
+///     Author::new("John Roe"),
+/// ]);
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".SH AUTHORS\n\
+///      .MT jane@example.com\n\
+///      Jane Doe\n\
+///      .ME ,\n\
+///      John Roe.\n"
+/// );
+/// ```
+pub fn authors<'a>(authors: impl IntoIterator<Item = Author<'a>>) -> Roff<'a> {
+    let authors: Vec<_> = authors.into_iter().collect();
+    let mut doc = Roff::new();
+    doc.control("SH", ["AUTHORS"]);
+    let last = authors.len().saturating_sub(1);
+    for (i, author) in authors.into_iter().enumerate() {
+        let punctuation = if i == last { "." } else { "," };
+        match author.email {
+            Some(email) => {
+                doc.control("MT", [email])
+                    .text([roman(author.name)])
+                    .control("ME", [punctuation]);
+            }
+            None => {
+                doc.text([roman(author.name), roman(punctuation)]);
+            }
+        }
+    }
+    doc
+}
+
+/// The REPORTING BUGS section of a manual page.
+///
+/// The address is an email address or a URL, such as that of an
+/// issue tracker, and is marked up as a link with the `MT` and `ME`,
+/// or `UR` and `UE` macros.
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::reporting_bugs;
+/// let doc = reporting_bugs("https://github.com/rust-cli/roff-rs/issues");
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".SH \"REPORTING BUGS\"\n\
+///      Report bugs at\n\
+///      .UR https://github.com/rust-cli/roff-rs/issues\n\
+///      .UE .\n"
+/// );
+/// ```
+pub fn reporting_bugs<'a>(address: impl Into<Cow<'a, str>>) -> Roff<'a> {
+    let address = address.into();
+    let mut doc = Roff::new();
+    doc.control("SH", ["REPORTING BUGS"]);
+    if address.contains('@') && !address.contains("://") {
+        doc.text([roman("Report bugs to")])
+            .control("MT", [address])
+            .control("ME", ["."]);
+    } else {
+        doc.text([roman("Report bugs at")])
+            .control("UR", [address])
+            .control("UE", ["."]);
+    }
+    doc
+}
+
+/// The COPYRIGHT section of a manual page.
+///
+/// If the [SPDX] identifier of the license is given, such as `MIT` or
+/// `MIT OR Apache-2.0`, a sentence saying what the license is follows
+/// the copyright notice. Expressions with `AND`, `WITH`, and
+/// parentheses are spelled out too; one that can't be parsed is given
+/// as it is.
+///
+/// [SPDX]: https://spdx.org/licenses/
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::copyright;
+/// let doc = copyright("2021", "The Authors", Some("MIT OR Apache-2.0"));
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".SH COPYRIGHT\n\
+///      Copyright \\(co 2021 The Authors.\n\
+///      .br\n\
+///      Licensed under the MIT license or the Apache License, Version 2.0, at your option.\n"
+/// );
+/// ```
+pub fn copyright(years: &str, holder: &str, license: Option<&str>) -> Roff<'static> {
+    // A holder such as "Example, Inc." already ends the sentence.
+    let end = if holder.ends_with('.') { "" } else { "." };
+    let mut doc = Roff::new();
+    doc.control("SH", ["COPYRIGHT"]).text([
+        roman("Copyright "),
+        SpecialChar::Copyright.into(),
+        roman(format!(" {years} {holder}{end}")),
+    ]);
+    if let Some(license) = license {
+        doc.control("br", NO_ARGS)
+            .text([roman(license_sentence(license))]);
+    }
+    doc
+}
+
+/// A sentence saying what license an SPDX license expression is for.
+///
+/// An expression that can't be parsed is given as it is.
+fn license_sentence(spdx: &str) -> String {
+    let tokens = spdx_tokens(spdx);
+    let mut tokens = tokens.iter().copied().peekable();
+    let alternatives = match parse_or(&mut tokens) {
+        Some(alternatives) if tokens.next().is_none() => alternatives,
+        _ => return format!("Licensed under {}.", spdx.trim()),
+    };
+    match alternatives.as_slice() {
+        [license] => format!("Licensed under {license}."),
+        [init @ .., last] => {
+            format!(
+                "Licensed under {} or {last}, at your option.",
+                init.join(", ")
+            )
+        }
+        [] => unreachable!("an expression has at least one license"),
+    }
+}
+
+/// Split an SPDX license expression into identifiers, operators, and
+/// parentheses.
+fn spdx_tokens(spdx: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    for word in spdx.split_whitespace() {
+        let mut rest = word;
+        while !rest.is_empty() {
+            let end = match rest.find(['(', ')']) {
+                Some(0) => 1,
+                Some(i) => i,
+                None => rest.len(),
+            };
+            tokens.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+type Tokens<'t> = std::iter::Peekable<std::iter::Copied<std::slice::Iter<'t, &'t str>>>;
+
+/// Parse licenses separated by `OR`, lowest in precedence, into a
+/// phrase for each.
+fn parse_or(tokens: &mut Tokens<'_>) -> Option<Vec<String>> {
+    let mut alternatives = vec![parse_and(tokens)?];
+    while tokens.next_if_eq(&"OR").is_some() {
+        alternatives.push(parse_and(tokens)?);
+    }
+    Some(alternatives)
+}
+
+/// Parse licenses separated by `AND` into a phrase.
+fn parse_and(tokens: &mut Tokens<'_>) -> Option<String> {
+    let mut licenses = vec![parse_with(tokens)?];
+    while tokens.next_if_eq(&"AND").is_some() {
+        licenses.push(parse_with(tokens)?);
+    }
+    Some(match licenses.as_slice() {
+        [license] => license.clone(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+        [] => unreachable!("there is at least one license"),
+    })
+}
+
+/// Parse a license, with an exception if it is followed by `WITH`.
+fn parse_with(tokens: &mut Tokens<'_>) -> Option<String> {
+    let license = match tokens.next()? {
+        "(" => {
+            let alternatives = parse_or(tokens)?;
+            tokens.next_if_eq(&")")?;
+            match alternatives.as_slice() {
+                [license] => license.clone(),
+                [init @ .., last] => format!("either {} or {last}", init.join(", ")),
+                [] => unreachable!("there is at least one license"),
+            }
+        }
+        ")" | "AND" | "OR" | "WITH" => return None,
+        id => license_name(id),
+    };
+    if tokens.next_if_eq(&"WITH").is_some() {
+        let exception = tokens.next().filter(|id| !matches!(*id, "(" | ")"))?;
+        return Some(format!("{license} with the {exception} exception"));
+    }
+    Some(license)
+}
+
+/// The name of a license, from its SPDX identifier.
+fn license_name(id: &str) -> String {
+    let name = match id {
+        "MIT" => "the MIT license",
+        "Apache-2.0" => "the Apache License, Version 2.0",
+        "BSD-2-Clause" => "the 2-clause BSD license",
+        "BSD-3-Clause" => "the 3-clause BSD license",
+        "ISC" => "the ISC license",
+        "MPL-2.0" => "the Mozilla Public License, version 2.0",
+        "GPL-2.0-only" => "the GNU General Public License, version 2",
+        "GPL-2.0-or-later" => "the GNU General Public License, version 2 or later",
+        "GPL-3.0-only" => "the GNU General Public License, version 3",
+        "GPL-3.0-or-later" => "the GNU General Public License, version 3 or later",
+        "LGPL-2.1-or-later" => "the GNU Lesser General Public License, version 2.1 or later",
+        "LGPL-3.0-or-later" => "the GNU Lesser General Public License, version 3 or later",
+        "AGPL-3.0-or-later" => "the GNU Affero General Public License, version 3 or later",
+        "Unlicense" => "the Unlicense",
+        _ => return format!("the {id} license"),
+    };
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn copyright_holder_ending_in_period() {
+        assert_eq!(
+            copyright("2023", "Example, Inc.", None).to_roff(),
+            ".SH COPYRIGHT\nCopyright \\(co 2023 Example, Inc.\n"
+        );
+    }
+
+    #[test]
+    fn license_sentences() {
+        assert_eq!(license_sentence("MIT"), "Licensed under the MIT license.");
+        assert_eq!(
+            license_sentence("MIT OR Apache-2.0 OR Zlib"),
+            "Licensed under the MIT license, the Apache License, Version 2.0 or the Zlib license, at your option."
+        );
+    }
+
+    #[test]
+    fn license_sentences_with_operators() {
+        assert_eq!(
+            license_sentence("Apache-2.0 WITH LLVM-exception"),
+            "Licensed under the Apache License, Version 2.0 with the LLVM-exception exception."
+        );
+        assert_eq!(
+            license_sentence("(MIT OR Apache-2.0) AND Unicode-DFS-2016"),
+            "Licensed under either the MIT license or the Apache License, Version 2.0 and the Unicode-DFS-2016 license."
+        );
+        assert_eq!(
+            license_sentence("MIT AND ISC OR Unlicense"),
+            "Licensed under the MIT license and the ISC license or the Unlicense, at your option."
+        );
+        for spdx in [
+            "(MIT",
+            "MIT)",
+            "MIT OR",
+            "AND MIT",
+            "MIT WITH",
+            "MIT Apache-2.0",
+        ] {
+            assert_eq!(license_sentence(spdx), format!("Licensed under {spdx}."));
+        }
+    }
+
+    #[test]
+    fn table_hint_comes_first() {
+        let doc = exit_status_table([(">1", [roman("An error.")])]);
+        let rendered = doc.render_with(&crate::RenderOptions::new().coding_comment(true));
+        assert!(rendered.starts_with("'\\\" t\n.\\\" -*- coding: utf-8 -*-\n.ie "));
+        assert!(rendered.contains("\n>1\tT{\nAn error.\nT}\n"));
+    }
+
+    #[test]
+    fn reporting_bugs_by_email() {
+        assert_eq!(
+            reporting_bugs("bugs@example.com").to_roff(),
+            ".SH \"REPORTING BUGS\"\nReport bugs to\n.MT bugs@example.com\n.ME .\n"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+
+use super::ManPage;
+use crate::{bold, roman, Inline, Roff};
+
+/// A reference to a manual page, such as `ls(1)`.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::man::ManRef;
+/// let mut doc = Roff::new();
+/// doc.text(ManRef::new("ls", "1").to_inlines());
+/// assert_eq!(doc.to_roff(), "\\fBls\\fR(1)\n");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+pub struct ManRef<'a> {
+    name: Cow<'a, str>,
+    section: Cow<'a, str>,
+}
+
+impl<'a> ManRef<'a> {
+    /// Refer to a page by its name and section.
+    pub fn new(name: impl Into<Cow<'a, str>>, section: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            section: section.into(),
+        }
+    }
+
+    /// The name of the page.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The section of the page.
+    pub fn section(&self) -> &str {
+        &self.section
+    }
+
+    /// The reference as text: the name in bold, followed by the
+    /// section in parentheses.
+    pub fn to_inlines(&self) -> Vec<Inline<'a>> {
+        vec![
+            bold(self.name.clone()),
+            roman(format!("({})", self.section)),
+        ]
+    }
+}
+
+impl ManPage<'_> {
+    /// A reference to this page.
+    pub fn to_ref(&self) -> ManRef<'static> {
+        ManRef::new(self.page_name(), self.section.clone().into_owned())
+    }
+}
+
+/// The SEE ALSO section of a manual page.
+///
+/// The references are sorted by section, then by name, and separated
+/// by commas, as `mandoc -T lint` expects.
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::{see_also, ManRef};
+/// let doc = see_also([ManRef::new("passwd", "5"), ManRef::new("ls", "1")]);
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".SH \"SEE ALSO\"\n\\fBls\\fR(1), \\fBpasswd\\fR(5)\n"
+/// );
+/// ```
+pub fn see_also<'a>(refs: impl IntoIterator<Item = ManRef<'a>>) -> Roff<'a> {
+    let mut inlines = vec![];
+    for man_ref in sorted(refs) {
+        if !inlines.is_empty() {
+            inlines.push(roman(", "));
+        }
+        inlines.extend(man_ref.to_inlines());
+    }
+    let mut doc = Roff::new();
+    doc.control("SH", ["SEE ALSO"]).text(inlines);
+    doc
+}
+
+/// The SEE ALSO section of a manual page written with the [mdoc(7)]
+/// macros.
+///
+/// This is like [`see_also`], but uses the `Xr` macro for each
+/// reference.
+///
+/// [mdoc(7)]: https://manpages.debian.org/bullseye/mandoc/mdoc.7.en.html
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::{see_also_mdoc, ManRef};
+/// let doc = see_also_mdoc([ManRef::new("passwd", "5"), ManRef::new("ls", "1")]);
+/// assert_eq!(doc.to_roff(), ".Sh SEE ALSO\n.Xr ls 1 ,\n.Xr passwd 5\n");
+/// ```
+pub fn see_also_mdoc<'a>(refs: impl IntoIterator<Item = ManRef<'a>>) -> Roff<'a> {
+    let refs = sorted(refs);
+    let mut doc = Roff::new();
+    doc.control("Sh", ["SEE", "ALSO"]);
+    let last = refs.len().saturating_sub(1);
+    for (i, man_ref) in refs.into_iter().enumerate() {
+        let mut args = vec![man_ref.name, man_ref.section];
+        if i < last {
+            args.push(Cow::Borrowed(","));
+        }
+        doc.control("Xr", args);
+    }
+    doc
+}
+
+fn sorted<'a>(refs: impl IntoIterator<Item = ManRef<'a>>) -> Vec<ManRef<'a>> {
+    let mut refs: Vec<_> = refs.into_iter().collect();
+    refs.sort_by(|a, b| (&a.section, &a.name).cmp(&(&b.section, &b.name)));
+    refs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::ManSection;
+
+    #[test]
+    fn sorts_by_section_then_name() {
+        let refs = sorted([
+            ManRef::new("b", "5"),
+            ManRef::new("b", "1"),
+            ManRef::new("a", "1"),
+        ]);
+        assert_eq!(
+            refs,
+            [
+                ManRef::new("a", "1"),
+                ManRef::new("b", "1"),
+                ManRef::new("b", "5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn page_ref() {
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.name("foo", "do a foo thing");
+        assert_eq!(page.to_ref(), ManRef::new("foo", "1"));
+    }
+}
@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{borrow, see_also, Locale, ManPage};
+use crate::RenderOptions;
+
+/// A set of related manual pages, such as those for a command, its
+/// subcommands, and its file formats.
+///
+/// The date, source, and manual are set once for the whole set, and
+/// used for each page that doesn't set its own. Each page can also be
+/// given a SEE ALSO section referring to the others.
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::{ManPage, ManSection, ManSet};
+/// let mut set = ManSet::new();
+/// set.source("foo 1.0").cross_links(true);
+/// set.push(ManPage::new("FOO", ManSection::Executable));
+/// set.push(ManPage::new("FOO-BAR", ManSection::Executable));
+/// let rendered = set.render();
+/// assert_eq!(rendered[0].0, "foo.1");
+/// assert!(rendered[0].1.contains(".TH FOO 1 \"\" \"foo 1.0\"\n"));
+/// assert!(rendered[0].1.ends_with(".SH \"SEE ALSO\"\n\\fBfoo\\-bar\\fR(1)\n"));
+/// ```
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct ManSet<'a> {
+    date: Option<Cow<'a, str>>,
+    source: Option<Cow<'a, str>>,
+    manual: Option<Cow<'a, str>>,
+    cross_links: bool,
+    pages: Vec<ManPage<'a>>,
+}
+
+impl<'a> ManSet<'a> {
+    /// Start an empty set of pages.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the date for pages that don't set their own.
+    pub fn date(&mut self, date: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    /// Set the source for pages that don't set their own.
+    pub fn source(&mut self, source: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Set the manual for pages that don't set their own.
+    pub fn manual(&mut self, manual: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.manual = Some(manual.into());
+        self
+    }
+
+    /// Give each page a SEE ALSO section referring to the other pages
+    /// in the set.
+    ///
+    /// Pages that already have a SEE ALSO section are left as they
+    /// are.
+    pub fn cross_links(&mut self, yes: bool) -> &mut Self {
+        self.cross_links = yes;
+        self
+    }
+
+    /// Add a page to the set.
+    pub fn push(&mut self, page: ManPage<'a>) -> &mut Self {
+        self.pages.push(page);
+        self
+    }
+
+    /// The pages in the set, as they were added.
+    pub fn pages(&self) -> &[ManPage<'a>] {
+        &self.pages
+    }
+
+    /// A copy of the set in another language, with each page
+    /// [translated](ManPage::translated) by the translation function.
+    ///
+    /// This is for rendering or installing the pages once for each
+    /// locale, each in the directory for its locale.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use roff::man::{Locale, ManPage, ManSection, ManSet};
+    /// # fn lookup(locale: Locale, text: &str) -> Option<String> { None }
+    /// let mut set = ManSet::new();
+    /// set.push(ManPage::new("FOO", ManSection::Executable));
+    /// for locale in [Locale::German, Locale::French] {
+    ///     let paths = set
+    ///         .translated(locale, |text| lookup(locale, text))
+    ///         .install("/usr/local/share")
+    ///         .unwrap();
+    ///     assert_eq!(
+    ///         paths[0],
+    ///         std::path::Path::new(&format!("/usr/local/share/man/{}/man1/foo.1", locale.code()))
+    ///     );
+    /// }
+    /// ```
+    pub fn translated(
+        &self,
+        locale: Locale,
+        translate: impl Fn(&str) -> Option<String>,
+    ) -> ManSet<'_> {
+        ManSet {
+            date: borrow(&self.date),
+            source: borrow(&self.source),
+            manual: self.manual.as_deref().map(|manual| {
+                translate(manual)
+                    .map(Cow::Owned)
+                    .unwrap_or(Cow::Borrowed(manual))
+            }),
+            cross_links: self.cross_links,
+            pages: self
+                .pages
+                .iter()
+                .map(|page| page.translated(locale, &translate))
+                .collect(),
+        }
+    }
+
+    /// The pages in the set, with the set's metadata and cross links
+    /// applied.
+    pub fn finished_pages(&self) -> Vec<ManPage<'_>> {
+        let refs: Vec<_> = self.pages.iter().map(ManPage::to_ref).collect();
+        self.pages
+            .iter()
+            .enumerate()
+            .map(|(i, page)| {
+                let mut page = page.reborrow();
+                if page.date.is_none() {
+                    page.date = borrow(&self.date);
+                }
+                if page.source.is_none() {
+                    page.source = borrow(&self.source);
+                }
+                if page.manual.is_none() {
+                    page.manual = borrow(&self.manual);
+                }
+                if self.cross_links
+                    && refs.len() > 1
+                    && !page.body.section_names().iter().any(|s| s == "SEE ALSO")
+                {
+                    let others = refs
+                        .iter()
+                        .enumerate()
+                        .filter(|&(j, _)| j != i)
+                        .map(|(_, man_ref)| man_ref.clone());
+                    page.append(see_also(others));
+                }
+                page
+            })
+            .collect()
+    }
+
+    /// Render each page, as pairs of a [file name](ManPage::file_name)
+    /// and ROFF source text.
+    ///
+    /// The [stubs](ManPage::alias_stubs) for the aliases of each page
+    /// follow it.
+    pub fn render(&self) -> Vec<(String, String)> {
+        self.render_with(&RenderOptions::default())
+    }
+
+    /// Render each page, with custom [`RenderOptions`].
+    pub fn render_with(&self, options: &RenderOptions) -> Vec<(String, String)> {
+        let mut rendered = vec![];
+        for page in self.finished_pages() {
+            rendered.push((page.file_name(), page.render_with(options)));
+            rendered.extend(page.alias_stubs());
+        }
+        rendered
+    }
+
+    /// Install each page under `prefix`, as by [`ManPage::install`].
+    ///
+    /// The paths written to are returned.
+    pub fn install(&self, prefix: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        self.finished_pages()
+            .iter()
+            .map(|page| page.install(prefix.as_ref()))
+            .collect()
+    }
+
+    /// Install each page under `prefix`, compressed with gzip, as by
+    /// [`ManPage::install_gz`].
+    #[cfg(feature = "gzip")]
+    pub fn install_gz(&self, prefix: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        self.finished_pages()
+            .iter()
+            .map(|page| page.install_gz(prefix.as_ref()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::ManSection;
+    use crate::roman;
+
+    #[test]
+    fn page_metadata_wins() {
+        let mut set = ManSet::new();
+        set.date("2023").source("foo 1.0");
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.date("2021");
+        set.push(page);
+        let rendered = set.render();
+        assert!(rendered[0].1.contains(".TH FOO 1 2021 \"foo 1.0\"\n"));
+    }
+
+    #[test]
+    fn cross_links_skip_existing_see_also() {
+        let mut set = ManSet::new();
+        set.cross_links(true);
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.section("SEE ALSO", |doc| {
+            doc.text([roman("nothing")]);
+        });
+        set.push(page);
+        set.push(ManPage::new("FOO.TOML", ManSection::FileFormat));
+        set.push(ManPage::new("FOO-BAR", ManSection::Executable));
+        let rendered = set.render();
+        assert_eq!(rendered[0].1.matches("SEE ALSO").count(), 1);
+        assert!(rendered[1]
+            .1
+            .ends_with("\\fBfoo\\fR(1), \\fBfoo\\-bar\\fR(1)\n"));
+        assert_eq!(rendered[1].0, "foo.toml.5");
+    }
+}
@@ -0,0 +1,258 @@
+use std::borrow::Cow;
+
+use crate::{bold, italic, roman, Inline, Roff, SpecialChar, NO_ARGS};
+
+/// The synopsis of a command: its name, options, and arguments.
+///
+/// By default, this uses the `SY`, `OP`, and `YS` macros of GNU troff,
+/// which lay out a synopsis with a hanging indent and keep each option
+/// together with its value. They are understood by GNU troff and
+/// mandoc; for other implementations, see
+/// [`portable`](Synopsis::portable).
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::Synopsis;
+/// let mut synopsis = Synopsis::new("corrupt");
+/// synopsis
+///     .option("-n", Some("bits"))
+///     .flag("-v")
+///     .arg("file")
+///     .repeated();
+/// assert_eq!(
+///     synopsis.into_roff().to_roff(),
+///     ".SY corrupt\n\
+///      .OP \\-n bits\n\
+///      .OP \\-v\n\
+///      \\fIfile\\fR...\n\
+///      .YS\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Synopsis<'a> {
+    command: Cow<'a, str>,
+    items: Vec<Item<'a>>,
+    portable: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Item<'a> {
+    kind: Kind,
+    name: Cow<'a, str>,
+    value: Option<Cow<'a, str>>,
+    repeated: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Kind {
+    /// An option that may be left out.
+    Optional,
+    /// An option that must be given.
+    Required,
+    /// A positional argument that must be given.
+    Arg,
+    /// A positional argument that may be left out.
+    OptionalArg,
+}
+
+impl<'a> Synopsis<'a> {
+    /// Start the synopsis of a command.
+    pub fn new(command: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            command: command.into(),
+            items: vec![],
+            portable: false,
+        }
+    }
+
+    /// Add an option that may be left out, such as `-v`.
+    pub fn flag(&mut self, flag: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.push(Kind::Optional, flag.into(), None)
+    }
+
+    /// Add an option that may be left out, with the name of its value
+    /// if it takes one, such as `-n` and `bits`.
+    pub fn option(
+        &mut self,
+        flag: impl Into<Cow<'a, str>>,
+        value: Option<impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.push(Kind::Optional, flag.into(), value.map(Into::into))
+    }
+
+    /// Add an option that must be given, with the name of its value if
+    /// it takes one.
+    pub fn required_option(
+        &mut self,
+        flag: impl Into<Cow<'a, str>>,
+        value: Option<impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.push(Kind::Required, flag.into(), value.map(Into::into))
+    }
+
+    /// Add a positional argument that must be given.
+    pub fn arg(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.push(Kind::Arg, name.into(), None)
+    }
+
+    /// Add a positional argument that may be left out.
+    pub fn optional_arg(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.push(Kind::OptionalArg, name.into(), None)
+    }
+
+    /// Mark the last option or argument added as one that may be
+    /// given more than once.
+    pub fn repeated(&mut self) -> &mut Self {
+        if let Some(item) = self.items.last_mut() {
+            item.repeated = true;
+        }
+        self
+    }
+
+    /// Lay out the synopsis as plain text, rather than with the macros
+    /// of GNU troff.
+    ///
+    /// Each option is then kept together with its value by a space
+    /// that lines aren't broken at, but there is no hanging indent.
+    pub fn portable(&mut self, yes: bool) -> &mut Self {
+        self.portable = yes;
+        self
+    }
+
+    fn push(&mut self, kind: Kind, name: Cow<'a, str>, value: Option<Cow<'a, str>>) -> &mut Self {
+        self.items.push(Item {
+            kind,
+            name,
+            value,
+            repeated: false,
+        });
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        if self.portable {
+            self.into_portable()
+        } else {
+            self.into_macros()
+        }
+    }
+
+    fn into_macros(self) -> Roff<'a> {
+        // Arguments to macros aren't escaped, so dashes must be
+        // escaped here.
+        let mut doc = Roff::new();
+        doc.control("SY", [escape_dashes(&self.command)]);
+        for item in self.items {
+            match item.kind {
+                Kind::Optional if !item.repeated => {
+                    let mut args = vec![escape_dashes(&item.name)];
+                    args.extend(item.value.as_deref().map(escape_dashes));
+                    doc.control("OP", args);
+                }
+                _ => {
+                    doc.text(item.inlines());
+                }
+            }
+        }
+        doc.control("YS", NO_ARGS);
+        doc
+    }
+
+    fn into_portable(self) -> Roff<'a> {
+        let mut inlines = vec![bold(self.command)];
+        for item in self.items {
+            inlines.push(roman(" "));
+            inlines.extend(item.inlines());
+        }
+        let mut doc = Roff::new();
+        doc.text(inlines);
+        doc
+    }
+}
+
+impl<'a> Item<'a> {
+    /// The item as text: an option in bold, names of values and
+    /// arguments in italics, and brackets around what may be left out.
+    fn inlines(self) -> Vec<Inline<'a>> {
+        let mut inlines = vec![];
+        match self.kind {
+            Kind::Optional | Kind::Required => {
+                inlines.push(bold(self.name));
+                if let Some(value) = self.value {
+                    inlines.push(SpecialChar::NoBreakSpace.into());
+                    inlines.push(italic(value));
+                }
+            }
+            Kind::Arg | Kind::OptionalArg => inlines.push(italic(self.name)),
+        }
+        if matches!(self.kind, Kind::Optional | Kind::OptionalArg) {
+            inlines.insert(0, roman("["));
+            inlines.push(roman("]"));
+        }
+        if self.repeated {
+            inlines.push(roman("..."));
+        }
+        inlines
+    }
+}
+
+fn escape_dashes(text: &str) -> String {
+    text.replace('-', r"\-")
+}
+
+impl<'a> From<Synopsis<'a>> for Roff<'a> {
+    fn from(synopsis: Synopsis<'a>) -> Self {
+        synopsis.into_roff()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn required_and_optional() {
+        let mut synopsis = Synopsis::new("foo");
+        synopsis
+            .required_option("--config", Some("file"))
+            .option("-I", Some("dir"))
+            .repeated()
+            .optional_arg("name");
+        assert_eq!(
+            synopsis.into_roff().to_roff(),
+            ".SY foo\n\
+             \\fB\\-\\-config\\fR\\ \\fIfile\\fR\n\
+             [\\fB\\-I\\fR\\ \\fIdir\\fR]...\n\
+             [\\fIname\\fR]\n\
+             .YS\n"
+        );
+    }
+
+    #[test]
+    fn dashes_are_escaped() {
+        let mut synopsis = Synopsis::new("git-commit");
+        synopsis.option("--cleanup", Some("strip-space"));
+        assert_eq!(
+            synopsis.into_roff().to_roff(),
+            ".SY git\\-commit\n\
+             .OP \\-\\-cleanup strip\\-space\n\
+             .YS\n"
+        );
+    }
+
+    #[test]
+    fn portable() {
+        let mut synopsis = Synopsis::new("foo");
+        synopsis
+            .portable(true)
+            .option("-n", Some("bits"))
+            .arg("file")
+            .repeated();
+        assert_eq!(
+            synopsis.into_roff().to_roff(),
+            "\\fBfoo\\fR [\\fB\\-n\\fR\\ \\fIbits\\fR] \\fIfile\\fR...\n"
+        );
+    }
+}
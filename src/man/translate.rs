@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+
+use super::{Locale, ManPage};
+use crate::{Inline, Line};
+
+impl ManPage<'_> {
+    /// A copy of the page in another language, with its text passed
+    /// through a translation function, such as a lookup in a gettext
+    /// or Fluent catalog.
+    ///
+    /// The function is given each piece of text of the page, as it was
+    /// given to [`roman`](crate::roman), [`bold`](crate::bold), and so
+    /// on, the headings of sections and subsections, and the manual,
+    /// and returns its translation, or `None` to leave it as it is.
+    /// The standard section headings, such as NAME, are instead
+    /// translated as by [`ManPage::locale`], which is set to the
+    /// locale. The page is then [installed](ManPage::install) in the
+    /// directory for the locale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// # use roff::man::{Locale, ManPage, ManSection};
+    /// let mut page = ManPage::new("FOO", ManSection::Executable);
+    /// page.section("DESCRIPTION", |doc| {
+    ///     doc.text([bold("foo"), roman(" does a foo thing.")]);
+    /// });
+    /// let translated = page.translated(Locale::German, |text| match text {
+    ///     " does a foo thing." => Some(" tut ein Foo-Ding.".to_owned()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(
+    ///     translated.into_roff().to_roff(),
+    ///     ".TH FOO 1\n\
+    ///      .SH BESCHREIBUNG\n\
+    ///      \\fBfoo\\fR tut ein Foo\\-Ding.\n"
+    /// );
+    /// ```
+    pub fn translated(
+        &self,
+        locale: Locale,
+        translate: impl Fn(&str) -> Option<String>,
+    ) -> ManPage<'_> {
+        let mut page = self.reborrow();
+        page.locale = locale;
+        page.manual = page.manual.map(|manual| translate_text(manual, &translate));
+        for line in &mut page.body.lines {
+            match line {
+                Line::Control { name, args } if name == "SH" || name == "SS" => {
+                    let heading = args.join(" ");
+                    if locale.heading(&heading).is_none() {
+                        if let Some(translated) = translate(&heading) {
+                            *args = vec![Cow::Owned(translated)];
+                        }
+                    }
+                }
+                Line::Control { .. } => {}
+                Line::Text(inlines) => translate_inlines(inlines, &translate),
+            }
+        }
+        page
+    }
+}
+
+fn translate_inlines(inlines: &mut [Inline<'_>], translate: &impl Fn(&str) -> Option<String>) {
+    for inline in inlines {
+        match inline {
+            Inline::Roman(text) | Inline::Italic(text) | Inline::Bold(text) => {
+                *text = translate_text(std::mem::take(text), translate);
+            }
+            Inline::Colored { inlines, .. }
+            | Inline::Resized { inlines, .. }
+            | Inline::Superscript(inlines)
+            | Inline::Subscript(inlines) => translate_inlines(inlines, translate),
+            _ => {}
+        }
+    }
+}
+
+fn translate_text<'a>(
+    text: Cow<'a, str>,
+    translate: &impl Fn(&str) -> Option<String>,
+) -> Cow<'a, str> {
+    match translate(&text) {
+        Some(translated) => Cow::Owned(translated),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::ManSection;
+    use crate::roman;
+
+    #[test]
+    fn other_headings_and_manual() {
+        let mut page = ManPage::new("FOO", ManSection::Executable);
+        page.manual("User Commands")
+            .section("HACKING", |doc| {
+                doc.control("SS", ["Style"]).text([roman("Yes.")]);
+            })
+            .section("SEE ALSO", |_| {});
+        let translated = page.translated(Locale::Spanish, |text| {
+            let translated = match text {
+                "User Commands" => "Órdenes de usuario",
+                "HACKING" => "DESARROLLO",
+                "Style" => "Estilo",
+                "Yes." => "Sí.",
+                _ => return None,
+            };
+            Some(translated.to_owned())
+        });
+        assert_eq!(
+            translated.into_roff().to_roff(),
+            ".TH FOO 1 \"\" \"\" \"Órdenes de usuario\"\n\
+             .SH DESARROLLO\n\
+             .SS Estilo\n\
+             Sí.\n\
+             .SH \"VÉASE TAMBIÉN\"\n"
+        );
+    }
+}
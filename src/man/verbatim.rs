@@ -0,0 +1,95 @@
+use crate::{roman, Roff, NO_ARGS};
+
+/// Preformatted text, such as a diagram or a configuration file, set
+/// as it is written.
+///
+/// Filling is turned off with the `nf` request for the text, and back
+/// on after it, so each line of the text is set as a line of its own,
+/// spaces and all. Everything in it is escaped, so it can contain
+/// backslashes and lines starting with periods or apostrophes, which
+/// would otherwise be taken as control lines.
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::verbatim;
+/// let doc = verbatim("+---+\n| ' |\n+---+");
+/// assert_eq!(doc.to_roff(), ".nf\n+\\-\\-\\-+\n| ' |\n+\\-\\-\\-+\n.fi\n");
+/// ```
+pub fn verbatim(text: &str) -> Roff<'_> {
+    let mut doc = Roff::new();
+    doc.control("nf", NO_ARGS);
+    push_lines(&mut doc, text);
+    doc.control("fi", NO_ARGS);
+    doc
+}
+
+/// An example, such as a command line, set as it is written.
+///
+/// The text is set without filling, line for line, in a constant-width
+/// font, with the `EX` and `EE` macros. Everything in it is escaped, so
+/// it can contain backslashes and lines starting with periods.
+///
+/// `EX` and `EE` are understood by GNU troff and mandoc; for other
+/// implementations, see [`example_portable`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::man::example;
+/// let doc = example("$ corrupt -n 2 file\n.\\file");
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".EX\n\
+///      $ corrupt \\-n 2 file\n\
+///      \\&.\\\\file\n\
+///      .EE\n"
+/// );
+/// ```
+pub fn example(text: &str) -> Roff<'_> {
+    let mut doc = Roff::new();
+    doc.control("EX", NO_ARGS);
+    push_lines(&mut doc, text);
+    doc.control("EE", NO_ARGS);
+    doc
+}
+
+/// An example, such as a command line, set as it is written, without
+/// the macros of GNU troff.
+///
+/// This is like [`example`], but turns off filling and changes to a
+/// constant-width font with requests that every ROFF implementation
+/// understands.
+pub fn example_portable(text: &str) -> Roff<'_> {
+    let mut doc = Roff::new();
+    doc.control("nf", NO_ARGS).control("ft", ["CW"]);
+    push_lines(&mut doc, text);
+    // `ft` without an argument goes back to the previous font.
+    doc.control("ft", NO_ARGS).control("fi", NO_ARGS);
+    doc
+}
+
+/// Append a text line for each line of `text`.
+fn push_lines<'a>(doc: &mut Roff<'a>, text: &'a str) {
+    for line in text.lines() {
+        doc.text([roman(line)]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn protects_control_characters() {
+        assert_eq!(verbatim(".TH\n'br").to_roff(), ".nf\n\\&.TH\n\\&'br\n.fi\n");
+    }
+
+    #[test]
+    fn keeps_blank_lines() {
+        assert_eq!(
+            example_portable("a\n\n  b\n").to_roff(),
+            ".nf\n.ft CW\na\n\n  b\n.ft\n.fi\n"
+        );
+    }
+}
@@ -0,0 +1,397 @@
+//! Generating manual pages from a description of a command.
+//!
+//! A [`Manifest`] describes a command: its name, synopsis, options,
+//! examples, and so on. It can be read from TOML with
+//! [`Manifest::from_toml`], or from any other format supported by
+//! `serde`, such as YAML, and turned into a complete [`ManPage`].
+//!
+//! # Example
+//!
+//! ```
+//! # use roff::manifest::Manifest;
+//! let manifest = Manifest::from_toml(r#"
+//! name = "corrupt"
+//! description = "modify files by randomly changing bits"
+//! synopsis = ["[-n BITS] FILE..."]
+//! long-description = ["corrupt modifies files by toggling a randomly chosen bit."]
+//!
+//! [[options]]
+//! short = "n"
+//! long = "bits"
+//! value-name = "BITS"
+//! description = "Set the number of bits to modify."
+//! default = "1"
+//!
+//! [[examples]]
+//! description = "Corrupt a copy of a file:"
+//! command = "corrupt -n 2 copy.bin"
+//!
+//! [[see-also]]
+//! name = "dd"
+//! section = "1"
+//! "#).unwrap();
+//! let page = manifest.to_man_page().unwrap().render();
+//! assert!(page.contains(".TH CORRUPT 1\n"));
+//! ```
+
+use serde::Deserialize;
+
+use crate::man::{
+    self, indented_by, InvalidManSection, ManOption, ManPage, ManRef, ManSection, OptionsSection,
+};
+use crate::{bold, roman, Error};
+use crate::{man::man_section::check_suffix, NO_ARGS};
+
+/// A description of a command, from which to generate a manual page.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct Manifest {
+    /// Name of the command.
+    pub name: String,
+
+    /// Section of the manual, `1` if not set.
+    #[serde(default = "default_section")]
+    pub section: ManSection,
+
+    /// Suffix of the subsection of the manual, such as `p` for `3p`.
+    #[serde(default)]
+    pub section_suffix: Option<String>,
+
+    /// Short description of the command, for the NAME section.
+    pub description: String,
+
+    /// Date the page was last changed.
+    #[serde(default)]
+    pub date: Option<String>,
+
+    /// Source of the page, such as the name and version of the
+    /// package.
+    #[serde(default)]
+    pub source: Option<String>,
+
+    /// Title of the manual.
+    #[serde(default)]
+    pub manual: Option<String>,
+
+    /// Ways to invoke the command, each without the name of the
+    /// command.
+    #[serde(default)]
+    pub synopsis: Vec<String>,
+
+    /// Paragraphs for the DESCRIPTION section.
+    #[serde(default)]
+    pub long_description: Vec<String>,
+
+    /// Command line options.
+    #[serde(default)]
+    pub options: Vec<ManifestOption>,
+
+    /// Examples of using the command.
+    #[serde(default)]
+    pub examples: Vec<ManifestExample>,
+
+    /// Related manual pages.
+    #[serde(default)]
+    pub see_also: Vec<ManifestRef>,
+}
+
+/// A command line option in a [`Manifest`].
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ManifestOption {
+    /// Short name of the option, without the dash.
+    #[serde(default)]
+    pub short: Option<String>,
+
+    /// Long name of the option, without the dashes.
+    #[serde(default)]
+    pub long: Option<String>,
+
+    /// Name of the value the option takes, if it takes one.
+    #[serde(default)]
+    pub value_name: Option<String>,
+
+    /// What the option does.
+    pub description: String,
+
+    /// Default value of the option.
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+/// An example in a [`Manifest`].
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ManifestExample {
+    /// What the example does.
+    pub description: String,
+
+    /// The command line.
+    pub command: String,
+}
+
+/// A reference to another manual page in a [`Manifest`].
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ManifestRef {
+    /// Name of the page.
+    pub name: String,
+
+    /// Section of the page.
+    pub section: String,
+}
+
+fn default_section() -> ManSection {
+    ManSection::Executable
+}
+
+impl Manifest {
+    /// Read a manifest in TOML format.
+    pub fn from_toml(toml: &str) -> Result<Self, Error> {
+        let manifest: Self = toml::from_str(toml)?;
+        if let Some(suffix) = &manifest.section_suffix {
+            if let Err(reason) = check_suffix(suffix) {
+                let message = format!("invalid section suffix {suffix:?}: {reason}");
+                return Err(Error::Manifest(serde::de::Error::custom(message)));
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Generate the manual page.
+    ///
+    /// Returns an error if the section suffix is invalid, which
+    /// [`Manifest::from_toml`] checks for, but other formats don't.
+    pub fn to_man_page(&self) -> Result<ManPage<'_>, InvalidManSection> {
+        let mut page = ManPage::new(self.name.to_uppercase(), self.section);
+        if let Some(suffix) = &self.section_suffix {
+            page.suffix(suffix)?;
+        }
+        if let Some(date) = &self.date {
+            page.date(date.as_str());
+        }
+        if let Some(source) = &self.source {
+            page.source(source.as_str());
+        }
+        if let Some(manual) = &self.manual {
+            page.manual(manual.as_str());
+        }
+        page.name(&self.name, &self.description);
+
+        if !self.synopsis.is_empty() {
+            page.section("SYNOPSIS", |doc| {
+                for (i, usage) in self.synopsis.iter().enumerate() {
+                    if i > 0 {
+                        doc.control("br", NO_ARGS);
+                    }
+                    doc.text([bold(self.name.as_str()), roman(" "), roman(usage.as_str())]);
+                }
+            });
+        }
+
+        if !self.long_description.is_empty() {
+            page.section("DESCRIPTION", |doc| {
+                for (i, paragraph) in self.long_description.iter().enumerate() {
+                    if i > 0 {
+                        doc.paragraph();
+                    }
+                    doc.text([roman(paragraph.as_str())]);
+                }
+            });
+        }
+
+        if !self.options.is_empty() {
+            let mut options = OptionsSection::new();
+            for option in &self.options {
+                options.option(option.to_man_option());
+            }
+            page.append(options.into_roff());
+        }
+
+        if !self.examples.is_empty() {
+            page.section("EXAMPLES", |doc| {
+                for (i, example) in self.examples.iter().enumerate() {
+                    if i > 0 {
+                        doc.paragraph();
+                    }
+                    doc.text([roman(example.description.as_str())]);
+                    doc.paragraph();
+                    doc.append(indented_by("4", |doc| {
+                        doc.append(man::example(&example.command));
+                    }));
+                }
+            });
+        }
+
+        if !self.see_also.is_empty() {
+            let refs = self
+                .see_also
+                .iter()
+                .map(|see| ManRef::new(see.name.as_str(), see.section.as_str()));
+            page.append(man::see_also(refs));
+        }
+
+        Ok(page)
+    }
+}
+
+impl ManifestOption {
+    fn to_man_option(&self) -> ManOption<'_> {
+        let mut option = ManOption::new().description([roman(self.description.as_str())]);
+        if let Some(short) = &self.short {
+            option = option.short(short.as_str());
+        }
+        if let Some(long) = &self.long {
+            option = option.long(long.as_str());
+        }
+        if let Some(value_name) = &self.value_name {
+            option = option.value_name(value_name.as_str());
+        }
+        if let Some(default) = &self.default {
+            option = option.default_value(default.as_str());
+        }
+        option
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn minimal_manifest() {
+        let manifest = Manifest::from_toml(
+            r#"
+            name = "foo"
+            description = "do a foo thing"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.to_man_page().unwrap().into_roff().to_roff(),
+            ".TH FOO 1\n.SH NAME\nfoo \\- do a foo thing\n"
+        );
+    }
+
+    #[test]
+    fn full_manifest() {
+        let manifest = Manifest::from_toml(
+            r#"
+            name = "foo"
+            section = 8
+            section-suffix = "x"
+            description = "do a foo thing"
+            source = "foo 1.0"
+            synopsis = ["[-v] FILE", "--help"]
+            long-description = ["Foo does things.", "Many things."]
+
+            [[options]]
+            short = "v"
+            description = "Be verbose."
+
+            [[options]]
+            long = "level"
+            value-name = "N"
+            description = "Set the level."
+            default = "3"
+
+            [[examples]]
+            description = "Do a foo thing:"
+            command = "foo -v file"
+
+            [[see-also]]
+            name = "bar"
+            section = "1"
+
+            [[see-also]]
+            name = "baz"
+            section = "5"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.to_man_page().unwrap().into_roff().to_roff(),
+            r#".TH FOO 8x "" "foo 1.0"
+.SH NAME
+foo \- do a foo thing
+.SH SYNOPSIS
+\fBfoo\fR [\-v] FILE
+.br
+\fBfoo\fR \-\-help
+.SH DESCRIPTION
+Foo does things.
+.PP
+Many things.
+.SH OPTIONS
+.TP
+\fB\-v\fR
+Be verbose.
+.TP
+\fB\-\-level\fR=\fIN\fR
+Set the level. The default is \fI3\fR.
+.SH EXAMPLES
+Do a foo thing:
+.PP
+.RS 4
+.EX
+foo \-v file
+.EE
+.RE
+.SH "SEE ALSO"
+\fBbar\fR(1), \fBbaz\fR(5)
+"#
+        );
+    }
+
+    #[test]
+    fn reject_invalid_sections() {
+        let manifest = |section: &str| {
+            Manifest::from_toml(&format!(
+                "name = \"foo\"\ndescription = \"do a foo thing\"\n{section}"
+            ))
+        };
+        assert!(manifest("section = 10").is_err());
+        assert!(manifest("section = \"10\"").is_err());
+        assert!(manifest("section = \"x\"").is_err());
+        assert!(manifest("section-suffix = \"X\"").is_err());
+        assert!(manifest("section-suffix = \"1\"").is_err());
+        assert!(manifest("section = 3\nsection-suffix = \"p\"").is_ok());
+    }
+
+    #[test]
+    fn section_as_string() {
+        let manifest = Manifest::from_toml(
+            r#"
+            name = "foo"
+            section = "5"
+            description = "do a foo thing"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(manifest.section, ManSection::FileFormat);
+    }
+
+    #[test]
+    fn invalid_suffix_is_an_error() {
+        let mut manifest = Manifest::from_toml("name = \"foo\"\ndescription = \"foo\"").unwrap();
+        manifest.section_suffix = Some("x y".to_owned());
+        assert!(manifest.to_man_page().is_err());
+    }
+
+    #[test]
+    fn reject_unknown_fields() {
+        let err = Manifest::from_toml(
+            r#"
+            name = "foo"
+            description = "do a foo thing"
+            colour = "blue"
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::Manifest(_)));
+    }
+}
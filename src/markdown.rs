@@ -0,0 +1,365 @@
+use crate::edit::normalize_inlines;
+use crate::{Inline, Line, Roff};
+
+impl Roff<'_> {
+    /// Convert the document to Markdown.
+    ///
+    /// This is meant for publishing a manual page in other places,
+    /// such as a README or a web site, without keeping a second copy
+    /// of it. Only the common manual page macros are understood: `TH`
+    /// becomes the title, `SH` and `SS` become headings, `TP` and `IP`
+    /// become list items, `nf` and `EX` start a code block, and `PP`,
+    /// `P`, `LP`, and `br` break the text as they would in the manual
+    /// page. Other control lines are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let doc = Roff::new()
+    ///     .control("SH", ["OPTIONS"])
+    ///     .control("TP", NO_ARGS)
+    ///     .text([bold("-v")])
+    ///     .text([roman("Be "), italic("very"), roman(" verbose.")])
+    ///     .to_markdown();
+    /// assert_eq!(doc, "## OPTIONS\n\n- **-v**\n\n  Be *very* verbose.\n");
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = Markdown::default();
+        for line in &self.lines {
+            match line {
+                Line::Control { name, args } => markdown.control(name, args),
+                Line::Text(inlines) => markdown.text(inlines),
+            }
+        }
+        markdown.finish()
+    }
+}
+
+/// The state of a conversion to Markdown.
+#[derive(Default)]
+struct Markdown {
+    blocks: Vec<String>,
+    paragraph: Vec<String>,
+    code: Option<Vec<String>>,
+    in_item: bool,
+    tag_follows: bool,
+    // The marker of a list item whose body is the next paragraph.
+    marker: Option<String>,
+}
+
+impl Markdown {
+    fn control(&mut self, name: &str, args: &[std::borrow::Cow<'_, str>]) {
+        if let Some(code) = &mut self.code {
+            match name {
+                "fi" | "EE" => {
+                    let code = std::mem::take(code);
+                    self.code = None;
+                    self.block(format!("```\n{}\n```", code.join("\n")));
+                }
+                "br" => code.push(String::new()),
+                _ => {}
+            }
+            return;
+        }
+        match name {
+            "TH" => {
+                self.end_item();
+                let title = match args {
+                    [title, section, ..] => format!("# {title}({section})"),
+                    _ => format!("# {}", args.join(" ")),
+                };
+                self.block(title);
+            }
+            "SH" => {
+                self.end_item();
+                self.block(format!("## {}", args.join(" ")));
+            }
+            "SS" => {
+                self.end_item();
+                self.block(format!("### {}", args.join(" ")));
+            }
+            "PP" | "P" | "LP" => self.end_item(),
+            "TP" => {
+                self.end_item();
+                self.tag_follows = true;
+            }
+            "IP" => {
+                self.end_item();
+                match args.first().and_then(|tag| list_marker(tag)) {
+                    Some(marker) => self.marker = Some(marker),
+                    None => {
+                        let tag = args.first().map(|tag| escape(tag)).unwrap_or_default();
+                        self.block(format!("- {tag}").trim_end().to_owned());
+                        self.in_item = true;
+                    }
+                }
+            }
+            "nf" | "EX" => {
+                self.flush();
+                self.code = Some(vec![]);
+            }
+            "br" => {
+                if let Some(last) = self.paragraph.last_mut() {
+                    last.push('\\');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, inlines: &[Inline<'_>]) {
+        if let Some(code) = &mut self.code {
+            let mut line = String::new();
+            push_code(&mut line, inlines);
+            code.push(line);
+            return;
+        }
+
+        let text = render_inlines(inlines);
+        if self.tag_follows {
+            self.tag_follows = false;
+            self.block(format!("- {text}"));
+            self.in_item = true;
+        } else {
+            self.paragraph.push(text);
+        }
+    }
+
+    /// End the paragraph being written, if any.
+    fn flush(&mut self) {
+        if self.paragraph.is_empty() {
+            return;
+        }
+        let paragraph = std::mem::take(&mut self.paragraph).join("\n");
+        let paragraph = paragraph
+            .strip_suffix('\\')
+            .unwrap_or(&paragraph)
+            .to_owned();
+        match self.marker.take() {
+            // The paragraph is the body of a list item, starting on
+            // the line of its marker.
+            Some(marker) => {
+                let indent = " ".repeat(marker.len());
+                let item: Vec<_> = paragraph
+                    .lines()
+                    .enumerate()
+                    .map(|(i, line)| match i {
+                        0 => format!("{marker}{line}"),
+                        _ if line.is_empty() => String::new(),
+                        _ => format!("{indent}{line}"),
+                    })
+                    .collect();
+                self.blocks.push(item.join("\n"));
+                self.in_item = true;
+            }
+            None => self.block(paragraph),
+        }
+    }
+
+    /// End the paragraph being written, and the list item it is in.
+    fn end_item(&mut self) {
+        self.flush();
+        if let Some(marker) = self.marker.take() {
+            self.blocks.push(marker.trim_end().to_owned());
+        }
+        self.in_item = false;
+        self.tag_follows = false;
+    }
+
+    fn block(&mut self, block: String) {
+        self.flush();
+        if self.in_item {
+            let indented: Vec<_> = block
+                .lines()
+                .map(|line| {
+                    if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("  {line}")
+                    }
+                })
+                .collect();
+            self.blocks.push(indented.join("\n"));
+        } else {
+            self.blocks.push(block);
+        }
+    }
+
+    fn finish(mut self) -> String {
+        if let Some(code) = self.code.take() {
+            self.block(format!("```\n{}\n```", code.join("\n")));
+        }
+        self.end_item();
+        let mut markdown = self.blocks.join("\n\n");
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        markdown
+    }
+}
+
+/// The Markdown marker of a list item for the tag of an `IP` macro, if
+/// the tag is a bullet or a number, as made by
+/// [`bullet_list`](crate::man::bullet_list) and
+/// [`numbered_list`](crate::man::numbered_list).
+fn list_marker(tag: &str) -> Option<String> {
+    if matches!(tag, r"\(bu" | r"\[bu]" | "*" | "\u{2022}") {
+        return Some("- ".to_owned());
+    }
+    let number = tag.strip_suffix('.')?;
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        Some(format!("{number}. "))
+    } else {
+        None
+    }
+}
+
+/// Append inline elements to a line of a code block, as plain text.
+fn push_code(line: &mut String, inlines: &[Inline<'_>]) {
+    for inline in inlines {
+        match inline {
+            Inline::Roman(text) | Inline::Italic(text) | Inline::Bold(text) => {
+                line.push_str(text);
+            }
+            Inline::LineBreak => line.push('\n'),
+            Inline::Special(c) => line.push(c.as_char()),
+            Inline::HyphenationPoint | Inline::BreakPoint | Inline::ZeroWidth => {}
+            Inline::Tab => line.push('\t'),
+            Inline::Colored { inlines, .. }
+            | Inline::Resized { inlines, .. }
+            | Inline::Superscript(inlines)
+            | Inline::Subscript(inlines) => {
+                push_code(line, inlines);
+            }
+        }
+    }
+}
+
+fn render_inlines(inlines: &[Inline<'_>]) -> String {
+    let inlines: Vec<_> = inlines.iter().map(Inline::reborrow).collect();
+    let mut text = String::new();
+    for inline in normalize_inlines(inlines) {
+        match inline {
+            Inline::Roman(s) => text.push_str(&escape(&s)),
+            Inline::Italic(s) => {
+                text.push('*');
+                text.push_str(&escape(&s));
+                text.push('*');
+            }
+            Inline::Bold(s) => {
+                text.push_str("**");
+                text.push_str(&escape(&s));
+                text.push_str("**");
+            }
+            Inline::LineBreak => text.push_str("\\\n"),
+            Inline::Special(c) => text.push(c.as_char()),
+            Inline::HyphenationPoint | Inline::BreakPoint | Inline::ZeroWidth => {}
+            Inline::Tab => text.push('\t'),
+            Inline::Colored { inlines, .. } | Inline::Resized { inlines, .. } => {
+                text.push_str(&render_inlines(&inlines));
+            }
+            Inline::Superscript(inlines) => {
+                text.push_str("<sup>");
+                text.push_str(&render_inlines(&inlines));
+                text.push_str("</sup>");
+            }
+            Inline::Subscript(inlines) => {
+                text.push_str("<sub>");
+                text.push_str(&render_inlines(&inlines));
+                text.push_str("</sub>");
+            }
+        }
+    }
+    text
+}
+
+/// Escape the characters that have a meaning in Markdown text.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::man::{bullet_list, numbered_list};
+    use crate::{bold, italic, line_break, roman, NO_ARGS};
+
+    #[test]
+    fn man_page() {
+        let doc = Roff::new()
+            .control("TH", ["FOO", "1"])
+            .control("SH", ["NAME"])
+            .text([roman("foo - do a foo thing")])
+            .control("SH", ["DESCRIPTION"])
+            .text([bold("foo"), roman(" does a thing,")])
+            .text([roman("in one paragraph.")])
+            .control("PP", NO_ARGS)
+            .text([roman("Another one"), line_break(), roman("with a break.")])
+            .control("IP", ["*"])
+            .text([roman("an item")])
+            .control("SH", ["EXAMPLES"])
+            .control("nf", NO_ARGS)
+            .text([italic("foo"), roman(" *")])
+            .text([roman("foo -v")])
+            .control("fi", NO_ARGS)
+            .to_markdown();
+        assert_eq!(
+            doc,
+            r"# FOO(1)
+
+## NAME
+
+foo - do a foo thing
+
+## DESCRIPTION
+
+**foo** does a thing,
+in one paragraph.
+
+Another one\
+with a break.
+
+- an item
+
+## EXAMPLES
+
+```
+foo *
+foo -v
+```
+"
+        );
+    }
+
+    #[test]
+    fn lists() {
+        let mut doc = bullet_list([[roman("one")], [roman("two")]]);
+        doc.append(numbered_list([
+            vec![roman("first")],
+            vec![roman("second,"), line_break(), roman("broken")],
+        ]));
+        assert_eq!(
+            doc.to_markdown(),
+            "- one\n\n- two\n\n1. first\n\n2. second,\\\n   broken\n"
+        );
+    }
+
+    #[test]
+    fn escapes_markdown() {
+        let doc = Roff::new().text([roman("a_b [c] <d> #e")]).to_markdown();
+        assert_eq!(doc, "a\\_b \\[c\\] \\<d\\> \\#e\n");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Roff::new().to_markdown(), "");
+    }
+}
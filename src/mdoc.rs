@@ -0,0 +1,476 @@
+use std::borrow::Cow;
+
+use crate::{bold, italic, roman, Inline, Line, Roff, NO_ARGS};
+
+impl<'a> Roff<'a> {
+    /// Convert a manual page written with the [man(7)] macros to the
+    /// [mdoc(7)] macros.
+    ///
+    /// The structure of the page carries over: `TH` becomes `Dd`,
+    /// `Dt`, and `Os`, headings and paragraphs become their `mdoc`
+    /// counterparts, `TP` and `IP` become items of a tagged list, and
+    /// `nf` and `RS` become displays. Text keeps its fonts. The `man`
+    /// macros say little about what the text means, so the result
+    /// mostly uses none of the semantic macros of `mdoc`; those are
+    /// worth adding by hand. Only a `TP` tag set with a font macro, such
+    /// as `.B \-v`, becomes `Fl` for the flags in bold, `Ar` for the
+    /// words in italics, and `No` for the rest. Other control lines are
+    /// kept as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mdoc = Roff::new()
+    ///     .control("TH", ["FOO", "1", "2023-01-01"])
+    ///     .control("SH", ["NAME"])
+    ///     .text([roman("foo - do a foo thing")])
+    ///     .to_mdoc()
+    ///     .to_roff();
+    /// assert_eq!(
+    ///     mdoc,
+    ///     ".Dd 2023-01-01\n.Dt FOO 1\n.Os\n.Sh NAME\nfoo \\- do a foo thing\n"
+    /// );
+    /// ```
+    ///
+    /// [man(7)]: https://manpages.debian.org/bullseye/groff/groff_man.7.en.html
+    /// [mdoc(7)]: https://manpages.debian.org/bullseye/mandoc/mdoc.7.en.html
+    pub fn to_mdoc(&self) -> Roff<'_> {
+        let mut doc = Roff::new();
+        let mut blocks: Vec<&str> = vec![];
+        let mut in_list = false;
+        let mut tag_follows = false;
+        for line in &self.lines {
+            let (name, args) = match line {
+                Line::Text(inlines) => {
+                    if tag_follows {
+                        tag_follows = false;
+                        doc.control("It", tag_args(inlines));
+                    } else {
+                        doc.text(inlines.iter().map(Inline::reborrow).collect::<Vec<_>>());
+                    }
+                    continue;
+                }
+                Line::Control { name, args } => (&**name, args),
+            };
+            if tag_follows {
+                if let Some(tag) = font_tag_args(name, args) {
+                    tag_follows = false;
+                    doc.control("It", tag);
+                    continue;
+                }
+            }
+            let args = args.iter().map(|arg| Cow::Borrowed(&**arg));
+            if in_list && matches!(name, "SH" | "SS" | "PP" | "P" | "LP") {
+                doc.control("El", NO_ARGS);
+                in_list = false;
+            }
+            match name {
+                "TH" => {
+                    let args: Vec<_> = args.collect();
+                    doc.control("Dd", args.get(2).cloned());
+                    doc.control("Dt", args.iter().take(2).cloned());
+                    doc.control("Os", args.get(3).cloned());
+                }
+                "SH" => {
+                    doc.control("Sh", args);
+                }
+                "SS" => {
+                    doc.control("Ss", args);
+                }
+                "PP" | "P" | "LP" => {
+                    doc.control("Pp", NO_ARGS);
+                }
+                "TP" | "IP" => {
+                    if !in_list {
+                        doc.control("Bl", ["-tag", "-width", "Ds"]);
+                        in_list = true;
+                    }
+                    if name == "TP" {
+                        tag_follows = true;
+                    } else {
+                        doc.control("It", args.take(1));
+                    }
+                }
+                "nf" | "EX" => {
+                    doc.control("Bd", ["-literal"]);
+                    blocks.push(name);
+                }
+                "RS" => {
+                    doc.control("Bd", ["-ragged", "-offset", "indent"]);
+                    blocks.push(name);
+                }
+                "fi" | "EE" | "RE" => {
+                    if blocks.pop().is_some() {
+                        doc.control("Ed", NO_ARGS);
+                    }
+                }
+                _ => {
+                    doc.control(name, args);
+                }
+            }
+        }
+        if in_list {
+            doc.control("El", NO_ARGS);
+        }
+        for _ in blocks {
+            doc.control("Ed", NO_ARGS);
+        }
+        doc
+    }
+
+    /// Convert a manual page written with the [mdoc(7)] macros to the
+    /// [man(7)] macros.
+    ///
+    /// This is the reverse of [`Roff::to_mdoc`], and loses what
+    /// can't be said with the `man` macros: the semantic macros of
+    /// `mdoc` become text in the font they would be typeset in. Macros
+    /// that call other macros on the same line aren't understood, and
+    /// only the first macro of such a line is converted. Other control
+    /// lines are kept as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let man = Roff::new()
+    ///     .control("Dd", ["2023-01-01"])
+    ///     .control("Dt", ["FOO", "1"])
+    ///     .control("Os", NO_ARGS)
+    ///     .control("Sh", ["SYNOPSIS"])
+    ///     .control("Nm", ["foo"])
+    ///     .control("Ar", ["file"])
+    ///     .to_man()
+    ///     .to_roff();
+    /// assert_eq!(
+    ///     man,
+    ///     ".TH FOO 1 2023-01-01\n.SH SYNOPSIS\n\\fBfoo\\fR\n\\fIfile\\fR\n"
+    /// );
+    /// ```
+    ///
+    /// [man(7)]: https://manpages.debian.org/bullseye/groff/groff_man.7.en.html
+    /// [mdoc(7)]: https://manpages.debian.org/bullseye/mandoc/mdoc.7.en.html
+    pub fn to_man(&self) -> Roff<'_> {
+        let mut doc = Roff::new();
+        let mut date = None;
+        let mut title: Vec<Cow<'_, str>> = vec![];
+        let mut page_name: Option<&str> = None;
+        let mut blocks = vec![];
+        for line in &self.lines {
+            let (name, args) = match line {
+                Line::Text(inlines) => {
+                    doc.text(inlines.iter().map(Inline::reborrow).collect::<Vec<_>>());
+                    continue;
+                }
+                Line::Control { name, args } => (&**name, args),
+            };
+            let borrowed = || args.iter().map(|arg| Cow::Borrowed(&**arg));
+            match name {
+                "Dd" => date = Some(args.join(" ")),
+                "Dt" => title = borrowed().collect(),
+                "Os" => {
+                    let mut th = std::mem::take(&mut title);
+                    th.resize(2, Cow::Borrowed(""));
+                    th.push(date.take().unwrap_or_default().into());
+                    th.extend(borrowed());
+                    while th.len() > 2 && th.last().is_some_and(|arg| arg.is_empty()) {
+                        th.pop();
+                    }
+                    doc.control("TH", th);
+                }
+                "Sh" => {
+                    doc.control("SH", borrowed());
+                }
+                "Ss" => {
+                    doc.control("SS", borrowed());
+                }
+                "Pp" | "Lp" => {
+                    doc.control("PP", NO_ARGS);
+                }
+                "Bl" => blocks.push(name),
+                "It" => {
+                    doc.control("TP", NO_ARGS);
+                    doc.text(semantic_inlines(args.iter().map(|arg| &**arg)));
+                }
+                "El" => {
+                    blocks.pop();
+                }
+                "Bd" => {
+                    let literal = args
+                        .iter()
+                        .any(|arg| arg == "-literal" || arg == "-unfilled");
+                    let offset = args.iter().any(|arg| arg == "-offset");
+                    if offset {
+                        doc.control("RS", NO_ARGS);
+                    }
+                    if literal {
+                        doc.control("nf", NO_ARGS);
+                    }
+                    blocks.push(match (literal, offset) {
+                        (true, true) => "literal offset",
+                        (true, false) => "literal",
+                        (false, true) => "offset",
+                        (false, false) => "",
+                    });
+                }
+                "Ed" => {
+                    let block = blocks.pop().unwrap_or_default();
+                    if block.starts_with("literal") {
+                        doc.control("fi", NO_ARGS);
+                    }
+                    if block.ends_with("offset") {
+                        doc.control("RE", NO_ARGS);
+                    }
+                }
+                "Nm" => {
+                    if page_name.is_none() {
+                        page_name = args.first().map(|arg| &**arg);
+                    }
+                    let name = args.first().map(|arg| &**arg).or(page_name);
+                    doc.text(name.map(bold).into_iter().collect::<Vec<_>>());
+                }
+                "Nd" => {
+                    doc.text([roman(format!("- {}", args.join(" ")))]);
+                }
+                "Xr" => {
+                    let mut inlines = vec![];
+                    if let Some(page) = args.first() {
+                        inlines.push(bold(&**page));
+                    }
+                    if let Some(section) = args.get(1) {
+                        inlines.push(roman(format!("({section})")));
+                    }
+                    inlines.extend(args.iter().skip(2).map(|arg| roman(&**arg)));
+                    doc.text(inlines);
+                }
+                "Fl" => {
+                    doc.text([bold(format!("-{}", args.join(" ")))]);
+                }
+                _ if font(name).is_some() => {
+                    let words = std::iter::once(name).chain(args.iter().map(|arg| &**arg));
+                    doc.text(semantic_inlines(words));
+                }
+                _ => {
+                    doc.control(name, borrowed());
+                }
+            }
+        }
+        doc
+    }
+}
+
+/// The arguments of an `It` line for the tag of a tagged paragraph.
+fn tag_args<'r>(inlines: &'r [Inline<'_>]) -> Vec<Cow<'r, str>> {
+    let mut args = vec![];
+    for inline in inlines {
+        let (font, text) = match inline {
+            Inline::Roman(text) => ("No", text),
+            Inline::Italic(text) => ("Em", text),
+            Inline::Bold(text) => ("Sy", text),
+            Inline::LineBreak
+            | Inline::HyphenationPoint
+            | Inline::BreakPoint
+            | Inline::Tab
+            | Inline::ZeroWidth => continue,
+            Inline::Special(c) => {
+                args.push(Cow::Borrowed(c.escape()));
+                continue;
+            }
+            Inline::Colored { inlines, .. }
+            | Inline::Resized { inlines, .. }
+            | Inline::Superscript(inlines)
+            | Inline::Subscript(inlines) => {
+                args.extend(tag_args(inlines));
+                continue;
+            }
+        };
+        if !text.trim().is_empty() {
+            args.push(Cow::Borrowed(font));
+            args.push(Cow::Borrowed(text.trim()));
+        }
+    }
+    args
+}
+
+/// The arguments of an `It` line for the tag of a tagged paragraph
+/// that is set with a font macro, such as `.B \-v`, if it is one.
+fn font_tag_args<'r>(name: &str, args: &'r [Cow<'_, str>]) -> Option<Vec<Cow<'r, str>>> {
+    let fonts = match name {
+        "B" | "I" | "BR" | "RB" | "BI" | "IB" | "IR" | "RI" => name,
+        _ => return None,
+    };
+    let mut tag = vec![];
+    for (arg, font) in args.iter().zip(fonts.chars().cycle()) {
+        let arg = arg.trim();
+        if arg.is_empty() {
+            continue;
+        }
+        match font {
+            'B' => match arg.strip_prefix(r"\-").or_else(|| arg.strip_prefix('-')) {
+                Some(flag) => {
+                    tag.push(Cow::Borrowed("Fl"));
+                    if !flag.is_empty() {
+                        tag.push(Cow::Borrowed(flag));
+                    }
+                }
+                None => tag.extend([Cow::Borrowed("Sy"), Cow::Borrowed(arg)]),
+            },
+            'I' => tag.extend([Cow::Borrowed("Ar"), Cow::Borrowed(arg)]),
+            _ => tag.extend([Cow::Borrowed("No"), Cow::Borrowed(arg)]),
+        }
+    }
+    Some(tag)
+}
+
+/// Text for a line of `mdoc` macros and their arguments, such as the
+/// arguments of an `It` line.
+fn semantic_inlines<'r>(words: impl IntoIterator<Item = &'r str>) -> Vec<Inline<'r>> {
+    let mut inlines = vec![];
+    let mut current: fn(&'r str) -> Inline<'r> = roman;
+    for word in words {
+        if let Some(font) = font(word) {
+            current = font;
+            continue;
+        }
+        if !inlines.is_empty() {
+            inlines.push(roman(" "));
+        }
+        inlines.push(current(word));
+    }
+    inlines
+}
+
+/// The font an `mdoc` macro for text is typeset in, if it is one.
+fn font(name: &str) -> Option<fn(&str) -> Inline<'_>> {
+    match name {
+        "Sy" | "Cm" | "Ic" | "Nm" | "Fn" | "Fd" | "In" => Some(|text| bold(text)),
+        "Em" | "Ar" | "Pa" | "Va" | "Ev" | "Ft" | "Fa" | "Dv" => Some(|text| italic(text)),
+        "No" | "Li" | "Ql" => Some(|text| roman(text)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn man_to_mdoc() {
+        let man = Roff::new()
+            .control("TH", ["FOO", "1", "", "foo 1.0"])
+            .control("SH", ["OPTIONS"])
+            .control("TP", NO_ARGS)
+            .text([bold("-v"), roman(", "), bold("--verbose")])
+            .text([roman("Be verbose.")])
+            .control("TP", NO_ARGS)
+            .text([bold("-q")])
+            .text([roman("Be quiet.")])
+            .control("SH", ["EXAMPLES"])
+            .control("nf", NO_ARGS)
+            .text([roman("foo -v")])
+            .control("fi", NO_ARGS)
+            .to_mdoc()
+            .to_roff();
+        assert_eq!(
+            man,
+            r#".Dd ""
+.Dt FOO 1
+.Os "foo 1.0"
+.Sh OPTIONS
+.Bl -tag -width Ds
+.It Sy -v No , Sy --verbose
+Be verbose.
+.It Sy -q
+Be quiet.
+.El
+.Sh EXAMPLES
+.Bd -literal
+foo \-v
+.Ed
+"#
+        );
+    }
+
+    #[test]
+    fn font_macro_tags() {
+        let mdoc = Roff::new()
+            .control("TP", NO_ARGS)
+            .control("B", [r"\-v"])
+            .text([roman("Be verbose.")])
+            .control("TP", NO_ARGS)
+            .control("BI", [r"\-o ", "file"])
+            .text([roman("Write to a file.")])
+            .control("TP", NO_ARGS)
+            .control("BR", [r"\-\-quiet", ","])
+            .text([roman("Be quiet.")])
+            .to_mdoc()
+            .to_roff();
+        assert_eq!(
+            mdoc,
+            r".Bl -tag -width Ds
+.It Fl v
+Be verbose.
+.It Fl o Ar file
+Write to a file.
+.It Fl \-quiet No ,
+Be quiet.
+.El
+"
+        );
+    }
+
+    #[test]
+    fn mdoc_to_man() {
+        let man = Roff::new()
+            .control("Dd", ["January", "1,", "2023"])
+            .control("Dt", ["FOO", "1"])
+            .control("Os", NO_ARGS)
+            .control("Sh", ["NAME"])
+            .control("Nm", ["foo"])
+            .control("Nd", ["do", "a", "foo", "thing"])
+            .control("Sh", ["OPTIONS"])
+            .control("Bl", ["-tag", "-width", "Ds"])
+            .control("It", ["Sy", "-v"])
+            .text([roman("Be verbose.")])
+            .control("El", NO_ARGS)
+            .control("Sh", ["SEE", "ALSO"])
+            .control("Xr", ["bar", "1"])
+            .to_man()
+            .to_roff();
+        assert_eq!(
+            man,
+            r#".TH FOO 1 "January 1, 2023"
+.SH NAME
+\fBfoo\fR
+\- do a foo thing
+.SH OPTIONS
+.TP
+\fB\-v\fR
+Be verbose.
+.SH SEE ALSO
+\fBbar\fR(1)
+"#
+        );
+    }
+
+    #[test]
+    fn round_trip_keeps_structure() {
+        let man = Roff::new()
+            .control("TH", ["FOO", "1"])
+            .control("SH", ["DESCRIPTION"])
+            .text([roman("Does a thing.")])
+            .control("PP", NO_ARGS)
+            .text([roman("Does another.")])
+            .to_roff();
+        let doc = Roff::new()
+            .control("TH", ["FOO", "1"])
+            .control("SH", ["DESCRIPTION"])
+            .text([roman("Does a thing.")])
+            .control("PP", NO_ARGS)
+            .text([roman("Does another.")])
+            .to_mdoc()
+            .to_man()
+            .to_roff();
+        assert_eq!(doc, man);
+    }
+}
@@ -0,0 +1,197 @@
+//! Building documents with the me macros.
+//!
+//! The me macros are the macros of BSD for papers and other general
+//! documents, as described in [groff_me(7)]. [`MeDocument`] takes care
+//! of paragraphs and section headings, while quotes and displays are
+//! blocks in this module.
+//!
+//! [groff_me(7)]: https://manpages.debian.org/bullseye/groff/groff_me.7.en.html
+
+use std::borrow::Cow;
+
+use crate::{Inline, PageTitle, Roff, NO_ARGS};
+
+/// A document using the me macros.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::me::MeDocument;
+/// let mut doc = MeDocument::new();
+/// doc.heading(1, "Introduction")
+///     .paragraph([roman("Foo is a thing.")]);
+/// assert_eq!(
+///     doc.into_roff().to_roff(),
+///     ".sh 1 Introduction\n\
+///      .pp\n\
+///      Foo is a thing.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct MeDocument<'a> {
+    header: Option<PageTitle<'a>>,
+    footer: Option<PageTitle<'a>>,
+    body: Roff<'a>,
+}
+
+impl<'a> MeDocument<'a> {
+    /// Start an empty document.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the header at the top of each page, with the `he` macro.
+    pub fn header(&mut self, title: PageTitle<'a>) -> &mut Self {
+        self.header = Some(title);
+        self
+    }
+
+    /// Set the footer at the bottom of each page, with the `fo` macro.
+    pub fn footer(&mut self, title: PageTitle<'a>) -> &mut Self {
+        self.footer = Some(title);
+        self
+    }
+
+    /// Add a numbered section heading, with the `sh` macro.
+    pub fn heading(&mut self, level: u8, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.body
+            .control("sh", [Cow::Owned(level.to_string()), text.into()]);
+        self
+    }
+
+    /// Add a section heading that isn't numbered, with the `uh` macro.
+    pub fn unnumbered_heading(&mut self, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.body.control("uh", [text.into()]);
+        self
+    }
+
+    /// Add a paragraph, with the `pp` macro.
+    ///
+    /// Its first line is indented.
+    pub fn paragraph(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.body.control("pp", NO_ARGS).text(inlines);
+        self
+    }
+
+    /// Add a paragraph whose first line isn't indented, with the `lp`
+    /// macro.
+    pub fn flush_paragraph(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.body.control("lp", NO_ARGS).text(inlines);
+        self
+    }
+
+    /// Add an indented paragraph, with a tag in its margin, with the
+    /// `ip` macro.
+    pub fn indented_paragraph(
+        &mut self,
+        tag: impl Into<Cow<'a, str>>,
+        inlines: impl Into<Vec<Inline<'a>>>,
+    ) -> &mut Self {
+        self.body.control("ip", [tag.into()]).text(inlines);
+        self
+    }
+
+    /// Append a block, such as a [`quote`] or [`display`].
+    pub fn append(&mut self, doc: Roff<'a>) -> &mut Self {
+        self.body.append(doc);
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let mut doc = Roff::new();
+        if let Some(header) = self.header {
+            doc.control("he", [header.delimited()]);
+        }
+        if let Some(footer) = self.footer {
+            doc.control("fo", [footer.delimited()]);
+        }
+        doc.append(self.body);
+        doc
+    }
+}
+
+/// A quote, indented on both sides and in a smaller size, with the
+/// `(q` and `)q` macros.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::me::quote;
+/// let doc = quote(|doc| {
+///     doc.text([roman("To be or not to be.")]);
+/// });
+/// assert_eq!(doc.to_roff(), ".(q\nTo be or not to be.\n.)q\n");
+/// ```
+pub fn quote<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.control("(q", NO_ARGS);
+    content(&mut doc);
+    doc.control(")q", NO_ARGS);
+    doc
+}
+
+/// How the lines of a [`display`] are placed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Display {
+    /// At the left margin.
+    Left,
+
+    /// Indented.
+    #[default]
+    Indented,
+
+    /// Each line centered.
+    Centered,
+}
+
+impl Display {
+    /// The argument to the `(l` macro for the display.
+    pub fn arg(self) -> &'static str {
+        match self {
+            Self::Left => "L",
+            Self::Indented => "I",
+            Self::Centered => "C",
+        }
+    }
+}
+
+/// A display, whose lines aren't filled, with the `(l` and `)l`
+/// macros.
+pub fn display<'a>(kind: Display, content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.control("(l", [kind.arg()]);
+    content(&mut doc);
+    doc.control(")l", NO_ARGS);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::roman;
+
+    #[test]
+    fn paragraphs_and_displays() {
+        let mut doc = MeDocument::new();
+        doc.unnumbered_heading("Preface")
+            .flush_paragraph([roman("first")])
+            .indented_paragraph("(a)", [roman("item")])
+            .append(display(Display::Centered, |doc| {
+                doc.text([roman("centered")]);
+            }));
+        assert_eq!(
+            doc.into_roff().to_roff(),
+            ".uh Preface\n\
+             .lp\n\
+             first\n\
+             .ip (a)\n\
+             item\n\
+             .(l C\n\
+             centered\n\
+             .)l\n"
+        );
+    }
+}
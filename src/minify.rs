@@ -0,0 +1,87 @@
+use crate::{edit::normalize_inlines, Inline, Line};
+
+/// Remove what doesn't affect how a text line is typeset.
+///
+/// Empty inline elements are dropped, and consecutive ones in the
+/// same font are merged, so that no redundant font changes are
+/// rendered. Runs of line breaks are collapsed into one, and spaces
+/// at the ends of output lines are removed.
+pub(crate) fn minify_inlines<'a>(inlines: &[Inline<'a>]) -> Vec<Inline<'a>> {
+    let mut minified: Vec<Inline<'a>> = Vec::with_capacity(inlines.len());
+    for inline in inlines.iter().cloned() {
+        if inline == Inline::LineBreak {
+            trim_trailing_spaces(&mut minified);
+            if minified.last() == Some(&Inline::LineBreak) {
+                continue;
+            }
+        }
+        minified.push(inline);
+    }
+    trim_trailing_spaces(&mut minified);
+    normalize_inlines(minified)
+}
+
+fn trim_trailing_spaces(inlines: &mut Vec<Inline<'_>>) {
+    while let Some(Inline::Roman(text) | Inline::Italic(text) | Inline::Bold(text)) =
+        inlines.last_mut()
+    {
+        let len = text.trim_end_matches(' ').len();
+        if len == 0 {
+            inlines.pop();
+        } else {
+            if len < text.len() {
+                text.to_mut().truncate(len);
+            }
+            break;
+        }
+    }
+}
+
+impl Line<'_> {
+    /// Is this a `br` request?
+    pub(crate) fn is_break(&self) -> bool {
+        matches!(self, Line::Control { name, args } if name == "br" && args.is_empty())
+    }
+
+    /// Does this line end by breaking the output line?
+    pub(crate) fn ends_with_break(&self) -> bool {
+        match self {
+            Line::Control { .. } => self.is_break(),
+            Line::Text(inlines) => inlines.last() == Some(&Inline::LineBreak),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bold, italic, line_break, roman, RenderOptions, Roff, NO_ARGS};
+
+    #[test]
+    fn minify_text() {
+        let inlines = [
+            roman("foo  "),
+            bold(""),
+            line_break(),
+            line_break(),
+            roman("bar"),
+            roman(" "),
+        ];
+        assert_eq!(
+            minify_inlines(&inlines),
+            [roman("foo"), line_break(), roman("bar")]
+        );
+    }
+
+    #[test]
+    fn minified_render() {
+        let options = RenderOptions::new().minify(true);
+        let text = Roff::new()
+            .text([bold("a"), bold("b"), italic(""), line_break(), roman("c ")])
+            .control("br", NO_ARGS)
+            .control("br", NO_ARGS)
+            .text([roman("d")])
+            .render_with(&options);
+        assert!(text.ends_with("\n\\fBab\\fR\n.br\nc\n.br\nd\n"));
+    }
+}
@@ -0,0 +1,292 @@
+//! Building documents with the mm macros.
+//!
+//! The mm macros, or memorandum macros, are for memos, letters, and
+//! reports, as described in [groff_mm(7)]. [`MmDocument`] takes care of
+//! the memo header and numbered headings, while the content is built
+//! as a [`Roff`], with the lists in this module.
+//!
+//! [groff_mm(7)]: https://manpages.debian.org/bullseye/groff/groff_mm.7.en.html
+
+use std::borrow::Cow;
+
+use crate::{roman, Inline, PageTitle, Roff, NO_ARGS};
+
+/// A document using the mm macros.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::mm::MmDocument;
+/// let mut doc = MmDocument::new("Foo Plans");
+/// doc.author("Jane Doe")
+///     .memo_type("1")
+///     .heading(1, "Background")
+///     .paragraph([roman("Foo is a thing.")]);
+/// assert_eq!(
+///     doc.into_roff().to_roff(),
+///     ".TL\n\
+///      Foo Plans\n\
+///      .AU \"Jane Doe\"\n\
+///      .MT 1\n\
+///      .H 1 Background\n\
+///      .P\n\
+///      Foo is a thing.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct MmDocument<'a> {
+    title: Cow<'a, str>,
+    date: Option<Cow<'a, str>>,
+    authors: Vec<Cow<'a, str>>,
+    memo_type: Option<Cow<'a, str>>,
+    header: Option<PageTitle<'a>>,
+    footer: Option<PageTitle<'a>>,
+    contents: bool,
+    body: Roff<'a>,
+}
+
+impl<'a> MmDocument<'a> {
+    /// Start a document with a title.
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the date printed on the document, with the `ND` macro.
+    pub fn date(&mut self, date: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    /// Add an author, with the `AU` macro.
+    pub fn author(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.authors.push(name.into());
+        self
+    }
+
+    /// Set the type of memo, with the `MT` macro.
+    ///
+    /// This is a number from `0` to `6`, such as `1` for a memo for
+    /// file, or any other text to print as the type.
+    pub fn memo_type(&mut self, memo_type: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.memo_type = Some(memo_type.into());
+        self
+    }
+
+    /// Set the header at the top of each page, with the `PH` macro.
+    ///
+    /// By default, the header is the page number in the center.
+    pub fn header(&mut self, title: PageTitle<'a>) -> &mut Self {
+        self.header = Some(title);
+        self
+    }
+
+    /// Set the footer at the bottom of each page, with the `PF` macro.
+    pub fn footer(&mut self, title: PageTitle<'a>) -> &mut Self {
+        self.footer = Some(title);
+        self
+    }
+
+    /// Add a numbered heading, with the `H` macro.
+    ///
+    /// Headings of level 1 are numbered 1, 2, and so on, those of level
+    /// 2 under them 1.1, 1.2, and so on, up to level 7.
+    pub fn heading(&mut self, level: u8, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.body
+            .control("H", [Cow::Owned(level.to_string()), text.into()]);
+        self
+    }
+
+    /// Add a heading that isn't numbered, with the `HU` macro.
+    pub fn unnumbered_heading(&mut self, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.body.control("HU", [text.into()]);
+        self
+    }
+
+    /// Add a paragraph, with the `P` macro.
+    pub fn paragraph(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.body.control("P", NO_ARGS);
+        self.body.text(inlines);
+        self
+    }
+
+    /// Append a block, such as a [`list`].
+    pub fn append(&mut self, doc: Roff<'a>) -> &mut Self {
+        self.body.append(doc);
+        self
+    }
+
+    /// End the document with a table of contents, with the `TC`
+    /// macro.
+    ///
+    /// The mm macros collect the headings of levels 1 and 2 for it, or
+    /// more with the `Cl` register.
+    pub fn table_of_contents(&mut self, yes: bool) -> &mut Self {
+        self.contents = yes;
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let mut doc = Roff::new();
+        if let Some(date) = self.date {
+            doc.control("ND", [date]);
+        }
+        doc.control("TL", NO_ARGS).text([roman(self.title)]);
+        for name in self.authors {
+            doc.control("AU", [name]);
+        }
+        if let Some(memo_type) = self.memo_type {
+            doc.control("MT", [memo_type]);
+        }
+        if let Some(header) = self.header {
+            doc.control("PH", [header.delimited()]);
+        }
+        if let Some(footer) = self.footer {
+            doc.control("PF", [footer.delimited()]);
+        }
+        doc.append(self.body);
+        if self.contents {
+            doc.control("TC", NO_ARGS);
+        }
+        doc
+    }
+}
+
+/// The kind of a [`list`], and how its items are marked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ListKind {
+    /// Items numbered in a style, with the `AL` macro.
+    Numbered(Numbering),
+
+    /// Items marked with bullets, with the `BL` macro.
+    Bullet,
+
+    /// Items marked with dashes, with the `DL` macro.
+    Dash,
+}
+
+/// How the items of a numbered list are numbered.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Numbering {
+    /// 1, 2, 3.
+    #[default]
+    Arabic,
+
+    /// A, B, C.
+    UpperAlpha,
+
+    /// a, b, c.
+    LowerAlpha,
+
+    /// I, II, III.
+    UpperRoman,
+
+    /// i, ii, iii.
+    LowerRoman,
+}
+
+impl Numbering {
+    /// The argument to the `AL` macro for the numbering.
+    pub fn arg(self) -> &'static str {
+        match self {
+            Self::Arabic => "1",
+            Self::UpperAlpha => "A",
+            Self::LowerAlpha => "a",
+            Self::UpperRoman => "I",
+            Self::LowerRoman => "i",
+        }
+    }
+}
+
+/// A list, with an `LI` macro for each item, ended by the `LE`
+/// macro.
+///
+/// Lists can be nested, by appending a list to an item.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::mm::{list, ListKind, Numbering};
+/// let doc = list(
+///     ListKind::Numbered(Numbering::LowerRoman),
+///     [[roman("one")], [roman("two")]],
+/// );
+/// assert_eq!(doc.to_roff(), ".AL i\n.LI\none\n.LI\ntwo\n.LE\n");
+/// ```
+pub fn list<'a, I>(kind: ListKind, items: impl IntoIterator<Item = I>) -> Roff<'a>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    let mut doc = Roff::new();
+    match kind {
+        ListKind::Numbered(numbering) => doc.control("AL", [numbering.arg()]),
+        ListKind::Bullet => doc.control("BL", NO_ARGS),
+        ListKind::Dash => doc.control("DL", NO_ARGS),
+    };
+    for item in items {
+        doc.control("LI", NO_ARGS).text(item);
+    }
+    doc.control("LE", NO_ARGS);
+    doc
+}
+
+/// A list with a tag for each item, indented by a width, such as `8n`,
+/// with the `VL` macro.
+///
+/// See [`list`].
+pub fn tagged_list<'a, T, I>(
+    width: impl Into<Cow<'a, str>>,
+    items: impl IntoIterator<Item = (T, I)>,
+) -> Roff<'a>
+where
+    T: Into<Cow<'a, str>>,
+    I: Into<Vec<Inline<'a>>>,
+{
+    let mut doc = Roff::new();
+    doc.control("VL", [width.into()]);
+    for (tag, item) in items {
+        doc.control("LI", [tag.into()]).text(item);
+    }
+    doc.control("LE", NO_ARGS);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn memo_with_lists() {
+        let mut doc = MmDocument::new("Plan");
+        doc.date("2024-01-01")
+            .footer(PageTitle::new().center("- % -"))
+            .unnumbered_heading("Steps")
+            .append(
+                tagged_list("6n", [("1.", [roman("first")]), ("2.", [roman("second")])])
+                    + list(ListKind::Bullet, [[roman("detail")]]),
+            );
+        assert_eq!(
+            doc.into_roff().to_roff(),
+            ".ND 2024-01-01\n\
+             .TL\n\
+             Plan\n\
+             .PF \"''\\- % \\-''\"\n\
+             .HU Steps\n\
+             .VL 6n\n\
+             .LI 1.\n\
+             first\n\
+             .LI 2.\n\
+             second\n\
+             .LE\n\
+             .BL\n\
+             .LI\n\
+             detail\n\
+             .LE\n"
+        );
+    }
+}
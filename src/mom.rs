@@ -0,0 +1,313 @@
+//! Building typeset documents with the mom macros.
+//!
+//! The mom macros are for typesetting documents, from letters to
+//! books, with GNU troff, as described in [groff_mom(7)].
+//! [`MomDocument`] takes care of the title, the cover, the style, and
+//! chapters, while the content is built as a [`Roff`].
+//!
+//! [groff_mom(7)]: https://manpages.debian.org/bullseye/groff/groff_mom.7.en.html
+
+use std::borrow::Cow;
+
+use crate::{Inline, Roff, NO_ARGS};
+
+/// The kind of a document, which decides how its title is shown.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum DocType<'a> {
+    /// A document with a title, subtitle, and author.
+    #[default]
+    Default,
+
+    /// A chapter of a book.
+    Chapter,
+
+    /// A letter.
+    Letter,
+
+    /// A document with a name to show instead of "Chapter", such as
+    /// "Appendix".
+    Named(Cow<'a, str>),
+}
+
+/// How a document is printed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum PrintStyle {
+    /// Typeset, with the fonts and sizes of the document.
+    #[default]
+    Typeset,
+
+    /// As from a typewriter, in a single font and size, double spaced.
+    Typewrite,
+}
+
+/// A typeset document using the mom macros.
+///
+/// Headings, paragraphs, and blocks go in the current chapter, or
+/// before any chapter if [`chapter`](MomDocument::chapter) hasn't
+/// been called.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::mom::MomDocument;
+/// let mut doc = MomDocument::new("Foo");
+/// doc.author("Jane Doe")
+///     .family("P")
+///     .chapter("1", "Beginnings")
+///     .paragraph([roman("Foo is a thing.")]);
+/// assert_eq!(
+///     doc.into_roff().to_roff(),
+///     ".TITLE Foo\n\
+///      .AUTHOR \"Jane Doe\"\n\
+///      .DOCTYPE CHAPTER\n\
+///      .PRINTSTYLE TYPESET\n\
+///      .FAMILY P\n\
+///      .CHAPTER 1\n\
+///      .CHAPTER_TITLE Beginnings\n\
+///      .START\n\
+///      .PP\n\
+///      Foo is a thing.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct MomDocument<'a> {
+    title: Cow<'a, str>,
+    subtitle: Option<Cow<'a, str>>,
+    authors: Vec<Cow<'a, str>>,
+    doc_type: DocType<'a>,
+    print_style: PrintStyle,
+    cover: bool,
+    contents: bool,
+    style: Roff<'a>,
+    front: Roff<'a>,
+    chapters: Vec<(Cow<'a, str>, Cow<'a, str>, Roff<'a>)>,
+}
+
+impl<'a> MomDocument<'a> {
+    /// Start a document with a title.
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the subtitle.
+    pub fn subtitle(&mut self, subtitle: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// Add an author.
+    pub fn author(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.authors.push(name.into());
+        self
+    }
+
+    /// Set the kind of document.
+    ///
+    /// By default, this is [`DocType::Chapter`] if the document has
+    /// chapters, and [`DocType::Default`] otherwise.
+    pub fn doc_type(&mut self, doc_type: DocType<'a>) -> &mut Self {
+        self.doc_type = doc_type;
+        self
+    }
+
+    /// Set how the document is printed.
+    pub fn print_style(&mut self, print_style: PrintStyle) -> &mut Self {
+        self.print_style = print_style;
+        self
+    }
+
+    /// Start the document with a cover page, with its title, subtitle,
+    /// and authors.
+    pub fn cover(&mut self, yes: bool) -> &mut Self {
+        self.cover = yes;
+        self
+    }
+
+    /// End the document with a table of contents, with the `TOC` macro.
+    ///
+    /// The mom macros collect the chapters and headings for it.
+    pub fn table_of_contents(&mut self, yes: bool) -> &mut Self {
+        self.contents = yes;
+        self
+    }
+
+    /// Set the family of fonts of the text, such as `T` for Times or
+    /// `P` for Palatino.
+    pub fn family(&mut self, family: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.style.control("FAMILY", [family.into()]);
+        self
+    }
+
+    /// Set the size of the text, in points.
+    pub fn point_size(&mut self, size: f64) -> &mut Self {
+        self.style.control("PT_SIZE", [size.to_string()]);
+        self
+    }
+
+    /// Set the leading, the distance between lines, in points.
+    pub fn leading(&mut self, leading: f64) -> &mut Self {
+        self.style.control("LS", [leading.to_string()]);
+        self
+    }
+
+    /// Start a chapter, with its number, such as `1` or `IV`, and its
+    /// title.
+    pub fn chapter(
+        &mut self,
+        number: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.chapters
+            .push((number.into(), title.into(), Roff::new()));
+        self
+    }
+
+    /// Add a heading, with the `HEADING` macro.
+    ///
+    /// Level 1 is the highest.
+    pub fn heading(&mut self, level: u8, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.body()
+            .control("HEADING", [Cow::Owned(level.to_string()), text.into()]);
+        self
+    }
+
+    /// Add a paragraph, with the `PP` macro.
+    pub fn paragraph(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.body().control("PP", NO_ARGS).text(inlines);
+        self
+    }
+
+    /// Append a block.
+    pub fn append(&mut self, doc: Roff<'a>) -> &mut Self {
+        self.body().append(doc);
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let mut doc = Roff::new();
+        doc.control("TITLE", [self.title]);
+        if let Some(subtitle) = self.subtitle {
+            doc.control("SUBTITLE", [subtitle]);
+        }
+        for author in self.authors {
+            doc.control("AUTHOR", [author]);
+        }
+        match self.doc_type {
+            DocType::Default if !self.chapters.is_empty() => doc.control("DOCTYPE", ["CHAPTER"]),
+            DocType::Default => doc.control("DOCTYPE", ["DEFAULT"]),
+            DocType::Chapter => doc.control("DOCTYPE", ["CHAPTER"]),
+            DocType::Letter => doc.control("DOCTYPE", ["LETTER"]),
+            DocType::Named(name) => doc.control("DOCTYPE", [Cow::Borrowed("NAMED"), name]),
+        };
+        let print_style = match self.print_style {
+            PrintStyle::Typeset => "TYPESET",
+            PrintStyle::Typewrite => "TYPEWRITE",
+        };
+        doc.control("PRINTSTYLE", [print_style]);
+        doc.append(self.style);
+        if self.cover {
+            doc.control("COVER", ["TITLE", "SUBTITLE", "AUTHOR"]);
+        }
+
+        let mut chapters = self.chapters.into_iter();
+        match chapters.next() {
+            None => {
+                doc.control("START", NO_ARGS).append(self.front);
+            }
+            Some((number, title, body)) => {
+                // Text before the first chapter goes on its first page.
+                doc.control("CHAPTER", [number])
+                    .control("CHAPTER_TITLE", [title])
+                    .control("START", NO_ARGS)
+                    .append(self.front)
+                    .append(body);
+            }
+        }
+        for (number, title, body) in chapters {
+            doc.control("COLLATE", NO_ARGS)
+                .control("CHAPTER", [number])
+                .control("CHAPTER_TITLE", [title])
+                .control("START", NO_ARGS)
+                .append(body);
+        }
+        if self.contents {
+            doc.control("TOC", NO_ARGS);
+        }
+        doc
+    }
+
+    /// The document that content goes in: the last chapter, or before
+    /// any chapter.
+    fn body(&mut self) -> &mut Roff<'a> {
+        match self.chapters.last_mut() {
+            Some((_, _, body)) => body,
+            None => &mut self.front,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::roman;
+
+    #[test]
+    fn document_without_chapters() {
+        let mut doc = MomDocument::new("Report");
+        doc.subtitle("On Foo")
+            .print_style(PrintStyle::Typewrite)
+            .cover(true)
+            .table_of_contents(true)
+            .point_size(11.5)
+            .leading(14.0)
+            .heading(1, "Summary")
+            .paragraph([roman("text")]);
+        assert_eq!(
+            doc.into_roff().to_roff(),
+            ".TITLE Report\n\
+             .SUBTITLE \"On Foo\"\n\
+             .DOCTYPE DEFAULT\n\
+             .PRINTSTYLE TYPEWRITE\n\
+             .PT_SIZE 11.5\n\
+             .LS 14\n\
+             .COVER TITLE SUBTITLE AUTHOR\n\
+             .START\n\
+             .HEADING 1 Summary\n\
+             .PP\n\
+             text\n\
+             .TOC\n"
+        );
+    }
+
+    #[test]
+    fn chapters_are_collated() {
+        let mut doc = MomDocument::new("Book");
+        doc.doc_type(DocType::Named("Part".into()))
+            .chapter("1", "One")
+            .paragraph([roman("first")])
+            .chapter("2", "Two")
+            .paragraph([roman("second")]);
+        assert_eq!(
+            doc.into_roff().to_roff(),
+            ".TITLE Book\n\
+             .DOCTYPE NAMED Part\n\
+             .PRINTSTYLE TYPESET\n\
+             .CHAPTER 1\n\
+             .CHAPTER_TITLE One\n\
+             .START\n\
+             .PP\n\
+             first\n\
+             .COLLATE\n\
+             .CHAPTER 2\n\
+             .CHAPTER_TITLE Two\n\
+             .START\n\
+             .PP\n\
+             second\n"
+        );
+    }
+}
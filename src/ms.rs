@@ -0,0 +1,505 @@
+//! Building documents with the ms macros.
+//!
+//! The ms macros are for general documents, such as reports, papers,
+//! and letters, as described in [groff_ms(7)]. [`MsDocument`] takes
+//! care of the title page and headings, while the content is built as
+//! a [`Roff`], with the blocks in this module for footnotes, keeps,
+//! and displays.
+//!
+//! [groff_ms(7)]: https://manpages.debian.org/bullseye/groff/groff_ms.7.en.html
+
+use std::borrow::Cow;
+
+use crate::{roman, Inline, Line, PageTitle, Roff, NO_ARGS};
+
+/// A document using the ms macros.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::ms::MsDocument;
+/// let mut doc = MsDocument::new("On Foo");
+/// doc.author("Jane Doe", Some("Foo University"))
+///     .heading(1, "Introduction")
+///     .paragraph([roman("Foo is a thing.")]);
+/// assert_eq!(
+///     doc.into_roff().to_roff(),
+///     ".TL\n\
+///      On Foo\n\
+///      .AU\n\
+///      Jane Doe\n\
+///      .AI\n\
+///      Foo University\n\
+///      .NH 1\n\
+///      Introduction\n\
+///      .PP\n\
+///      Foo is a thing.\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct MsDocument<'a> {
+    title: Cow<'a, str>,
+    date: Option<Cow<'a, str>>,
+    authors: Vec<(Cow<'a, str>, Option<Cow<'a, str>>)>,
+    abstract_: Option<Roff<'a>>,
+    header: Option<PageTitle<'a>>,
+    footer: Option<PageTitle<'a>>,
+    contents: bool,
+    body: Roff<'a>,
+}
+
+impl<'a> MsDocument<'a> {
+    /// Start a document with a title.
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the date printed on the document, with the `ND` macro.
+    ///
+    /// By default, GNU troff prints today's date in some documents,
+    /// and none in others; an empty date prints none in all of them.
+    pub fn date(&mut self, date: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    /// Add an author, and the institution they are from, if any.
+    pub fn author(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        institution: Option<impl Into<Cow<'a, str>>>,
+    ) -> &mut Self {
+        self.authors
+            .push((name.into(), institution.map(Into::into)));
+        self
+    }
+
+    /// Set the header at the top of each page, with the `LH`, `CH`,
+    /// and `RH` strings.
+    ///
+    /// By default, the header is the page number in the center, from
+    /// the second page on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// # use roff::ms::MsDocument;
+    /// let mut doc = MsDocument::new("Report");
+    /// doc.header(PageTitle::new().left("Foo Inc.").right("%"));
+    /// assert_eq!(
+    ///     doc.into_roff().to_roff(),
+    ///     ".ds LH Foo Inc.\n\
+    ///      .ds CH\n\
+    ///      .ds RH \\\\n%\n\
+    ///      .TL\n\
+    ///      Report\n"
+    /// );
+    /// ```
+    pub fn header(&mut self, title: PageTitle<'a>) -> &mut Self {
+        self.header = Some(title);
+        self
+    }
+
+    /// Set the footer at the bottom of each page, with the `LF`, `CF`,
+    /// and `RF` strings.
+    ///
+    /// See [`header`](MsDocument::header).
+    pub fn footer(&mut self, title: PageTitle<'a>) -> &mut Self {
+        self.footer = Some(title);
+        self
+    }
+
+    /// Set the abstract, which is built by the given function.
+    pub fn abstract_(&mut self, content: impl FnOnce(&mut Roff<'a>)) -> &mut Self {
+        let mut doc = Roff::new();
+        content(&mut doc);
+        self.abstract_ = Some(doc);
+        self
+    }
+
+    /// Add a numbered heading, with the `NH` macro.
+    ///
+    /// Headings of level 1 are numbered 1, 2, and so on, those of level
+    /// 2 under them 1.1, 1.2, and so on.
+    pub fn heading(&mut self, level: u8, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.body.control("NH", [level.to_string()]);
+        self.body.text([roman(text)]);
+        self
+    }
+
+    /// Add a heading that isn't numbered, with the `SH` macro.
+    pub fn unnumbered_heading(&mut self, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.body.control("SH", NO_ARGS);
+        self.body.text([roman(text)]);
+        self
+    }
+
+    /// Add a paragraph, with the `PP` macro.
+    ///
+    /// Its first line is indented.
+    pub fn paragraph(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.body.control("PP", NO_ARGS);
+        self.body.text(inlines);
+        self
+    }
+
+    /// Add a paragraph whose first line isn't indented, with the `LP`
+    /// macro.
+    pub fn flush_paragraph(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.body.control("LP", NO_ARGS);
+        self.body.text(inlines);
+        self
+    }
+
+    /// Add an indented paragraph, with a tag in its margin, with the
+    /// `IP` macro.
+    pub fn indented_paragraph(
+        &mut self,
+        tag: impl Into<Cow<'a, str>>,
+        inlines: impl Into<Vec<Inline<'a>>>,
+    ) -> &mut Self {
+        self.body.control("IP", [tag.into()]);
+        self.body.text(inlines);
+        self
+    }
+
+    /// Add a quotation, indented on both sides, with the `QP` macro.
+    pub fn quotation(&mut self, inlines: impl Into<Vec<Inline<'a>>>) -> &mut Self {
+        self.body.control("QP", NO_ARGS);
+        self.body.text(inlines);
+        self
+    }
+
+    /// Append a block, such as a [`footnote`] or [`display`].
+    pub fn append(&mut self, doc: Roff<'a>) -> &mut Self {
+        self.body.append(doc);
+        self
+    }
+
+    /// End the document with a table of contents, with the `TC`
+    /// macro, listing its headings.
+    ///
+    /// Each heading is followed by an entry between the `XS` and `XE`
+    /// macros, with the page it's on, and those below level 1 are
+    /// indented by 2 ens per level.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// # use roff::ms::MsDocument;
+    /// let mut doc = MsDocument::new("On Foo");
+    /// doc.table_of_contents(true).heading(2, "Details");
+    /// assert_eq!(
+    ///     doc.into_roff().to_roff(),
+    ///     ".TL\n\
+    ///      On Foo\n\
+    ///      .NH 2\n\
+    ///      Details\n\
+    ///      .XS \\n% 2n\n\
+    ///      Details\n\
+    ///      .XE\n\
+    ///      .TC\n"
+    /// );
+    /// ```
+    pub fn table_of_contents(&mut self, yes: bool) -> &mut Self {
+        self.contents = yes;
+        self
+    }
+
+    /// Convert into a document.
+    pub fn into_roff(self) -> Roff<'a> {
+        let mut doc = Roff::new();
+        if let Some(date) = self.date {
+            doc.control("ND", [date]);
+        }
+        let titles = [
+            (self.header, ["LH", "CH", "RH"]),
+            (self.footer, ["LF", "CF", "RF"]),
+        ];
+        for (title, names) in titles {
+            let Some(title) = title else { continue };
+            for (name, part) in names.into_iter().zip(title.parts()) {
+                define_string(&mut doc, name, &part);
+            }
+        }
+        doc.control("TL", NO_ARGS).text([roman(self.title)]);
+        for (name, institution) in self.authors {
+            doc.control("AU", NO_ARGS).text([roman(name)]);
+            if let Some(institution) = institution {
+                doc.control("AI", NO_ARGS).text([roman(institution)]);
+            }
+        }
+        if let Some(abstract_) = self.abstract_ {
+            doc.control("AB", NO_ARGS)
+                .append(abstract_)
+                .control("AE", NO_ARGS);
+        }
+        if self.contents {
+            doc.append(with_contents_entries(self.body))
+                .control("TC", NO_ARGS);
+        } else {
+            doc.append(self.body);
+        }
+        doc
+    }
+}
+
+/// Define a string, whose uses interpolate the page number where the
+/// text has `%`.
+///
+/// The `ds` request takes the rest of its line, so the words of the
+/// text are its arguments, unquoted.
+fn define_string(doc: &mut Roff<'_>, name: &'static str, text: &str) {
+    let text = text.replace('%', r"\\n%").replace('"', r"\(dq");
+    let words = text
+        .split_whitespace()
+        .map(|word| Cow::Owned(word.to_owned()));
+    doc.control("ds", std::iter::once(Cow::Borrowed(name)).chain(words));
+}
+
+/// Add an entry for the table of contents after the text of each
+/// heading.
+fn with_contents_entries(body: Roff<'_>) -> Roff<'_> {
+    let mut doc = Roff::new();
+    // The level of a heading whose text is on the next text line.
+    let mut pending = None;
+    for line in body.lines {
+        let entry = match &line {
+            Line::Control { name, args } if name == "NH" => {
+                pending = Some(
+                    args.first()
+                        .and_then(|level| level.parse::<u8>().ok())
+                        .unwrap_or(1),
+                );
+                None
+            }
+            Line::Control { name, args } if name == "SH" && args.is_empty() => {
+                pending = Some(1);
+                None
+            }
+            Line::Control { .. } => None,
+            Line::Text(inlines) => pending.take().map(|level| (level, inlines.clone())),
+        };
+        doc.lines.push(line);
+        if let Some((level, inlines)) = entry {
+            if level > 1 {
+                let indent = format!("{}n", 2 * u32::from(level - 1));
+                doc.control("XS", [Cow::Borrowed("\\n%"), Cow::Owned(indent)]);
+            } else {
+                doc.control("XS", NO_ARGS);
+            }
+            doc.text(inlines).control("XE", NO_ARGS);
+        }
+    }
+    doc
+}
+
+/// A footnote, with the `FS` and `FE` macros.
+///
+/// The mark is printed before the footnote, and should also be in the
+/// text the footnote is for, just before it.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::ms::footnote;
+/// let mut doc = Roff::new();
+/// doc.text([roman("Foo is a thing.*")]);
+/// doc.append(footnote("*", |doc| {
+///     doc.text([roman("Or so they say.")]);
+/// }));
+/// assert_eq!(
+///     doc.to_roff(),
+///     "Foo is a thing.*\n.FS *\nOr so they say.\n.FE\n"
+/// );
+/// ```
+pub fn footnote<'a>(
+    mark: impl Into<Cow<'a, str>>,
+    content: impl FnOnce(&mut Roff<'a>),
+) -> Roff<'a> {
+    block("FS", Some(mark.into()), "FE", content)
+}
+
+/// A block that is kept together, on the next page if it doesn't fit
+/// on this one, with the `KS` and `KE` macros.
+pub fn keep<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    block("KS", None, "KE", content)
+}
+
+/// A block that is kept together, and floats to the next page if it
+/// doesn't fit on this one, with the `KF` and `KE` macros.
+///
+/// Unlike a [`keep`], text after the block fills the rest of this page.
+pub fn floating_keep<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    block("KF", None, "KE", content)
+}
+
+/// A block set in two columns, with the `2C` and `1C` macros.
+///
+/// Text flows from the bottom of the left column to the top of the
+/// right one. Put each entry, such as a term of a glossary and its
+/// definition, in a [`keep`], so that it isn't split across columns.
+///
+/// The `1C` macro starts a new page, so this is best used for the
+/// whole document, or the end of it.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::ms::{keep, two_columns};
+/// let doc = two_columns(|doc| {
+///     doc.append(keep(|doc| {
+///         doc.control("IP", ["foo"]).text([roman("A thing.")]);
+///     }));
+/// });
+/// assert_eq!(
+///     doc.to_roff(),
+///     ".2C\n.KS\n.IP foo\nA thing.\n.KE\n.1C\n"
+/// );
+/// ```
+pub fn two_columns<'a>(content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    block("2C", None, "1C", content)
+}
+
+/// How the lines of a [`display`] are placed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Display {
+    /// At the left margin.
+    Left,
+
+    /// Indented.
+    #[default]
+    Indented,
+
+    /// Each line centered.
+    Centered,
+
+    /// Centered as a block, with the lines aligned to its left.
+    Block,
+}
+
+impl Display {
+    /// The argument to the `DS` macro for the display.
+    pub fn arg(self) -> &'static str {
+        match self {
+            Self::Left => "L",
+            Self::Indented => "I",
+            Self::Centered => "C",
+            Self::Block => "B",
+        }
+    }
+}
+
+/// A display, whose lines aren't filled, kept together, with the `DS`
+/// and `DE` macros.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// # use roff::ms::{display, Display};
+/// let doc = display(Display::Centered, |doc| {
+///     doc.text([roman("one")]).text([roman("two")]);
+/// });
+/// assert_eq!(doc.to_roff(), ".DS C\none\ntwo\n.DE\n");
+/// ```
+pub fn display<'a>(kind: Display, content: impl FnOnce(&mut Roff<'a>)) -> Roff<'a> {
+    block("DS", Some(Cow::Borrowed(kind.arg())), "DE", content)
+}
+
+fn block<'a>(
+    start: &'static str,
+    arg: Option<Cow<'a, str>>,
+    end: &'static str,
+    content: impl FnOnce(&mut Roff<'a>),
+) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.control(start, arg);
+    content(&mut doc);
+    doc.control(end, NO_ARGS);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn title_page() {
+        let mut doc = MsDocument::new("Report");
+        doc.date("")
+            .author("A", None::<&str>)
+            .author("B", Some("Lab"))
+            .abstract_(|doc| {
+                doc.text([roman("Short.")]);
+            })
+            .unnumbered_heading("Preface")
+            .flush_paragraph([roman("first")])
+            .indented_paragraph("1.", [roman("item")])
+            .quotation([roman("quoted")])
+            .append(keep(|doc| {
+                doc.text([roman("kept")]);
+            }));
+        assert_eq!(
+            doc.into_roff().to_roff(),
+            ".ND \"\"\n\
+             .TL\n\
+             Report\n\
+             .AU\n\
+             A\n\
+             .AU\n\
+             B\n\
+             .AI\n\
+             Lab\n\
+             .AB\n\
+             Short.\n\
+             .AE\n\
+             .SH\n\
+             Preface\n\
+             .LP\n\
+             first\n\
+             .IP 1.\n\
+             item\n\
+             .QP\n\
+             quoted\n\
+             .KS\n\
+             kept\n\
+             .KE\n"
+        );
+    }
+
+    #[test]
+    fn table_of_contents_entries() {
+        let mut doc = MsDocument::new("Report");
+        doc.table_of_contents(true)
+            .unnumbered_heading("Preface")
+            .heading(1, "Start")
+            .paragraph([roman("text")]);
+        assert_eq!(
+            doc.into_roff().to_roff(),
+            ".TL\n\
+             Report\n\
+             .SH\n\
+             Preface\n\
+             .XS\n\
+             Preface\n\
+             .XE\n\
+             .NH 1\n\
+             Start\n\
+             .XS\n\
+             Start\n\
+             .XE\n\
+             .PP\n\
+             text\n\
+             .TC\n"
+        );
+    }
+}
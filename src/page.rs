@@ -0,0 +1,210 @@
+use std::borrow::Cow;
+
+use crate::{Distance, Roff};
+
+/// The geometry of the page, for documents that are printed or turned
+/// into PDF, rather than read with a manual page viewer.
+///
+/// Anything not set is left as the ROFF implementation has it. Man
+/// page viewers set the line length to fit the terminal, so this is not
+/// for manual pages.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let setup = PageSetup::new()
+///     .page_length(Distance::inches(11.0))
+///     .page_offset(Distance::inches(1.0))
+///     .line_length(Distance::inches(6.5));
+/// let mut doc = Roff::new();
+/// doc.page_setup(&setup);
+/// assert_eq!(doc.to_roff(), ".pl 11i\n.po 1i\n.ll 6.5i\n");
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PageSetup {
+    page_length: Option<Distance>,
+    page_offset: Option<Distance>,
+    line_length: Option<Distance>,
+}
+
+impl PageSetup {
+    /// Leave all of the page geometry as it is.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the length of the page, from top to bottom, with the `pl`
+    /// request.
+    pub fn page_length(mut self, length: Distance) -> Self {
+        self.page_length = Some(length);
+        self
+    }
+
+    /// Set the offset of the left margin from the left edge of the
+    /// paper, with the `po` request.
+    pub fn page_offset(mut self, offset: Distance) -> Self {
+        self.page_offset = Some(offset);
+        self
+    }
+
+    /// Set the length of output lines, from the left margin, with the
+    /// `ll` request.
+    pub fn line_length(mut self, length: Distance) -> Self {
+        self.line_length = Some(length);
+        self
+    }
+}
+
+/// A title of three parts, at the left, center, and right of the
+/// line, such as a running header or footer.
+///
+/// In each part, `%` stands for the number of the page, as in the `tl`
+/// request.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let title = PageTitle::new().left("Foo manual").right("page %");
+/// let mut doc = Roff::new();
+/// doc.title_line(&title);
+/// assert_eq!(doc.to_roff(), ".tl 'Foo manual''page %'\n");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PageTitle<'a> {
+    left: Cow<'a, str>,
+    center: Cow<'a, str>,
+    right: Cow<'a, str>,
+}
+
+impl<'a> PageTitle<'a> {
+    /// Start a title with all three parts empty.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the part at the left.
+    pub fn left(mut self, text: impl Into<Cow<'a, str>>) -> Self {
+        self.left = text.into();
+        self
+    }
+
+    /// Set the part in the center.
+    pub fn center(mut self, text: impl Into<Cow<'a, str>>) -> Self {
+        self.center = text.into();
+        self
+    }
+
+    /// Set the part at the right.
+    pub fn right(mut self, text: impl Into<Cow<'a, str>>) -> Self {
+        self.right = text.into();
+        self
+    }
+
+    /// The parts, escaped, from left to right.
+    pub(crate) fn parts(&self) -> [String; 3] {
+        [&self.left, &self.center, &self.right].map(|part| escape_part(part))
+    }
+
+    /// The title in the form of the argument to the `tl` request, with
+    /// each part after a delimiter that none of them contains.
+    pub(crate) fn delimited(&self) -> String {
+        let parts = self.parts();
+        let delimiter = ['\'', '|', '/', '^', '~']
+            .into_iter()
+            .find(|&c| !parts.iter().any(|part| part.contains(c)))
+            .unwrap_or('\'');
+        let mut title = String::new();
+        for part in &parts {
+            title.push(delimiter);
+            title.push_str(part);
+        }
+        title.push(delimiter);
+        title
+    }
+}
+
+/// Escape a part of a title.
+///
+/// Titles are often kept in strings and macro arguments, which remove
+/// one level of backslashes, so a backslash is printed with `\e`. A
+/// double quote would have the title quoted, so it is printed with
+/// `\(dq`.
+fn escape_part(part: &str) -> String {
+    part.replace('\\', r"\e")
+        .replace('-', r"\-")
+        .replace('"', r"\(dq")
+}
+
+impl<'a> Roff<'a> {
+    /// Append a line with a title of three parts, with the `tl`
+    /// request.
+    ///
+    /// The line is as long as the title length, which is set with the
+    /// `lt` request. It isn't filled with the text around it.
+    pub fn title_line(&mut self, title: &PageTitle<'_>) -> &mut Self {
+        // The `tl` request takes the rest of its line, quotes and all,
+        // so the words of the title are its arguments, unquoted.
+        let title = title.delimited();
+        let words = title
+            .split_whitespace()
+            .map(|word| Cow::Owned(word.to_owned()));
+        self.control("tl", words)
+    }
+
+    /// Append the requests for a page geometry.
+    ///
+    /// This is usually done at the start of the document.
+    pub fn page_setup(&mut self, setup: &PageSetup) -> &mut Self {
+        let requests = [
+            ("pl", setup.page_length),
+            ("po", setup.page_offset),
+            ("ll", setup.line_length),
+        ];
+        for (name, distance) in requests {
+            if let Some(distance) = distance {
+                self.control(name, [distance]);
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ApostropheMode, RenderOptions};
+
+    #[test]
+    fn only_what_is_set() {
+        let mut doc = Roff::new();
+        doc.page_setup(&PageSetup::new())
+            .page_setup(&PageSetup::new().line_length(Distance::ens(60.0)));
+        assert_eq!(doc.to_roff(), ".ll 60n\n");
+    }
+
+    #[test]
+    fn title_delimiter_not_in_parts() {
+        let title = PageTitle::new().left("Foo's").center("a\\b");
+        let mut doc = Roff::new();
+        doc.title_line(&title);
+        assert_eq!(doc.to_roff(), ".tl |Foo's|a\\eb||\n");
+    }
+
+    #[test]
+    fn title_with_spaces_is_not_quoted() {
+        let title = PageTitle::new()
+            .left("The \"Foo\" manual")
+            .center("draft")
+            .right("page %");
+        let mut doc = Roff::new();
+        doc.title_line(&title);
+        let expected = ".tl 'The \\(dqFoo\\(dq manual'draft'page %'\n";
+        assert_eq!(doc.to_roff(), expected);
+        let options = RenderOptions::new()
+            .apostrophes(ApostropheMode::Plain)
+            .always_quote(true);
+        assert_eq!(doc.render_with(&options), expected);
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::{Dialect, Line, Roff};
+
+/// Requests and macros that only GNU troff has, of those documents are
+/// likely to use.
+const GNU_REQUESTS: &[&str] = &[
+    "als",
+    "am1",
+    "break",
+    "cflags",
+    "char",
+    "chop",
+    "continue",
+    "de1",
+    "defcolor",
+    "do",
+    "ds1",
+    "ecs",
+    "evc",
+    "fchar",
+    "fcolor",
+    "ftr",
+    "gcolor",
+    "hcode",
+    "hla",
+    "hlm",
+    "hpf",
+    "hym",
+    "kern",
+    "length",
+    "linetabs",
+    "mso",
+    "nop",
+    "open",
+    "opena",
+    "pdfhref",
+    "PSPIC",
+    "pso",
+    "pvs",
+    "rchar",
+    "return",
+    "rnn",
+    "shc",
+    "shift",
+    "sizes",
+    "special",
+    "substring",
+    "trf",
+    "trin",
+    "trnt",
+    "vpt",
+    "warn",
+    "warnscale",
+    "while",
+];
+
+/// Requests and macros that mandoc doesn't know, of those documents
+/// are likely to use.
+const NOT_MANDOC_REQUESTS: &[&str] = &["defcolor", "pdfhref", "PSPIC"];
+
+/// A construct in a document that the targeted ROFF implementation
+/// doesn't understand.
+///
+/// Returned by [`Roff::lint_portability`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PortabilityIssue {
+    index: usize,
+    construct: String,
+}
+
+impl PortabilityIssue {
+    /// Index of the offending line in the document, starting at zero.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The offending request, macro, or escape, such as `.defcolor` or
+    /// `\*[`.
+    pub fn construct(&self) -> &str {
+        &self.construct
+    }
+}
+
+impl fmt::Display for PortabilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {} is not understood by the targeted implementation",
+            self.index + 1,
+            self.construct
+        )
+    }
+}
+
+impl Roff<'_> {
+    /// Find the control lines that use requests, macros, or escapes
+    /// that a ROFF implementation doesn't understand.
+    ///
+    /// Text lines are escaped as they are rendered, with
+    /// [`RenderOptions::dialect`](crate::RenderOptions::dialect), so
+    /// only control lines, whose arguments are written as they are,
+    /// can have problems. A line that is only read by GNU troff, as it
+    /// starts with `.if \n(.g`, is never a problem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("TH", ["FOO", "1"])
+    ///     .control("defcolor", ["warning", "rgb", "#ff8000"])
+    ///     .control("SH", [r"\*[name]"]);
+    /// let issues = doc.lint_portability(Dialect::Heirloom);
+    /// assert_eq!(issues.len(), 2);
+    /// assert_eq!(issues[0].index(), 1);
+    /// assert_eq!(issues[0].construct(), ".defcolor");
+    /// assert_eq!(issues[1].construct(), r"\*[");
+    /// assert!(doc.lint_portability(Dialect::Groff).is_empty());
+    /// ```
+    pub fn lint_portability(&self, dialect: Dialect) -> Vec<PortabilityIssue> {
+        let mut issues = vec![];
+        for (index, line) in self.lines.iter().enumerate() {
+            let Line::Control { name, args } = line else {
+                continue;
+            };
+            if name == "if" && args.first().map(AsRef::as_ref) == Some(r"\n(.g") {
+                continue;
+            }
+            let unknown = match dialect {
+                Dialect::Groff => false,
+                Dialect::Mandoc => NOT_MANDOC_REQUESTS.contains(&name.as_ref()),
+                Dialect::Heirloom | Dialect::Plan9 => GNU_REQUESTS.contains(&name.as_ref()),
+            };
+            if unknown {
+                issues.push(PortabilityIssue {
+                    index,
+                    construct: format!(".{name}"),
+                });
+            }
+            if !dialect.has_groff_escapes() {
+                for construct in args.iter().flat_map(|arg| gnu_escapes(arg)) {
+                    issues.push(PortabilityIssue { index, construct });
+                }
+            }
+        }
+        issues
+    }
+}
+
+/// The escapes in an argument that only GNU troff and mandoc
+/// understand.
+fn gnu_escapes(arg: &str) -> Vec<String> {
+    let mut escapes = vec![];
+    let mut chars = arg.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            continue;
+        }
+        let Some(escape) = chars.next() else {
+            break;
+        };
+        match escape {
+            // A glyph by long name, or a color.
+            '[' => escapes.push(r"\[".to_owned()),
+            'm' | 'M' => escapes.push(format!(r"\{escape}")),
+            // A string, register, font, or size by long name.
+            '*' | 'n' | 'f' | 's' | 'F' if chars.peek() == Some(&'[') => {
+                escapes.push(format!(r"\{escape}["));
+            }
+            _ => {}
+        }
+    }
+    escapes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            gnu_escapes(r"\\[not] \(em \m[red]\n[.j]\n(.j\s[+12]\fB"),
+            [r"\m", r"\n[", r"\s["]
+        );
+    }
+
+    #[test]
+    fn guarded_lines_and_mandoc() {
+        let mut doc = Roff::new();
+        doc.control("if", [r"\n(.g", ".defcolor", r"\*[x]"])
+            .control("PSPIC", ["logo.eps"])
+            .control("nop", ["text"]);
+        let issues = doc.lint_portability(Dialect::Mandoc);
+        assert_eq!(
+            issues,
+            [PortabilityIssue {
+                index: 1,
+                construct: ".PSPIC".to_owned()
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "line 2: .PSPIC is not understood by the targeted implementation"
+        );
+        assert_eq!(doc.lint_portability(Dialect::Plan9).len(), 2);
+    }
+}
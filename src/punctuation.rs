@@ -0,0 +1,117 @@
+use crate::{Inline, SpecialChar};
+
+/// Return inline elements between double quotation marks: “ and ”.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.text(double_quoted([roman("foo")]));
+/// assert_eq!(doc.to_roff(), "\\(lqfoo\\(rq\n");
+/// ```
+pub fn double_quoted<'a>(inlines: impl Into<Vec<Inline<'a>>>) -> Vec<Inline<'a>> {
+    quoted(
+        SpecialChar::LeftDoubleQuote,
+        inlines.into(),
+        SpecialChar::RightDoubleQuote,
+    )
+}
+
+/// Return inline elements between single quotation marks: ‘ and ’.
+pub fn single_quoted<'a>(inlines: impl Into<Vec<Inline<'a>>>) -> Vec<Inline<'a>> {
+    quoted(
+        SpecialChar::LeftSingleQuote,
+        inlines.into(),
+        SpecialChar::RightSingleQuote,
+    )
+}
+
+fn quoted(left: SpecialChar, inlines: Vec<Inline<'_>>, right: SpecialChar) -> Vec<Inline<'_>> {
+    let mut quoted = Vec::with_capacity(inlines.len() + 2);
+    quoted.push(Inline::Special(left));
+    quoted.extend(inlines);
+    quoted.push(Inline::Special(right));
+    quoted
+}
+
+/// A preamble defining strings for the glyphs of smart punctuation.
+///
+/// Typesetters get the glyphs, while terminals get the ASCII
+/// characters they replace, so that manual pages read in a terminal
+/// look the same as without smart punctuation.
+pub(crate) const SMART_PUNCTUATION_PREAMBLE: &str = r#".ie t .ds Q< \(lq
+.el .ds Q< ""
+.ie t .ds Q> \(rq
+.el .ds Q> ""
+.ie t .ds S< \(oq
+.el .ds S< '
+.ie t .ds S> \(cq
+.el .ds S> '
+.ie t .ds D- \(en
+.el .ds D- \-
+.ie t .ds D= \(em
+.el .ds D= \-\-
+"#;
+
+/// Escape text, with ASCII quotes, dashes, and ellipses as glyphs.
+///
+/// A quote is an opening one after a space or an opening bracket, or
+/// at the start of the text if something other than a space or
+/// punctuation follows it; other quotes close. Two dashes are an en
+/// dash and three an em dash, except at the start of a word, as for
+/// an option such as `--verbose`. Three periods are an ellipsis.
+pub(crate) fn smarten(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut smart = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let prev = i.checked_sub(1).map(|j| chars[j]);
+        let at_word_start = prev.map(char::is_whitespace).unwrap_or(true);
+        let run = chars[i..].iter().take_while(|c| **c == chars[i]).count();
+        let next = chars.get(i + run).copied();
+        match chars[i] {
+            c @ ('"' | '\'') => {
+                let opening = match prev {
+                    Some(prev) => prev.is_whitespace() || "([{".contains(prev),
+                    None => next
+                        .is_some_and(|next| !next.is_whitespace() && !next.is_ascii_punctuation()),
+                };
+                smart.push_str(match (c, opening) {
+                    ('"', true) => r"\*(Q<",
+                    ('"', false) => r"\*(Q>",
+                    (_, true) => r"\*(S<",
+                    (_, false) => r"\*(S>",
+                });
+                i += 1;
+                continue;
+            }
+            '-' if (run == 2 || run == 3)
+                && !(at_word_start && next.is_some_and(char::is_alphanumeric)) =>
+            {
+                smart.push_str(if run == 2 { r"\*(D-" } else { r"\*(D=" });
+            }
+            '-' => smart.push_str(&r"\-".repeat(run)),
+            '.' if run == 3 => smart.push_str(SpecialChar::Ellipsis.escape()),
+            '\\' => smart.push_str(&r"\\".repeat(run)),
+            c => smart.extend(std::iter::repeat(c).take(run)),
+        }
+        i += run;
+    }
+    smart
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn quotes_and_dashes() {
+        assert_eq!(
+            smarten(r#"Say "don't" -- or use --quiet, pages 1--10..."#),
+            r"Say \*(Q<don\*(S>t\*(Q> \*(D- or use \-\-quiet, pages 1\*(D-10\&.\|.\|."
+        );
+        assert_eq!(smarten(r"'a' \ ---"), r"\*(S<a\*(S> \\ \*(D=");
+        assert_eq!(smarten("\""), r"\*(Q>");
+    }
+}
@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+
+use crate::{roman, Roff, NO_ARGS};
+
+/// A bibliographic reference, for the [refer(1)] preprocessor.
+///
+/// A citation either gives the fields of the reference, such as its
+/// authors and title, or keywords to look it up by in a database of
+/// references, as named with [`refer_commands`].
+///
+/// [refer(1)]: https://manpages.debian.org/bullseye/groff/refer.1.en.html
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let citation = Citation::new()
+///     .author("Brian W. Kernighan")
+///     .title("A Typesetter-independent TROFF")
+///     .date("1981");
+/// let mut doc = Roff::new();
+/// doc.text([roman("As shown before.")]).cite(&citation);
+/// assert_eq!(
+///     doc.to_roff(),
+///     "As shown before.\n\
+///      .[\n\
+///      %A Brian W. Kernighan\n\
+///      %T A Typesetter\\-independent TROFF\n\
+///      %D 1981\n\
+///      .]\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Citation<'a> {
+    keywords: Option<Cow<'a, str>>,
+    fields: Vec<(char, Cow<'a, str>)>,
+}
+
+impl<'a> Citation<'a> {
+    /// Start a citation with no fields.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Look up the reference in a database, by keywords such as
+    /// authors' names and words of the title.
+    pub fn keywords(mut self, keywords: impl Into<Cow<'a, str>>) -> Self {
+        self.keywords = Some(keywords.into());
+        self
+    }
+
+    /// Set a field by its letter: `%` and the letter start its line.
+    pub fn field(mut self, key: char, value: impl Into<Cow<'a, str>>) -> Self {
+        self.fields.push((key, value.into()));
+        self
+    }
+
+    /// Add an author, the `A` field.
+    pub fn author(self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.field('A', name)
+    }
+
+    /// Set the title, the `T` field.
+    pub fn title(self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.field('T', title)
+    }
+
+    /// Set the journal the reference was published in, the `J` field.
+    pub fn journal(self, journal: impl Into<Cow<'a, str>>) -> Self {
+        self.field('J', journal)
+    }
+
+    /// Set the book the reference is part of, the `B` field.
+    pub fn book(self, book: impl Into<Cow<'a, str>>) -> Self {
+        self.field('B', book)
+    }
+
+    /// Set the publisher, the `I` field.
+    pub fn publisher(self, publisher: impl Into<Cow<'a, str>>) -> Self {
+        self.field('I', publisher)
+    }
+
+    /// Set the place of publication, the `C` field.
+    pub fn place(self, place: impl Into<Cow<'a, str>>) -> Self {
+        self.field('C', place)
+    }
+
+    /// Set the date of publication, the `D` field.
+    pub fn date(self, date: impl Into<Cow<'a, str>>) -> Self {
+        self.field('D', date)
+    }
+
+    /// Set the volume, the `V` field.
+    pub fn volume(self, volume: impl Into<Cow<'a, str>>) -> Self {
+        self.field('V', volume)
+    }
+
+    /// Set the number of the issue, the `N` field.
+    pub fn number(self, number: impl Into<Cow<'a, str>>) -> Self {
+        self.field('N', number)
+    }
+
+    /// Set the pages, the `P` field.
+    pub fn pages(self, pages: impl Into<Cow<'a, str>>) -> Self {
+        self.field('P', pages)
+    }
+}
+
+impl<'a> Roff<'a> {
+    /// Cite a reference, between the `[` and `]` macros, for refer to
+    /// replace by a mark in the text, such as `[1]`, and an entry in
+    /// the list of references.
+    ///
+    /// The mark goes at the end of the text line before.
+    pub fn cite(&mut self, citation: &Citation<'a>) -> &mut Self {
+        self.control("[", NO_ARGS);
+        if let Some(keywords) = &citation.keywords {
+            self.text([roman(keywords.clone())]);
+        }
+        for (key, value) in &citation.fields {
+            self.text([roman(format!("%{key} ")), roman(value.clone())]);
+        }
+        self.control("]", NO_ARGS)
+    }
+}
+
+/// Commands for refer, between the `R1` and `R2` macros, such as
+/// `database refs` or `sort`.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = refer_commands(["database refs", "accumulate"]);
+/// assert_eq!(doc.to_roff(), ".R1\ndatabase refs\naccumulate\n.R2\n");
+/// ```
+pub fn refer_commands<'a>(commands: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Roff<'a> {
+    let mut doc = Roff::new();
+    doc.control("R1", NO_ARGS);
+    for command in commands {
+        doc.text([roman(command)]);
+    }
+    doc.control("R2", NO_ARGS);
+    doc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cite_by_keywords() {
+        let mut doc = Roff::new();
+        doc.cite(&Citation::new().keywords("kernighan troff").pages("1-10"));
+        assert_eq!(doc.to_roff(), ".[\nkernighan troff\n%P 1\\-10\n.]\n");
+    }
+}
@@ -0,0 +1,356 @@
+/// Options controlling how a [`Roff`](crate::Roff) is rendered.
+///
+/// The defaults match [`Roff::render`](crate::Roff::render).
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let options = RenderOptions::new().encoding(Encoding::Ascii);
+/// let doc = Roff::new().text([roman("naïve")]).render_with(&options);
+/// assert!(doc.ends_with("na\\[u00EF]ve\n"));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RenderOptions {
+    pub(crate) apostrophes: ApostropheMode,
+    pub(crate) encoding: Encoding,
+    pub(crate) coding_comment: bool,
+    pub(crate) always_quote: bool,
+    pub(crate) minify: bool,
+    pub(crate) sentence_per_line: bool,
+    pub(crate) pdf_outline: bool,
+    pub(crate) smart_punctuation: bool,
+    pub(crate) cjk_line_breaks: bool,
+    pub(crate) version: OutputVersion,
+    pub(crate) dialect: Dialect,
+}
+
+impl RenderOptions {
+    /// Instantiate the default `RenderOptions`
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Choose how apostrophes are rendered.
+    pub fn apostrophes(mut self, mode: ApostropheMode) -> Self {
+        self.apostrophes = mode;
+        self
+    }
+
+    /// Choose how characters outside of ASCII are rendered.
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Start the output with a comment naming the encoding.
+    ///
+    /// The comment is of the form `.\" -*- coding: utf-8 -*-`, which
+    /// is what [preconv(1)] looks for to decide how to read its input.
+    /// `man` runs `preconv` on manual pages, as does `groff -k`.
+    ///
+    /// [preconv(1)]: https://manpages.debian.org/bullseye/groff/preconv.1.en.html
+    pub fn coding_comment(mut self, yes: bool) -> Self {
+        self.coding_comment = yes;
+        self
+    }
+
+    /// Enclose every control line argument in double quotes.
+    ///
+    /// By default, only arguments that need it are quoted. The
+    /// arguments of the `ds` and `tl` requests are never quoted, as
+    /// they take the rest of their line, quotes and all.
+    pub fn always_quote(mut self, yes: bool) -> Self {
+        self.always_quote = yes;
+        self
+    }
+
+    /// Leave out whatever doesn't affect how the document is typeset.
+    ///
+    /// This removes redundant font changes, such as around empty
+    /// text, repeated line breaks, and spaces at the ends of lines.
+    /// Manual pages are installed by the thousands, so it adds up.
+    pub fn minify(mut self, yes: bool) -> Self {
+        self.minify = yes;
+        self
+    }
+
+    /// Start each sentence in a text line on a new line of output.
+    ///
+    /// This is the traditional convention for writing ROFF by hand:
+    /// it makes the source easier to read and changes easier to
+    /// review, and ROFF implementations know to put the right amount
+    /// of space between sentences. It makes no difference to how the
+    /// document is typeset.
+    pub fn sentence_per_line(mut self, yes: bool) -> Self {
+        self.sentence_per_line = yes;
+        self
+    }
+
+    /// Add each `SH` and `SS` heading to the outline of PDF output,
+    /// with the `pdfhref` macro of GNU troff.
+    ///
+    /// PDF viewers show the outline as a table of contents, with
+    /// subsections under their sections. The `pdfhref` request is only
+    /// made if the macro is defined, as it is when GNU troff writes
+    /// PDF, so other output is unchanged. Only [`Dialect::Groff`] has
+    /// the macro, so for other dialects this does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["SEE ALSO"]);
+    /// let options = RenderOptions::new()
+    ///     .apostrophes(ApostropheMode::Plain)
+    ///     .pdf_outline(true);
+    /// assert_eq!(
+    ///     doc.render_with(&options),
+    ///     ".SH \"SEE ALSO\"\n\
+    ///      .if \\n(.g .if d pdfhref .pdfhref O 1 \"SEE ALSO\"\n"
+    /// );
+    /// ```
+    pub fn pdf_outline(mut self, yes: bool) -> Self {
+        self.pdf_outline = yes;
+        self
+    }
+
+    /// Typeset ASCII quotes, dashes, and ellipses in text as the
+    /// glyphs they stand for: curly quotes, en and em dashes, and
+    /// ellipses.
+    ///
+    /// Quotes open or close depending on what is around them. Two
+    /// dashes are an en dash and three an em dash, except at the start
+    /// of a word, as for an option such as `--verbose`. The glyphs are
+    /// strings defined in a preamble, which only typesetters, not
+    /// terminals, define as the glyphs, so that terminal output stays
+    /// plain. Text meant to be copied, such as commands and code, is
+    /// better without this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let doc = Roff::new().text([roman("\"Fast\" -- really")]).render_with(
+    ///     &RenderOptions::new()
+    ///         .apostrophes(ApostropheMode::Plain)
+    ///         .smart_punctuation(true),
+    /// );
+    /// assert!(doc.starts_with(".ie t .ds Q< \\(lq\n"));
+    /// assert!(doc.ends_with("\n\\*(Q<Fast\\*(Q> \\*(D- really\n"));
+    /// ```
+    pub fn smart_punctuation(mut self, yes: bool) -> Self {
+        self.smart_punctuation = yes;
+        self
+    }
+
+    /// Let lines be broken between the characters of Chinese, Japanese,
+    /// and Korean text.
+    ///
+    /// These scripts don't put spaces between words, so without this,
+    /// ROFF implementations see a whole sentence as one word, which
+    /// overflows the line. This adds places where lines may be
+    /// broken, `\:`, between wide characters, except before closing
+    /// punctuation and small kana, and after opening punctuation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let doc = Roff::new()
+    ///     .text([roman("日本語です。")])
+    ///     .render_with(&RenderOptions::new().cjk_line_breaks(true));
+    /// assert!(doc.ends_with("日\\:本\\:語\\:で\\:す。\n"));
+    /// ```
+    pub fn cjk_line_breaks(mut self, yes: bool) -> Self {
+        self.cjk_line_breaks = yes;
+        self
+    }
+
+    /// Choose the implementation of ROFF the output is for.
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Render as a version of the output, rather than the latest.
+    ///
+    /// See [`OutputVersion`].
+    pub fn output_version(mut self, version: OutputVersion) -> Self {
+        self.version = version;
+        self
+    }
+}
+
+/// A version of how control line arguments are quoted, and of whether
+/// documents with tables say so.
+///
+/// Later releases of this crate may change these, where that improves
+/// the output, which changes the output for the same documents. Tools
+/// that check generated pages into version control can choose a version
+/// with [`RenderOptions::output_version`], so that upgrading the crate
+/// doesn't requote every control line of the pages, moving on to a later
+/// version when they choose to. How text is escaped isn't covered by a
+/// version, and may still change between releases.
+///
+/// This covers the output for the same options. Options, such as
+/// [`RenderOptions::minify`], aren't turned on by a version.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.control("TH", ["FOO", "1", ""]);
+/// let v1 = RenderOptions::new()
+///     .apostrophes(ApostropheMode::Plain)
+///     .output_version(OutputVersion::V1);
+/// assert_eq!(doc.render_with(&v1), ".TH FOO 1 \n");
+/// let v2 = v1.output_version(OutputVersion::V2);
+/// assert_eq!(doc.render_with(&v2), ".TH FOO 1 \"\"\n");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Default)]
+#[non_exhaustive]
+pub enum OutputVersion {
+    /// The argument quoting and the tbl hint of roff 0.2.
+    ///
+    /// Only control line arguments with spaces are quoted, and double
+    /// quotes in them are left as they are, so that an argument with
+    /// a double quote, a tab, or nothing at all doesn't come out as
+    /// one argument. Documents with tables don't start with a comment
+    /// saying so.
+    V1,
+
+    /// Control line arguments are quoted whenever they would not
+    /// otherwise come out as one argument: if they contain spaces,
+    /// tabs, or double quotes, or are empty. Double quotes in them are
+    /// doubled. Documents with tables start with the comment `'\" t`,
+    /// which tells `man` to run them through tbl(1).
+    #[default]
+    V2,
+}
+
+impl OutputVersion {
+    /// The latest version, which is the default.
+    pub const LATEST: Self = Self::V2;
+}
+
+/// How apostrophes are rendered.
+///
+/// Typeset as is, an apostrophe ("`'`") looks like a right single
+/// quote, including in words like "don't". That is rarely what is
+/// wanted, so by default a preamble defining a string for a proper
+/// apostrophe is rendered, and used in place of each apostrophe.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ApostropheMode {
+    /// Render a preamble defining an apostrophe string, and use it.
+    ///
+    /// This works with every ROFF implementation. It's what
+    /// [`Roff::render`](crate::Roff::render) does.
+    #[default]
+    Preamble,
+
+    /// Render apostrophes as they are, with no preamble.
+    ///
+    /// It's what [`Roff::to_roff`](crate::Roff::to_roff) does.
+    Plain,
+
+    /// Render apostrophes as the `\[u0027]` escape, with no preamble.
+    ///
+    /// This is understood by GNU troff and mandoc, but not by older
+    /// implementations.
+    Unicode,
+}
+
+/// The implementation of ROFF that output is for.
+///
+/// By default, output is for GNU troff, but only uses its extensions
+/// where there is a fallback for other implementations, as with
+/// [`ApostropheMode::Preamble`]. Choosing another implementation
+/// leaves out what it doesn't understand, or wouldn't use, instead.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let doc = Roff::new().text([roman("don't")]).render_with(
+///     &RenderOptions::new().dialect(Dialect::Mandoc),
+/// );
+/// assert_eq!(doc, "don\\(aqt\n");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Dialect {
+    /// GNU troff, as run by `groff` and `man` on most systems.
+    #[default]
+    Groff,
+
+    /// mandoc, the formatter of manual pages on the BSDs.
+    ///
+    /// It understands the escapes of GNU troff, so apostrophes are
+    /// rendered as `\(aq`, with no preamble, and PDF outlines are left
+    /// out.
+    Mandoc,
+
+    /// Heirloom troff, a descendant of the original troff.
+    ///
+    /// Escapes that only GNU troff and mandoc understand are left
+    /// out: apostrophes are rendered as they are, colors are left out,
+    /// type sizes use the two-digit form `\s+(12`, and characters
+    /// with no glyph name are written in UTF-8, not as `\[uXXXX]`,
+    /// even with [`Encoding::Ascii`].
+    Heirloom,
+
+    /// The troff of Plan 9, which reads UTF-8 natively.
+    ///
+    /// The output is the same as for [`Dialect::Heirloom`].
+    Plan9,
+}
+
+impl Dialect {
+    /// Does the implementation understand the escapes GNU troff added,
+    /// such as `\[u00E9]`, `\m[red]`, and `\s[+12]`?
+    pub(crate) fn has_groff_escapes(self) -> bool {
+        matches!(self, Self::Groff | Self::Mandoc)
+    }
+}
+
+/// How characters outside of ASCII are rendered.
+///
+/// There is no default that suits every use: ROFF implementations
+/// predating Unicode can't read UTF-8 at all, while for localized
+/// manual pages escaping every character makes the source
+/// unreadable. Choose explicitly if the output is going anywhere but a
+/// modern `man`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Encoding {
+    /// Characters are written as they are, in UTF-8.
+    ///
+    /// GNU troff reads its input as Latin-1 unless it is first run
+    /// through [preconv(1)], for example with `groff -k`. `man` does
+    /// this for manual pages. See also
+    /// [`RenderOptions::coding_comment`].
+    ///
+    /// [preconv(1)]: https://manpages.debian.org/bullseye/groff/preconv.1.en.html
+    #[default]
+    Utf8,
+
+    /// Characters outside of ASCII are written as escape sequences.
+    ///
+    /// A character that has a name known to ROFF (see
+    /// [`SpecialChar`](crate::SpecialChar)) is written as the named
+    /// glyph, any other character as `\[uXXXX]`. The output is then
+    /// pure ASCII, which is safe for old implementations of ROFF and
+    /// for tooling that chokes on anything else.
+    Ascii,
+}
+
+impl Encoding {
+    /// The name of the encoding, as used in a coding comment.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Ascii => "us-ascii",
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::slice;
+
+use crate::{render_preamble, Line, LineRenderer, RenderOptions, Roff};
+
+impl Roff<'_> {
+    /// The lines of the rendered document, without their newlines.
+    ///
+    /// Lines are rendered as they are needed, so the output can be
+    /// streamed or paginated without rendering all of it at once. The
+    /// lines are the same as those of [`render`](Roff::render).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["NAME"])
+    ///     .text([roman("foo"), line_break(), roman("bar")]);
+    /// let lines: Vec<String> = doc.rendered_lines().collect();
+    /// assert_eq!(lines[lines.len() - 4..], [".SH NAME", "foo", ".br", "bar"]);
+    /// ```
+    pub fn rendered_lines(&self) -> RenderedLines<'_> {
+        self.rendered_lines_with(&RenderOptions::default())
+    }
+
+    /// The lines of the rendered document, with custom
+    /// [`RenderOptions`].
+    ///
+    /// See [`rendered_lines`](Roff::rendered_lines).
+    pub fn rendered_lines_with(&self, options: &RenderOptions) -> RenderedLines<'_> {
+        let mut buf = String::new();
+        render_preamble(&mut buf, options, &self.lines).expect("writing to a String always works");
+        RenderedLines {
+            lines: self.lines.iter(),
+            options: options.clone(),
+            buf,
+            pos: 0,
+            renderer: LineRenderer::default(),
+        }
+    }
+}
+
+/// An iterator over the lines of a rendered document, from
+/// [`Roff::rendered_lines`].
+#[derive(Debug)]
+pub struct RenderedLines<'r> {
+    lines: slice::Iter<'r, Line<'r>>,
+    options: RenderOptions,
+    // The rendering of the last line of the document, and how much of
+    // it has been returned.
+    buf: String,
+    pos: usize,
+    renderer: LineRenderer,
+}
+
+impl Iterator for RenderedLines<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while self.pos == self.buf.len() {
+            let line = self.lines.next()?;
+            self.buf.clear();
+            self.pos = 0;
+            self.renderer
+                .render(&mut self.buf, line, &self.options)
+                .expect("writing to a String always works");
+        }
+        let rest = &self.buf[self.pos..];
+        let end = rest.find('\n').unwrap_or(rest.len());
+        let line = rest[..end].to_owned();
+        self.pos = (self.pos + end + 1).min(self.buf.len());
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{bold, roman, RenderOptions, Roff, NO_ARGS};
+
+    #[test]
+    fn same_as_render() {
+        let options = RenderOptions::new().minify(true).pdf_outline(true);
+        let mut doc = Roff::new();
+        doc.control("SH", ["OPTIONS"])
+            .control("br", NO_ARGS)
+            .control("br", NO_ARGS)
+            .text([bold("--foo"), roman("\nmore")]);
+        let joined: String = doc
+            .rendered_lines_with(&options)
+            .map(|line| format!("{line}\n"))
+            .collect();
+        assert_eq!(joined, doc.render_with(&options));
+        assert_eq!(
+            Roff::new().rendered_lines().count(),
+            Roff::new().render().lines().count()
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{Line, Roff};
+
+/// How to restore a setting after a block that changes it.
+///
+/// The setting is saved in a number register before the block, and
+/// restored from it after, so the name of the register has a number
+/// appended for how deeply the block is nested.
+pub(crate) struct Restore {
+    /// Prefix of the name of the register to save the setting in.
+    pub(crate) register: &'static str,
+
+    /// Escape for the current value of the setting.
+    pub(crate) current: &'static str,
+
+    /// Request that sets the setting.
+    pub(crate) request: &'static str,
+
+    /// Unit of the saved value, to append to it for the request.
+    pub(crate) unit: &'static str,
+}
+
+/// A block that changes a setting with `start`, after which the setting
+/// is restored.
+pub(crate) fn scoped<'a>(
+    restore: Restore,
+    start: impl FnOnce(&mut Roff<'a>),
+    content: impl FnOnce(&mut Roff<'a>),
+) -> Roff<'a> {
+    let mut body = Roff::new();
+    content(&mut body);
+
+    // Blocks nested in this one have been built already, so this one
+    // uses the register after theirs.
+    let depth = body
+        .lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Control { name, args } if name == "nr" => {
+                args.first()?.strip_prefix(restore.register)
+            }
+            _ => None,
+        })
+        .filter_map(|n| n.parse::<usize>().ok())
+        .max()
+        .map(|n| n + 1)
+        .unwrap_or(0);
+    let register = format!("{}{depth}", restore.register);
+
+    let mut doc = Roff::new();
+    doc.control("nr", [register.clone(), restore.current.to_owned()]);
+    start(&mut doc);
+    doc.append(body);
+    doc.control(
+        restore.request,
+        [format!(r"\n[{register}]{}", restore.unit)],
+    );
+    doc
+}
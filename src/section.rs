@@ -0,0 +1,135 @@
+use std::ops::Range;
+
+use crate::{Line, LineRef, Roff};
+
+impl<'a> Roff<'a> {
+    /// The section with a name, from its `SH` line up to the next one,
+    /// if the document has one.
+    ///
+    /// The name is compared with the arguments of each `SH` line,
+    /// joined by spaces, as in [`Roff::section_names`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["NAME"])
+    ///     .text([roman("foo")])
+    ///     .control("SH", ["OPTIONS"])
+    ///     .control("TP", NO_ARGS)
+    ///     .text([bold("-v")]);
+    /// let options = doc.section("OPTIONS").unwrap();
+    /// assert_eq!(options.range(), 2..5);
+    /// assert_eq!(options.lines().len(), 3);
+    /// assert!(doc.section("EXAMPLES").is_none());
+    /// ```
+    pub fn section(&self, name: &str) -> Option<SectionView<'_>> {
+        let range = self.section_range(name)?;
+        Some(SectionView {
+            lines: &self.lines[range.clone()],
+            range,
+        })
+    }
+
+    /// Move sections into an order, by name.
+    ///
+    /// Sections named in the order come first, in that order, and the
+    /// others after them, in the order they were in. Lines before the
+    /// first section, such as the `TH` line of a manual page, stay
+    /// where they are. This is for documents assembled from several
+    /// sources, with [`man::SECTION_ORDER`](crate::man::SECTION_ORDER)
+    /// for the conventional order of a manual page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("TH", ["FOO", "1"])
+    ///     .control("SH", ["SEE", "ALSO"])
+    ///     .control("SH", ["EXTRA"])
+    ///     .control("SH", ["NAME"]);
+    /// doc.reorder_sections(roff::man::SECTION_ORDER);
+    /// assert_eq!(doc.section_names(), ["NAME", "SEE ALSO", "EXTRA"]);
+    /// ```
+    pub fn reorder_sections(
+        &mut self,
+        order: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Self {
+        let order: Vec<_> = order.into_iter().collect();
+        let mut lines = std::mem::take(&mut self.lines).into_iter().peekable();
+        while let Some(line) = lines.next_if(|line| !is_section(line)) {
+            self.lines.push(line);
+        }
+        let mut sections: Vec<(usize, Vec<Line<'a>>)> = vec![];
+        for line in lines {
+            if is_section(&line) {
+                let name = LineRef::new(&line).args().join(" ");
+                let rank = order
+                    .iter()
+                    .position(|n| n.as_ref() == name)
+                    .unwrap_or(order.len());
+                sections.push((rank, vec![]));
+            }
+            if let Some((_, section)) = sections.last_mut() {
+                section.push(line);
+            }
+        }
+        sections.sort_by_key(|(rank, _)| *rank);
+        for (_, section) in sections {
+            self.lines.extend(section);
+        }
+        self
+    }
+}
+
+fn is_section(line: &Line<'_>) -> bool {
+    LineRef::new(line).is_control("SH")
+}
+
+/// A read-only view of a section of a [`Roff`], from
+/// [`Roff::section`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SectionView<'r> {
+    lines: &'r [Line<'r>],
+    range: Range<usize>,
+}
+
+impl<'r> SectionView<'r> {
+    /// The name of the section: the arguments of its `SH` line, joined
+    /// by spaces.
+    pub fn name(&self) -> String {
+        LineRef::new(&self.lines[0]).args().join(" ")
+    }
+
+    /// The lines of the section, starting with its `SH` line.
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = LineRef<'r>> + DoubleEndedIterator {
+        self.lines.iter().map(LineRef::new)
+    }
+
+    /// The indexes of the lines of the section in the document, for
+    /// editing it with methods such as [`Roff::remove_line`].
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{roman, Roff};
+
+    #[test]
+    fn reorder_keeps_content_and_duplicates() {
+        let mut doc = Roff::new();
+        doc.control("SH", ["B"])
+            .text([roman("b1")])
+            .control("SH", ["A"])
+            .text([roman("a")])
+            .control("SH", ["B"])
+            .text([roman("b2")]);
+        doc.reorder_sections(["A", "B"]);
+        assert_eq!(doc.to_roff(), ".SH A\na\n.SH B\nb1\n.SH B\nb2\n");
+        assert_eq!(doc.section("B").unwrap().name(), "B");
+    }
+}
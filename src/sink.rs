@@ -0,0 +1,92 @@
+use std::fmt;
+
+use crate::{roman, Roff};
+
+/// Append text to a document with [`write!`].
+///
+/// Everything written is appended as roman text, one text line for
+/// each line written, and escaped as usual when the document is
+/// rendered. Empty lines are left out. The last line, if it doesn't end
+/// with a newline, is appended when the sink is dropped.
+///
+/// This lets code that writes to a [`fmt::Write`], such as a
+/// [`Display`](fmt::Display) implementation, feed a document
+/// directly.
+///
+/// # Example
+///
+/// ```
+/// # use std::fmt::Write;
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.control("SH", ["DESCRIPTION"]);
+/// write!(doc.text_sink(), "{} - {} bits", "corrupt", 2).unwrap();
+/// assert_eq!(doc.to_roff(), ".SH DESCRIPTION\ncorrupt \\- 2 bits\n");
+/// ```
+#[derive(Debug)]
+pub struct TextSink<'r, 'a> {
+    doc: &'r mut Roff<'a>,
+    line: String,
+}
+
+impl<'a> Roff<'a> {
+    /// Get a [`TextSink`] that appends what is written to it as text.
+    pub fn text_sink(&mut self) -> TextSink<'_, 'a> {
+        TextSink {
+            doc: self,
+            line: String::new(),
+        }
+    }
+}
+
+impl TextSink<'_, '_> {
+    /// Append the last line, if it doesn't end with a newline.
+    ///
+    /// This is done when the sink is dropped, too.
+    pub fn finish(mut self) {
+        self.flush();
+    }
+
+    fn flush(&mut self) {
+        if !self.line.is_empty() {
+            self.doc.text([roman(std::mem::take(&mut self.line))]);
+        }
+    }
+}
+
+impl fmt::Write for TextSink<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut lines = s.split('\n');
+        if let Some(first) = lines.next() {
+            self.line.push_str(first);
+        }
+        for line in lines {
+            self.flush();
+            self.line.push_str(line);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TextSink<'_, '_> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Write;
+
+    use super::*;
+
+    #[test]
+    fn lines_across_writes() {
+        let mut doc = Roff::new();
+        let mut sink = doc.text_sink();
+        write!(sink, "one ").unwrap();
+        write!(sink, "two\n\n.three\nfour").unwrap();
+        sink.finish();
+        assert_eq!(doc.to_roff(), "one two\n\\&.three\nfour\n");
+    }
+}
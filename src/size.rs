@@ -0,0 +1,138 @@
+use crate::{roman, Inline};
+
+/// Return inline elements in a larger or smaller type size.
+///
+/// See [`Inline::Resized`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.text([resized(2, [bold("Title")]), roman(" text")]);
+/// assert_eq!(doc.to_roff(), "\\s+2\\fBTitle\\fR\\s-2 text\n");
+/// ```
+pub fn resized<'a>(change: i32, inlines: impl Into<Vec<Inline<'a>>>) -> Inline<'a> {
+    Inline::Resized {
+        change,
+        inlines: inlines.into(),
+    }
+}
+
+/// Return inline elements in superscript.
+///
+/// See [`Inline::Superscript`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.text([roman("10 cm"), superscript([roman("2")])]);
+/// assert_eq!(doc.to_roff(), "10 cm\\u\\s-22\\s+2\\d\n");
+/// ```
+pub fn superscript<'a>(inlines: impl Into<Vec<Inline<'a>>>) -> Inline<'a> {
+    Inline::Superscript(inlines.into())
+}
+
+/// Return inline elements in subscript.
+///
+/// See [`Inline::Subscript`].
+pub fn subscript<'a>(inlines: impl Into<Vec<Inline<'a>>>) -> Inline<'a> {
+    Inline::Subscript(inlines.into())
+}
+
+/// Return text in small capitals, as inline elements in the roman
+/// font.
+///
+/// Fonts rarely come with real small capitals, so lowercase letters
+/// are turned into capitals two points smaller than the rest.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// assert_eq!(
+///     small_caps("Unix"),
+///     [roman("U"), resized(-2, [roman("NIX")])]
+/// );
+/// ```
+pub fn small_caps(text: &str) -> Vec<Inline<'static>> {
+    let mut inlines = vec![];
+    let mut run = String::new();
+    let mut lowercase = false;
+    for c in text.chars() {
+        if c.is_lowercase() != lowercase && !run.is_empty() {
+            inlines.push(small_caps_run(std::mem::take(&mut run), lowercase));
+        }
+        lowercase = c.is_lowercase();
+        if lowercase {
+            run.extend(c.to_uppercase());
+        } else {
+            run.push(c);
+        }
+    }
+    if !run.is_empty() {
+        inlines.push(small_caps_run(run, lowercase));
+    }
+    inlines
+}
+
+fn small_caps_run(run: String, lowercase: bool) -> Inline<'static> {
+    if lowercase {
+        resized(-2, [roman(run)])
+    } else {
+        roman(run)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{italic, line_break, Roff};
+
+    #[test]
+    fn nested_sizes_restore_outer_size() {
+        let mut doc = Roff::new();
+        doc.text([resized(
+            12,
+            [roman("a "), resized(-3, [roman("b")]), roman(" c")],
+        )]);
+        assert_eq!(doc.to_roff(), "\\s[+12]a \\s-3b\\s+3 c\\s[-12]\n");
+    }
+
+    #[test]
+    fn line_break_in_size() {
+        let mut doc = Roff::new();
+        doc.text([resized(1, [roman("a"), line_break(), roman(".b")])]);
+        assert_eq!(doc.to_roff(), "\\s+1a\n.br\n\\&.b\\s-1\n");
+    }
+
+    #[test]
+    fn small_caps_runs() {
+        assert_eq!(
+            small_caps("GNU's roff 2"),
+            [
+                roman("GNU'"),
+                resized(-2, [roman("S")]),
+                roman(" "),
+                resized(-2, [roman("ROFF")]),
+                roman(" 2"),
+            ]
+        );
+        assert_eq!(small_caps(""), []);
+    }
+
+    #[test]
+    fn subscript_in_superscript() {
+        let mut doc = Roff::new();
+        doc.text([
+            italic("e"),
+            superscript([italic("x"), subscript([roman("1")])]),
+        ]);
+        assert_eq!(
+            doc.to_roff(),
+            "\\fIe\\fR\\u\\s-2\\fIx\\fR\\d\\s-21\\s+2\\u\\s+2\\d\n"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Roff;
+
+impl Roff<'_> {
+    /// Number of lines in the document.
+    ///
+    /// This counts lines as appended, not as rendered: a text line
+    /// with a line break renders as more than one line.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Is the document empty?
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Does the document contain a control line for the given request
+    /// or macro?
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("TH", ["FOO", "1"]);
+    /// assert!(doc.contains_request("TH"));
+    /// assert!(!doc.contains_request("SH"));
+    /// ```
+    pub fn contains_request(&self, name: &str) -> bool {
+        self.lines().any(|line| line.is_control(name))
+    }
+
+    /// Names of the sections, in order.
+    ///
+    /// These are the arguments of the `SH` control lines, joined by
+    /// spaces.
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["NAME"])
+    ///     .text([roman("foo")])
+    ///     .control("SH", ["SEE", "ALSO"]);
+    /// assert_eq!(doc.section_names(), ["NAME", "SEE ALSO"]);
+    /// ```
+    pub fn section_names(&self) -> Vec<String> {
+        self.lines()
+            .filter(|line| line.is_control("SH"))
+            .map(|line| line.args().join(" "))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{line_break, roman, Roff};
+
+    #[test]
+    fn counts_appended_lines() {
+        let mut doc = Roff::new();
+        assert!(doc.is_empty());
+        doc.control("SH", ["NAME"])
+            .text([roman("foo"), line_break(), roman("bar")]);
+        assert!(!doc.is_empty());
+        assert_eq!(doc.line_count(), 2);
+    }
+}
@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+
+use crate::{bold, roman, Inline, SpecialChar};
+
+/// Return the synopsis of a command as inline elements: its name in
+/// bold, followed by options and arguments, as made by [`opt`],
+/// [`req`], [`alt`], and [`repeat`].
+///
+/// Items are separated by spaces where lines may be broken, while
+/// the parts of each item are kept together.
+///
+/// See [`man::Synopsis`](crate::man::Synopsis) for a synopsis laid out
+/// with the macros of GNU troff.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let synopsis = synopsis_line(
+///     "corrupt",
+///     [
+///         opt([short_flag('n'), vec![italic("BITS")]]),
+///         opt([flag("--bits"), vec![italic("BITS")]]),
+///         repeat([[italic("FILE")]]),
+///     ],
+/// );
+/// assert_eq!(
+///     Roff::new().text(synopsis).to_roff(),
+///     "\\fBcorrupt\\fR \\%[\\fB\\-n\\fR\\ \\fIBITS\\fR] \
+///      \\%[\\fB\\-\\-bits\\fR\\ \\fIBITS\\fR] \\fIFILE\\fR...\n"
+/// );
+/// ```
+pub fn synopsis_line<'a, I>(
+    command: impl Into<Cow<'a, str>>,
+    items: impl IntoIterator<Item = I>,
+) -> Vec<Inline<'a>>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    let mut inlines = vec![bold(command)];
+    for item in items {
+        inlines.push(roman(" "));
+        inlines.extend(item.into());
+    }
+    inlines
+}
+
+/// Return parts that may be left out, such as an option and its
+/// value, in brackets: `[-n BITS]`.
+pub fn opt<'a, I>(parts: impl IntoIterator<Item = I>) -> Vec<Inline<'a>>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    bracketed("[", parts, "]")
+}
+
+/// Return parts that must be given, in braces: `{-n BITS}`.
+///
+/// This is mostly for a choice made with [`alt`]. A single part that
+/// must be given needs no braces.
+pub fn req<'a, I>(parts: impl IntoIterator<Item = I>) -> Vec<Inline<'a>>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    bracketed("{", parts, "}")
+}
+
+/// Return a choice between alternatives, separated by bars: `-q|-v`.
+///
+/// The choice is usually put in [`opt`] or [`req`]. Choices that are
+/// kept from being hyphenated, such as [flags](crate::flag), keep the
+/// whole choice from being hyphenated.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let choice = req([alt([flag("-q"), flag("-v")])]);
+/// assert_eq!(
+///     Roff::new().text(choice).to_roff(),
+///     "\\%{\\fB\\-q\\fR|\\fB\\-v\\fR}\n"
+/// );
+/// ```
+pub fn alt<'a, I>(choices: impl IntoIterator<Item = I>) -> Vec<Inline<'a>>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    let mut inlines = vec![];
+    let mut unhyphenated = false;
+    for (i, choice) in choices.into_iter().enumerate() {
+        if i > 0 {
+            inlines.push(roman("|"));
+        }
+        let mut choice = choice.into();
+        unhyphenated |= take_leading_hyphenation_point(&mut choice);
+        inlines.extend(choice);
+    }
+    if unhyphenated {
+        inlines.insert(0, Inline::HyphenationPoint);
+    }
+    inlines
+}
+
+/// Return parts that may be given more than once, followed by an
+/// ellipsis: `FILE...`.
+pub fn repeat<'a, I>(parts: impl IntoIterator<Item = I>) -> Vec<Inline<'a>>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    let mut inlines = joined(parts);
+    inlines.push(roman("..."));
+    inlines
+}
+
+fn bracketed<'a, I>(
+    open: &'static str,
+    parts: impl IntoIterator<Item = I>,
+    close: &'static str,
+) -> Vec<Inline<'a>>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    let mut parts = joined(parts);
+    // `\%` only keeps a word from being hyphenated at its start, so it
+    // goes before the bracket.
+    let mut inlines = if take_leading_hyphenation_point(&mut parts) {
+        vec![Inline::HyphenationPoint, roman(open)]
+    } else {
+        vec![roman(open)]
+    };
+    inlines.extend(parts);
+    inlines.push(roman(close));
+    inlines
+}
+
+/// Remove a hyphenation point at the start of the inline elements,
+/// returning whether there was one.
+fn take_leading_hyphenation_point(inlines: &mut Vec<Inline<'_>>) -> bool {
+    let leading = matches!(inlines.first(), Some(Inline::HyphenationPoint));
+    if leading {
+        inlines.remove(0);
+    }
+    leading
+}
+
+/// The parts, separated by spaces that lines are never broken at.
+fn joined<'a, I>(parts: impl IntoIterator<Item = I>) -> Vec<Inline<'a>>
+where
+    I: Into<Vec<Inline<'a>>>,
+{
+    let mut inlines = vec![];
+    for (i, part) in parts.into_iter().enumerate() {
+        if i > 0 {
+            inlines.push(SpecialChar::NoBreakSpace.into());
+        }
+        inlines.extend(part.into());
+    }
+    inlines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{flag, italic, Roff};
+
+    #[test]
+    fn nested_groups() {
+        let inlines = opt([
+            flag("--color"),
+            req([alt([[italic("always")], [italic("never")]])]),
+        ]);
+        assert_eq!(
+            Roff::new().text(inlines).to_roff(),
+            "\\%[\\fB\\-\\-color\\fR\\ {\\fIalways\\fR|\\fInever\\fR}]\n"
+        );
+    }
+
+    #[test]
+    fn hyphenation_point_starts_the_word() {
+        let inlines = opt([req([alt([flag("-q"), flag("-v")])])]);
+        assert_eq!(
+            Roff::new().text(inlines).to_roff(),
+            "\\%[{\\fB\\-q\\fR|\\fB\\-v\\fR}]\n"
+        );
+    }
+}
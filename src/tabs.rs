@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use crate::{Roff, NO_ARGS};
+
+/// A tab stop, for [`Roff::tab_stops`].
+///
+/// Each stop is at a distance from the left margin, such as `8n` or
+/// `1.5i`, and aligns the text after a [`tab`](crate::tab) to it in
+/// some way.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TabStop<'a> {
+    position: Cow<'a, str>,
+    align: &'static str,
+}
+
+impl<'a> TabStop<'a> {
+    /// A tab stop that text starts at.
+    pub fn left(position: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            position: position.into(),
+            align: "",
+        }
+    }
+
+    /// A tab stop that text ends at.
+    pub fn right(position: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            position: position.into(),
+            align: "R",
+        }
+    }
+
+    /// A tab stop that text is centered on.
+    pub fn center(position: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            position: position.into(),
+            align: "C",
+        }
+    }
+}
+
+impl<'a> Roff<'a> {
+    /// Set the tab stops for the lines that follow, with the `ta`
+    /// request.
+    ///
+    /// Text between tabs is laid out in columns, which is enough for
+    /// simple tables. Tabs are only aligned within a single output
+    /// line, so this is usually used with filling turned off.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.tab_stops([TabStop::left("8n"), TabStop::right("30n")])
+    ///     .control("nf", NO_ARGS)
+    ///     .text([bold("-v"), tab(), roman("verbose"), tab(), roman("off")])
+    ///     .control("fi", NO_ARGS)
+    ///     .reset_tab_stops();
+    /// assert_eq!(
+    ///     doc.to_roff(),
+    ///     ".ta 8n 30nR\n.nf\n\\fB\\-v\\fR\tverbose\toff\n.fi\n.ta\n"
+    /// );
+    /// ```
+    pub fn tab_stops(&mut self, stops: impl IntoIterator<Item = TabStop<'a>>) -> &mut Self {
+        let args: Vec<_> = stops
+            .into_iter()
+            .map(|stop| match stop.align {
+                "" => stop.position,
+                align => Cow::Owned(format!("{}{align}", stop.position)),
+            })
+            .collect();
+        self.control("ta", args)
+    }
+
+    /// Go back to the default tab stops, every half inch.
+    pub fn reset_tab_stops(&mut self) -> &mut Self {
+        self.control("ta", NO_ARGS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{roman, tab};
+
+    #[test]
+    fn tab_stop_alignment() {
+        let mut doc = Roff::new();
+        doc.tab_stops([
+            TabStop::left("1i"),
+            TabStop::center("2i"),
+            TabStop::right("3i"),
+        ]);
+        assert_eq!(doc.to_roff(), ".ta 1i 2iC 3iR\n");
+    }
+
+    #[test]
+    fn tab_at_line_start() {
+        let mut doc = Roff::new();
+        doc.text([tab(), roman(".x")]);
+        assert_eq!(doc.to_roff(), "\t.x\n");
+    }
+}
@@ -0,0 +1,97 @@
+//! Testing generated documents against ROFF implementations.
+//!
+//! These are the helpers this crate's own tests use, for tests of
+//! crates that generate manual pages: [`has_command`] checks that a
+//! ROFF implementation is installed, so that a test can be skipped
+//! where it isn't, and [`roff_to_ascii`] and [`mandoc_to_ascii`]
+//! format a page as plain text, to compare with what's expected.
+//!
+//! # Example
+//!
+//! ```
+//! # use roff::*;
+//! # use roff::test_util::{has_command, roff_to_ascii};
+//! if has_command("troff") {
+//!     let page = Roff::new()
+//!         .control("TH", ["FOO", "1"])
+//!         .control("SH", ["NAME"])
+//!         .text([roman("foo - do a foo thing")])
+//!         .render();
+//!     assert!(roff_to_ascii(&page).contains("foo - do a foo thing"));
+//! }
+//! ```
+
+use std::process::Command;
+
+use crate::check::run;
+
+/// Whether a command can be run, by running it with `--version`.
+///
+/// # Panics
+///
+/// If the command can be started but fails, or, in continuous
+/// integration on Linux, where the `CI` or `TF_BUILD` environment
+/// variable is set, if it can't be started: there, every command
+/// tests need is expected to be installed.
+pub fn has_command(command: &str) -> bool {
+    let output = match Command::new(command).arg("--version").output() {
+        Ok(output) => output,
+        Err(e) => {
+            if is_ci() && cfg!(target_os = "linux") {
+                panic!("expected command `{command}` to be somewhere in PATH: {e}");
+            }
+            return false;
+        }
+    };
+    if !output.status.success() {
+        panic!(
+            "expected command `{}` to be runnable, got error {}:\n\
+             stderr:{}\n\
+             stdout:{}\n",
+            command,
+            output.status,
+            String::from_utf8_lossy(&output.stderr),
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // The bash of macOS is too old for what tests use it for.
+    !(cfg!(target_os = "macos") && stdout.starts_with("GNU bash, version 3"))
+}
+
+/// Format a manual page as ASCII text, with `troff -a -mman`.
+///
+/// # Panics
+///
+/// If `troff` can't be run, or fails.
+pub fn roff_to_ascii(input: &str) -> String {
+    format_with(Command::new("troff").args(["-a", "-mman"]), input)
+}
+
+/// Format a manual page as ASCII text, with `mandoc -T ascii`.
+///
+/// # Panics
+///
+/// If `mandoc` can't be run, or fails.
+pub fn mandoc_to_ascii(input: &str) -> String {
+    format_with(Command::new("mandoc").args(["-T", "ascii"]), input)
+}
+
+fn format_with(command: &mut Command, input: &str) -> String {
+    let output = run(command, input)
+        .unwrap_or_else(|e| panic!("failed to run `{:?}`: {e}", command.get_program()));
+    if !output.status.success() {
+        panic!(
+            "`{:?}` failed with {}:\n{}",
+            command.get_program(),
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// Whether this is running in continuous integration.
+fn is_ci() -> bool {
+    std::env::var_os("CI").is_some() || std::env::var_os("TF_BUILD").is_some()
+}
@@ -0,0 +1,310 @@
+//! Property testing of generated documents.
+//!
+//! The [proptest](mod@proptest) strategies here make documents of any shape, and
+//! [`assert_well_formed`] and [`assert_no_stray_control_lines`] check
+//! rendered output for the invariants this crate keeps, so that a
+//! generator of documents can be tested end to end.
+//!
+//! # Example
+//!
+//! ```
+//! # use proptest::prelude::*;
+//! # use roff::testing::{assert_well_formed, inlines};
+//! # use roff::*;
+//! proptest!(|(description in inlines())| {
+//!     let mut doc = Roff::new();
+//!     doc.control("SH", ["DESCRIPTION"]).text(description);
+//!     assert_well_formed(&doc.render());
+//! });
+//! ```
+
+use std::borrow::Cow;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use crate::punctuation::SMART_PUNCTUATION_PREAMBLE;
+use crate::{bold, italic, roman, Inline, Line, Roff, SpecialChar, APOSTROPHE_PREABMLE};
+
+/// Any document.
+///
+/// Text is arbitrary, since it is escaped, but control lines are
+/// used as is, so their names are valid, as by [`Roff::validate`], and
+/// their arguments have no backslashes or newlines.
+pub fn roff() -> impl Strategy<Value = Roff<'static>> {
+    let control = (name(), vec(arg(), 0..4)).prop_map(|(name, args)| Line::control(name, args));
+    let text = inlines().prop_map(Line::text);
+    vec(prop_oneof![control, text], 0..16).prop_map(|lines| Roff { lines })
+}
+
+/// Any inline element, with elements nested in it.
+pub fn inline() -> impl Strategy<Value = Inline<'static>> {
+    let leaf = prop_oneof![
+        any::<String>().prop_map(roman),
+        any::<String>().prop_map(italic),
+        any::<String>().prop_map(bold),
+        Just(Inline::LineBreak),
+        special_char().prop_map(Inline::Special),
+        Just(Inline::HyphenationPoint),
+        Just(Inline::BreakPoint),
+        Just(Inline::Tab),
+        Just(Inline::ZeroWidth),
+    ];
+    leaf.prop_recursive(3, 32, 4, |inner| {
+        let inlines = vec(inner, 0..4);
+        prop_oneof![
+            (arg(), inlines.clone())
+                .prop_map(|(color, inlines)| Inline::Colored { color, inlines }),
+            (-99..=99, inlines.clone())
+                .prop_map(|(change, inlines)| Inline::Resized { change, inlines }),
+            inlines.clone().prop_map(Inline::Superscript),
+            inlines.prop_map(Inline::Subscript),
+        ]
+    })
+}
+
+/// The inline elements of a text line.
+pub fn inlines() -> impl Strategy<Value = Vec<Inline<'static>>> {
+    vec(inline(), 0..8)
+}
+
+fn special_char() -> impl Strategy<Value = SpecialChar> {
+    prop_oneof![
+        Just(SpecialChar::Bullet),
+        Just(SpecialChar::Copyright),
+        Just(SpecialChar::Registered),
+        Just(SpecialChar::Trademark),
+        Just(SpecialChar::Degree),
+        Just(SpecialChar::Ellipsis),
+        Just(SpecialChar::LeftDoubleQuote),
+        Just(SpecialChar::RightDoubleQuote),
+        Just(SpecialChar::LeftSingleQuote),
+        Just(SpecialChar::RightSingleQuote),
+        Just(SpecialChar::EmDash),
+        Just(SpecialChar::EnDash),
+        Just(SpecialChar::Section),
+        Just(SpecialChar::NoBreakSpace),
+    ]
+}
+
+fn name() -> impl Strategy<Value = Cow<'static, str>> {
+    vec(proptest::char::range('!', '~'), 1..5).prop_map(|chars| {
+        let name = chars
+            .into_iter()
+            .map(|c| if matches!(c, '"' | '\\') { 'x' } else { c })
+            .collect::<String>();
+        Cow::Owned(name)
+    })
+}
+
+fn arg() -> impl Strategy<Value = Cow<'static, str>> {
+    any::<String>().prop_map(|arg| Cow::Owned(arg.replace(['\\', '\n'], " ")))
+}
+
+/// Check rendered output for the invariants this crate keeps, and
+/// panic, naming the line, if any doesn't hold.
+///
+/// These are:
+///
+/// * Control lines have an even number of double quotes, so that no
+///   argument runs into the next.
+/// * No line ends with an escape, which would join it to the next.
+/// * The output ends in the roman font, and at the size it starts at,
+///   so that fonts and sizes changed for some text are restored after
+///   it.
+///
+/// Text that would start a control line isn't checked for here; see
+/// [`assert_no_stray_control_lines`].
+///
+/// # Example
+///
+/// ```
+/// # use roff::testing::assert_well_formed;
+/// assert_well_formed(".SH \"SEE ALSO\"\n\\fBfoo\\fR(1)\n");
+/// ```
+///
+/// ```should_panic
+/// # use roff::testing::assert_well_formed;
+/// assert_well_formed("\\fBfoo\n");
+/// ```
+pub fn assert_well_formed(rendered: &str) {
+    if let Err((line, problem)) = check(rendered) {
+        panic!("line {line}: {problem}");
+    }
+}
+
+/// Check that rendering a document made no control lines other than
+/// those it has, and panic, naming the first extra line, if it did.
+///
+/// Text that would start a control line is always escaped, so the
+/// only control lines the output may have beyond the document's are
+/// the `br` request for line breaks, the preamble, and the entries of
+/// the PDF outline.
+///
+/// # Example
+///
+/// ```
+/// # use roff::testing::assert_no_stray_control_lines;
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.control("SH", ["NAME"]).text([roman(".foo and 'bar")]);
+/// assert_no_stray_control_lines(&doc, &doc.render());
+/// ```
+///
+/// ```should_panic
+/// # use roff::testing::assert_no_stray_control_lines;
+/// # use roff::*;
+/// let doc = Roff::from(".foo");
+/// assert_no_stray_control_lines(&doc, ".foo\n");
+/// ```
+pub fn assert_no_stray_control_lines(doc: &Roff<'_>, rendered: &str) {
+    let expected = doc
+        .lines
+        .iter()
+        .filter(|line| matches!(line, Line::Control { .. }) && !line.is_break())
+        .count();
+    if let Err((line, problem)) = check_control_lines(rendered, expected) {
+        panic!("line {line}: {problem}");
+    }
+}
+
+/// Check that rendered output has at most `expected` control lines,
+/// returning the number of the first line beyond them, from 1, if not.
+fn check_control_lines(rendered: &str, expected: usize) -> Result<(), (usize, &'static str)> {
+    let preamble: Vec<&str> = APOSTROPHE_PREABMLE
+        .lines()
+        .chain(SMART_PUNCTUATION_PREAMBLE.lines())
+        .chain([r#"'\" t"#])
+        .collect();
+    let mut count = 0;
+    let mut in_preamble = true;
+    for (i, line) in rendered.lines().enumerate() {
+        in_preamble =
+            in_preamble && (preamble.contains(&line) || line.starts_with(r#".\" -*- coding: "#));
+        if in_preamble
+            || !line.starts_with(['.', '\''])
+            || line == ".br"
+            || line.starts_with(r".if \n(.g .if d pdfhref .pdfhref O ")
+        {
+            continue;
+        }
+        count += 1;
+        if count > expected {
+            return Err((i + 1, "control line not in the document"));
+        }
+    }
+    Ok(())
+}
+
+/// Check rendered output, returning the number of the line that breaks
+/// an invariant, from 1, with the problem.
+fn check(rendered: &str) -> Result<(), (usize, &'static str)> {
+    // The lines the font and the size were last changed on, if they
+    // aren't what they started as.
+    let mut font_changed = None;
+    let mut size_changed = None;
+    let mut size = 0;
+    for (i, line) in rendered.lines().enumerate() {
+        let number = i + 1;
+        if line.starts_with(['.', '\'']) {
+            if line.matches('"').count() % 2 != 0 {
+                return Err((number, "unbalanced double quotes"));
+            }
+            continue;
+        }
+
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                continue;
+            }
+            match chars.next() {
+                None => return Err((number, "escaped newline")),
+                Some('f') => {
+                    let font: String = match chars.next() {
+                        Some('(') => chars.by_ref().take(2).collect(),
+                        Some('[') => chars.by_ref().take_while(|&c| c != ']').collect(),
+                        Some(c) => c.to_string(),
+                        None => return Err((number, "incomplete font escape")),
+                    };
+                    let roman = matches!(font.as_str(), "R" | "P" | "1" | "");
+                    font_changed = if roman { None } else { Some(number) };
+                }
+                Some('s') => {
+                    size += size_change(&mut chars).map_err(|problem| (number, problem))?;
+                    size_changed = if size == 0 { None } else { Some(number) };
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    if let Some(number) = font_changed {
+        Err((number, "font not restored"))
+    } else if let Some(number) = size_changed {
+        Err((number, "size not restored"))
+    } else {
+        Ok(())
+    }
+}
+
+/// The change of size of a `\s` escape, after the `s`.
+fn size_change(chars: &mut std::str::Chars<'_>) -> Result<i32, &'static str> {
+    let spec: String = match chars.next() {
+        Some('[') => chars.by_ref().take_while(|&c| c != ']').collect(),
+        Some(sign @ ('+' | '-')) => match chars.next() {
+            Some('(') => std::iter::once(sign)
+                .chain(chars.by_ref().take(2))
+                .collect(),
+            Some(digit) => [sign, digit].into_iter().collect(),
+            None => return Err("incomplete size escape"),
+        },
+        Some(digit) => digit.to_string(),
+        None => return Err("incomplete size escape"),
+    };
+    match spec.parse() {
+        // An absolute size is only checked for being a number.
+        Ok(change) if spec.starts_with(['+', '-']) => Ok(change),
+        Ok(_) => Ok(0),
+        Err(_) => Err("invalid size escape"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn rendered_documents_are_well_formed(doc in roff()) {
+            let rendered = doc.render();
+            assert_well_formed(&rendered);
+            assert_no_stray_control_lines(&doc, &rendered);
+        }
+
+        #[test]
+        fn text_renders_no_control_lines(lines in vec(inlines(), 0..8)) {
+            let doc = Roff { lines: lines.into_iter().map(Line::text).collect() };
+            assert_no_stray_control_lines(&doc, &doc.render());
+            assert_no_stray_control_lines(&doc, &doc.to_roff());
+        }
+    }
+
+    #[test]
+    fn unrestored_size() {
+        assert_eq!(check("a\n\\s+2big\n"), Err((2, "size not restored")));
+        assert_eq!(check("\\s[+12]big\n.br\n\\s[-12]\n"), Ok(()));
+    }
+
+    #[test]
+    fn stray_control_lines() {
+        let preamble = APOSTROPHE_PREABMLE;
+        assert_eq!(
+            check_control_lines(&format!("{preamble}a\n.br\nb\n"), 0),
+            Ok(())
+        );
+        assert_eq!(
+            check_control_lines(&format!("{preamble}.SH A\n'b\n"), 1),
+            Err((4, "control line not in the document"))
+        );
+    }
+}
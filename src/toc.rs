@@ -0,0 +1,189 @@
+use crate::{roman, Inline, Line, Roff, RoffVisitor, SpecialChar, NO_ARGS};
+
+/// A heading of a document, as found by [`Roff::headings`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Heading {
+    level: u8,
+    text: String,
+}
+
+impl Heading {
+    /// The level of the heading, 1 for the highest, such as a section
+    /// of a manual page, 2 for those under it, and so on.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// The text of the heading, without fonts.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Roff<'_> {
+    /// The headings of the document, in order.
+    ///
+    /// Headings are found by the macros that make them in the man,
+    /// ms, mm, me, and mom macro packages: `SH` and `SS`; `NH` and
+    /// `SH`, which are followed by the text of the heading; `H` and
+    /// `HU`; `sh` and `uh`; and `HEADING`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["OPTIONS"])
+    ///     .control("SS", ["Output", "options"])
+    ///     .control("NH", ["2"])
+    ///     .text([bold("Details")]);
+    /// let headings: Vec<_> = doc
+    ///     .headings()
+    ///     .iter()
+    ///     .map(|heading| (heading.level(), heading.text().to_owned()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     headings,
+    ///     [
+    ///         (1, "OPTIONS".to_owned()),
+    ///         (2, "Output options".to_owned()),
+    ///         (2, "Details".to_owned()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn headings(&self) -> Vec<Heading> {
+        let mut headings = vec![];
+        // The level of a heading whose text is on the next text line.
+        let mut pending = None;
+        for line in &self.lines {
+            let (name, args) = match line {
+                Line::Text(inlines) => {
+                    if let Some(level) = pending.take() {
+                        headings.push(Heading {
+                            level,
+                            text: plain_text(inlines),
+                        });
+                    }
+                    continue;
+                }
+                Line::Control { name, args } => (name.as_ref(), args),
+            };
+            let level = || {
+                args.first()
+                    .and_then(|level| level.parse().ok())
+                    .unwrap_or(1)
+            };
+            let (level, text) = match name {
+                "SH" if args.is_empty() => {
+                    pending = Some(1);
+                    continue;
+                }
+                "NH" => {
+                    pending = Some(level());
+                    continue;
+                }
+                "SH" | "HU" | "uh" => (1, args.join(" ")),
+                "SS" => (2, args.join(" ")),
+                "H" | "sh" | "HEADING" if args.len() > 1 => (level(), args[1..].join(" ")),
+                _ => continue,
+            };
+            headings.push(Heading { level, text });
+        }
+        headings
+    }
+}
+
+/// A table of contents, listing headings with those of each level
+/// indented under those of the level before.
+///
+/// Headings can be found with [`Roff::headings`]. Each is on a line
+/// of its own; those of level 2 and below are indented by 4 ens per
+/// level.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut doc = Roff::new();
+/// doc.control("SH", ["OPTIONS"]).control("SS", ["Output"]);
+/// assert_eq!(
+///     table_of_contents(&doc.headings()).to_roff(),
+///     "OPTIONS\n\
+///      .br\n\
+///      .ti +4n\n\
+///      Output\n\
+///      .br\n"
+/// );
+/// ```
+pub fn table_of_contents(headings: &[Heading]) -> Roff<'static> {
+    let mut doc = Roff::new();
+    for heading in headings {
+        if heading.level > 1 {
+            doc.temporary_indent(format!("+{}n", 4 * u32::from(heading.level - 1)));
+        }
+        doc.text([roman(heading.text.clone())])
+            .control("br", NO_ARGS);
+    }
+    doc
+}
+
+/// The text of inline elements, without fonts.
+fn plain_text(inlines: &[Inline<'_>]) -> String {
+    struct PlainText(String);
+
+    impl RoffVisitor for PlainText {
+        fn visit_roman(&mut self, text: &str) {
+            self.0.push_str(text);
+        }
+
+        fn visit_italic(&mut self, text: &str) {
+            self.0.push_str(text);
+        }
+
+        fn visit_bold(&mut self, text: &str) {
+            self.0.push_str(text);
+        }
+
+        fn visit_special(&mut self, c: SpecialChar) {
+            self.0.push(c.as_char());
+        }
+    }
+
+    let mut text = PlainText(String::new());
+    text.visit_text(inlines);
+    text.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::italic;
+
+    #[test]
+    fn headings_of_macro_packages() {
+        let mut doc = Roff::new();
+        doc.control("SH", NO_ARGS)
+            .control("PP", NO_ARGS)
+            .text([roman("The "), italic("foo")])
+            .control("H", ["3", "Deep", "down"])
+            .control("uh", ["Preface"])
+            .control("HEADING", ["1"]);
+        assert_eq!(
+            doc.headings(),
+            [
+                Heading {
+                    level: 1,
+                    text: "The foo".to_owned()
+                },
+                Heading {
+                    level: 3,
+                    text: "Deep down".to_owned()
+                },
+                Heading {
+                    level: 1,
+                    text: "Preface".to_owned()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::borrow::Cow;
+
+use crate::{Inline, Line, Roff};
+
+impl Roff<'_> {
+    /// The lines of the document, as read-only views.
+    ///
+    /// This is for inspecting documents built by other code, such as
+    /// the manual pages of a crate that generates them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use roff::*;
+    /// let mut doc = Roff::new();
+    /// doc.control("SH", ["NAME"]).text([roman("foo")]);
+    /// let lines: Vec<_> = doc.lines().collect();
+    /// assert_eq!(lines[0].name(), Some("SH"));
+    /// assert_eq!(lines[0].args(), ["NAME"]);
+    /// assert_eq!(lines[1].inlines(), [roman("foo")]);
+    /// ```
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = LineRef<'_>> + DoubleEndedIterator {
+        self.lines.iter().map(LineRef::new)
+    }
+
+    /// The line at an index, if the document has that many lines.
+    pub fn line(&self, index: usize) -> Option<LineRef<'_>> {
+        self.lines.get(index).map(LineRef::new)
+    }
+}
+
+/// A read-only view of a line in a [`Roff`], from [`Roff::lines`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LineRef<'r> {
+    /// A control line.
+    Control {
+        /// Name of control request or macro being invoked.
+        name: &'r str,
+
+        /// Arguments on control line.
+        args: &'r [Cow<'r, str>],
+    },
+
+    /// A text line.
+    Text(&'r [Inline<'r>]),
+}
+
+impl<'r> LineRef<'r> {
+    pub(crate) fn new(line: &'r Line<'_>) -> Self {
+        match line {
+            Line::Control { name, args } => Self::Control { name, args },
+            Line::Text(inlines) => Self::Text(inlines),
+        }
+    }
+
+    /// Name of the request or macro, for a control line.
+    pub fn name(&self) -> Option<&'r str> {
+        match self {
+            Self::Control { name, .. } => Some(name),
+            Self::Text(_) => None,
+        }
+    }
+
+    /// Arguments of a control line, or nothing for a text line.
+    pub fn args(&self) -> &'r [Cow<'r, str>] {
+        match self {
+            Self::Control { args, .. } => args,
+            Self::Text(_) => &[],
+        }
+    }
+
+    /// Inline elements of a text line, or nothing for a control line.
+    pub fn inlines(&self) -> &'r [Inline<'r>] {
+        match self {
+            Self::Control { .. } => &[],
+            Self::Text(inlines) => inlines,
+        }
+    }
+
+    /// Is this a text line?
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Text(_))
+    }
+
+    /// Is this a control line invoking the given request or macro?
+    pub fn is_control(&self, name: &str) -> bool {
+        self.name() == Some(name)
+    }
+}
@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+
+use crate::{Inline, Line, Roff, SpecialChar};
+
+/// Callbacks for walking through a document.
+///
+/// See [`Roff::visit`]. All methods do nothing by default, except for
+/// those that call the methods for the parts of what they visit:
+/// [`visit_text`](RoffVisitor::visit_text) calls
+/// [`visit_inline`](RoffVisitor::visit_inline) for each inline
+/// element, which calls the method for the kind of element.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// #[derive(Default)]
+/// struct Sections(Vec<String>);
+///
+/// impl RoffVisitor for Sections {
+///     fn visit_control(&mut self, name: &str, args: &[std::borrow::Cow<'_, str>]) {
+///         if name == "SH" {
+///             self.0.push(args.join(" "));
+///         }
+///     }
+/// }
+///
+/// let mut sections = Sections::default();
+/// Roff::new()
+///     .control("SH", ["NAME"])
+///     .text([roman("foo")])
+///     .control("SH", ["SEE", "ALSO"])
+///     .visit(&mut sections);
+/// assert_eq!(sections.0, ["NAME", "SEE ALSO"]);
+/// ```
+pub trait RoffVisitor {
+    /// Visit a control line.
+    fn visit_control(&mut self, name: &str, args: &[Cow<'_, str>]) {
+        let _ = (name, args);
+    }
+
+    /// Visit a text line.
+    fn visit_text(&mut self, inlines: &[Inline<'_>]) {
+        for inline in inlines {
+            self.visit_inline(inline);
+        }
+    }
+
+    /// Visit an inline element of a text line.
+    fn visit_inline(&mut self, inline: &Inline<'_>) {
+        match inline {
+            Inline::Roman(text) => self.visit_roman(text),
+            Inline::Italic(text) => self.visit_italic(text),
+            Inline::Bold(text) => self.visit_bold(text),
+            Inline::LineBreak => self.visit_line_break(),
+            Inline::Special(c) => self.visit_special(*c),
+            Inline::HyphenationPoint => self.visit_hyphenation_point(),
+            Inline::BreakPoint => self.visit_break_point(),
+            Inline::Tab => self.visit_tab(),
+            Inline::ZeroWidth => self.visit_zero_width(),
+            Inline::Colored { color, inlines } => self.visit_colored(color, inlines),
+            Inline::Resized { change, inlines } => self.visit_resized(*change, inlines),
+            Inline::Superscript(inlines) => self.visit_superscript(inlines),
+            Inline::Subscript(inlines) => self.visit_subscript(inlines),
+        }
+    }
+
+    /// Visit text in the roman font.
+    fn visit_roman(&mut self, text: &str) {
+        let _ = text;
+    }
+
+    /// Visit text in the italic font.
+    fn visit_italic(&mut self, text: &str) {
+        let _ = text;
+    }
+
+    /// Visit text in the bold font.
+    fn visit_bold(&mut self, text: &str) {
+        let _ = text;
+    }
+
+    /// Visit a hard line break.
+    fn visit_line_break(&mut self) {}
+
+    /// Visit a special character.
+    fn visit_special(&mut self, c: SpecialChar) {
+        let _ = c;
+    }
+
+    /// Visit a place where a word may be hyphenated.
+    fn visit_hyphenation_point(&mut self) {}
+
+    /// Visit a place where a line may be broken without a hyphen.
+    fn visit_break_point(&mut self) {}
+
+    /// Visit a tab.
+    fn visit_tab(&mut self) {}
+
+    /// Visit a zero-width character that prints nothing.
+    fn visit_zero_width(&mut self) {}
+
+    /// Visit inline elements in a color.
+    fn visit_colored(&mut self, color: &str, inlines: &[Inline<'_>]) {
+        let _ = color;
+        for inline in inlines {
+            self.visit_inline(inline);
+        }
+    }
+
+    /// Visit inline elements in a larger or smaller type size.
+    fn visit_resized(&mut self, change: i32, inlines: &[Inline<'_>]) {
+        let _ = change;
+        for inline in inlines {
+            self.visit_inline(inline);
+        }
+    }
+
+    /// Visit inline elements in superscript.
+    fn visit_superscript(&mut self, inlines: &[Inline<'_>]) {
+        for inline in inlines {
+            self.visit_inline(inline);
+        }
+    }
+
+    /// Visit inline elements in subscript.
+    fn visit_subscript(&mut self, inlines: &[Inline<'_>]) {
+        for inline in inlines {
+            self.visit_inline(inline);
+        }
+    }
+}
+
+impl Roff<'_> {
+    /// Walk through the lines of the document, in order.
+    pub fn visit(&self, visitor: &mut impl RoffVisitor) {
+        for line in self.lines.iter() {
+            match line {
+                Line::Control { name, args } => visitor.visit_control(name, args),
+                Line::Text(inlines) => visitor.visit_text(inlines),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bold, italic, line_break, roman};
+
+    #[derive(Default)]
+    struct Log(Vec<String>);
+
+    impl RoffVisitor for Log {
+        fn visit_control(&mut self, name: &str, args: &[Cow<'_, str>]) {
+            self.0.push(format!("control {name} {args:?}"));
+        }
+
+        fn visit_roman(&mut self, text: &str) {
+            self.0.push(format!("roman {text}"));
+        }
+
+        fn visit_italic(&mut self, text: &str) {
+            self.0.push(format!("italic {text}"));
+        }
+
+        fn visit_bold(&mut self, text: &str) {
+            self.0.push(format!("bold {text}"));
+        }
+
+        fn visit_line_break(&mut self) {
+            self.0.push("break".to_owned());
+        }
+
+        fn visit_special(&mut self, c: SpecialChar) {
+            self.0.push(format!("special {c:?}"));
+        }
+    }
+
+    #[test]
+    fn visits_in_order() {
+        let mut log = Log::default();
+        Roff::new()
+            .control("TH", ["FOO", "1"])
+            .text([roman("a"), italic("b"), line_break(), bold("c")])
+            .text([SpecialChar::Bullet.into()])
+            .visit(&mut log);
+        assert_eq!(
+            log.0,
+            [
+                r#"control TH ["FOO", "1"]"#,
+                "roman a",
+                "italic b",
+                "break",
+                "bold c",
+                "special Bullet",
+            ]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use std::io;
+
+use crate::{Inline, Line, LineRenderer, RenderOptions};
+
+/// Write a ROFF document as it is built.
+///
+/// A [`Roff`](crate::Roff) keeps all of its lines until it is
+/// rendered. For very large documents, that is a waste of memory. A
+/// `RoffWriter` instead writes the preamble when it is created, and
+/// each line as it is appended. The output is the same as from
+/// [`Roff::to_writer_with`](crate::Roff::to_writer_with), except that
+/// the preamble can't say whether tables follow, so a document with
+/// tables must be run through tbl(1) explicitly.
+///
+/// # Example
+///
+/// ```
+/// # use roff::*;
+/// let mut w = RoffWriter::new(vec![]).unwrap();
+/// w.control("TH", ["FOO", "1"])
+///     .unwrap()
+///     .text([roman("foo - do a foo thing")])
+///     .unwrap();
+/// let doc = String::from_utf8(w.into_inner()).unwrap();
+/// assert!(doc.ends_with(".TH FOO 1\nfoo \\- do a foo thing\n"));
+/// ```
+#[derive(Debug)]
+pub struct RoffWriter<W: io::Write> {
+    inner: W,
+    options: RenderOptions,
+    renderer: LineRenderer,
+    // Each line is rendered here, to be written at once.
+    buf: String,
+}
+
+impl<W: io::Write> RoffWriter<W> {
+    /// Start writing a document to a writer.
+    pub fn new(inner: W) -> Result<Self, io::Error> {
+        Self::with_options(inner, RenderOptions::default())
+    }
+
+    /// Start writing a document to a writer, with custom
+    /// [`RenderOptions`].
+    pub fn with_options(inner: W, options: RenderOptions) -> Result<Self, io::Error> {
+        let mut w = Self {
+            inner,
+            options,
+            renderer: LineRenderer::default(),
+            buf: String::new(),
+        };
+        crate::render_preamble(&mut w.buf, &w.options, &[])
+            .expect("writing to a String always works");
+        w.write_buf()?;
+        Ok(w)
+    }
+
+    /// Write a control line.
+    ///
+    /// See [`Roff::control`](crate::Roff::control).
+    pub fn control<'a>(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        args: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> Result<&mut Self, io::Error> {
+        let line = Line::control(name.into(), args.into_iter().map(Into::into).collect());
+        self.write_line(&line)?;
+        Ok(self)
+    }
+
+    /// Write a text line.
+    ///
+    /// See [`Roff::text`](crate::Roff::text).
+    pub fn text<'a>(
+        &mut self,
+        inlines: impl Into<Vec<Inline<'a>>>,
+    ) -> Result<&mut Self, io::Error> {
+        let line = Line::text(inlines.into());
+        self.write_line(&line)?;
+        Ok(self)
+    }
+
+    /// Flush the underlying writer.
+    pub fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+
+    /// Get back the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn write_line(&mut self, line: &Line<'_>) -> Result<(), io::Error> {
+        self.renderer
+            .render(&mut self.buf, line, &self.options)
+            .expect("writing to a String always works");
+        self.write_buf()
+    }
+
+    fn write_buf(&mut self) -> Result<(), io::Error> {
+        let written = self.inner.write_all(self.buf.as_bytes());
+        self.buf.clear();
+        written
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bold, roman, Encoding, Roff};
+
+    #[test]
+    fn same_as_roff() {
+        let options = RenderOptions::new()
+            .encoding(Encoding::Ascii)
+            .coding_comment(true)
+            .pdf_outline(true);
+
+        let mut doc = Roff::new();
+        doc.control("TH", ["FOO", "1"])
+            .control("SH", ["NAMÉ"])
+            .text([roman("don't"), bold("café")]);
+
+        let mut w = RoffWriter::with_options(vec![], options.clone()).unwrap();
+        w.control("TH", ["FOO", "1"])
+            .unwrap()
+            .control("SH", ["NAMÉ"])
+            .unwrap()
+            .text([roman("don't"), bold("café")])
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(w.into_inner()).unwrap(),
+            doc.render_with(&options)
+        );
+    }
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "cli")]
+
+#[test]
+fn json_to_man() {
+    let json = r#"{"version":1,"lines":[{"type":"control","name":"SH","args":["NAME"]},{"type":"text","inlines":[{"type":"roman","text":"foo - do a foo thing"}]}]}"#;
+    let output = duct::cmd!(env!("CARGO_BIN_EXE_roff"))
+        .stdin_bytes(json)
+        .read()
+        .unwrap();
+    assert!(output.ends_with(".SH NAME\nfoo \\- do a foo thing"), "{output}");
+}
+
+#[test]
+fn toml_to_markdown() {
+    let toml = r#"
+name = "foo"
+description = "do a foo thing"
+"#;
+    let output = duct::cmd!(env!("CARGO_BIN_EXE_roff"), "--from", "toml", "--to", "markdown")
+        .stdin_bytes(toml)
+        .read()
+        .unwrap();
+    assert!(output.contains("foo - do a foo thing"), "{output}");
+}
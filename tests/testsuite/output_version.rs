@@ -0,0 +1,72 @@
+use pretty_assertions::assert_eq;
+use roff::*;
+
+// The output of each version must never change: a change to escaping
+// that fails these tests belongs in a new version instead.
+
+fn doc() -> Roff<'static> {
+    let mut doc = Roff::new();
+    doc.control("TH", ["FOO", "1", "", "foo \"1.0\""])
+        .control("SH", ["SEE ALSO"])
+        .control("TP", ["a\tb"])
+        .text([
+            roman(".start -- don't \\ naïve"),
+            line_break(),
+            bold("'quoted'"),
+            italic("\u{2014}"),
+            SpecialChar::Bullet.into(),
+            hyphenation_point(),
+            break_point(),
+            zero_width(),
+            tab(),
+            colored("red", [roman("red")]),
+            resized(2, [roman("big")]),
+            superscript([roman("2")]),
+        ]);
+    doc
+}
+
+const TEXT: &str = concat!(
+    r#"\&.start \-\- don\*(Aqt \\ naïve
+.br
+\fB\*(Aqquoted\*(Aq\fR\fI—\fR\(bu\%\:\&"#,
+    "\t",
+    r#"\m[red]red\m[]\s+2big\s-2\u\s-22\s+2\d
+"#,
+);
+
+#[test]
+fn v1_output_is_frozen() {
+    let options = RenderOptions::new().output_version(OutputVersion::V1);
+    let expected = concat!(
+        r#".ie \n(.g .ds Aq \(aq
+.el .ds Aq '
+.TH FOO 1  "foo "1.0""
+.SH "SEE ALSO"
+.TP a"#,
+        "\tb\n",
+    );
+    assert_eq!(doc().render_with(&options), format!("{expected}{TEXT}"));
+}
+
+#[test]
+fn v2_output_is_frozen() {
+    let options = RenderOptions::new().output_version(OutputVersion::V2);
+    let expected = concat!(
+        r#".ie \n(.g .ds Aq \(aq
+.el .ds Aq '
+.TH FOO 1 "" "foo ""1.0"""
+.SH "SEE ALSO"
+.TP "a"#,
+        "\tb\"\n",
+    );
+    assert_eq!(doc().render_with(&options), format!("{expected}{TEXT}"));
+}
+
+#[test]
+fn latest_is_default() {
+    assert_eq!(
+        doc().render(),
+        doc().render_with(&RenderOptions::new().output_version(OutputVersion::LATEST))
+    );
+}